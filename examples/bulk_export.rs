@@ -0,0 +1,61 @@
+//! Exports a resource to newline-delimited JSON, resuming an interrupted run.
+//!
+//! ```sh
+//! cargo run --example bulk_export -- bills bills-118.ndjson --congress 118 --hydrate
+//! ```
+//!
+//! The API key is read from `CDG_API_KEY`, or from a `.env` file.
+
+use std::error::Error;
+use std::process;
+
+use congressdotgov_rs::api::bulk::{BulkExport, BulkResource};
+use congressdotgov_rs::{Auth, Cdg};
+
+const USAGE: &str = "usage: bulk_export <resource> <output> [--congress <number>] [--hydrate]
+
+resources: bills, amendments, members, committees, nominations, treaties, reports";
+
+#[tokio::main]
+async fn main() {
+    if let Err(err) = run().await {
+        eprintln!("error: {err}");
+        process::exit(1);
+    }
+}
+
+async fn run() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+    let mut positional = Vec::new();
+    let mut export = BulkExport::builder();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--congress" => {
+                let congress: u16 = args.next().ok_or(USAGE)?.parse()?;
+                export.congress(congress);
+            }
+            "--hydrate" => {
+                export.hydrate(true);
+            }
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return Ok(());
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let [resource, output] = <[String; 2]>::try_from(positional).map_err(|_| USAGE)?;
+    let export = export.resource(resource.parse::<BulkResource>()?).build()?;
+
+    let _ = dotenvy::dotenv();
+    let client = Cdg::new(Auth::Token(dotenvy::var("CDG_API_KEY")?))?;
+
+    let manifest = export.run(&client, &output).await?;
+    println!(
+        "wrote {} of {} {resource} to {output}",
+        manifest.records,
+        manifest.total.unwrap_or(manifest.records),
+    );
+    Ok(())
+}
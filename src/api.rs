@@ -38,12 +38,18 @@
 //! ```
 
 mod client;
+mod de;
 mod endpoint;
 mod error;
+mod paged;
 mod params;
 pub(crate) mod query;
 
+pub mod bulk;
+pub mod calendar;
+pub mod citation;
 pub mod common;
+pub mod sync;
 
 pub mod amendments;
 pub mod bill;
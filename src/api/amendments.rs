@@ -2,8 +2,11 @@
 
 //! Amendments API endpoints and types.
 
+use crate::api::common::impl_api_enum;
+
 mod actions;
 mod amendment;
+mod amendment_id;
 mod amendment_number;
 mod amendment_type;
 mod amendments;
@@ -11,8 +14,11 @@ mod congress;
 mod cosponsors;
 mod text;
 
+pub mod models;
+
 pub use self::actions::{Actions, ActionsBuilder, ActionsBuilderError};
 pub use self::amendment::{Amendment, AmendmentBuilder, AmendmentBuilderError};
+pub use self::amendment_id::AmendmentId;
 pub use self::amendment_number::{
     AmendmentNumber, AmendmentNumberBuilder, AmendmentNumberBuilderError,
 };
@@ -23,7 +29,7 @@ pub use self::cosponsors::{Cosponsors, CosponsorsBuilder, CosponsorsBuilderError
 pub use self::text::{Text, TextBuilder, TextBuilderError};
 
 /// The possible Amendment Types in Congress.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CongressionalAmendmentType {
     /// H.Amdt. - House amendment. Amends a House bill.
     Hamdt,
@@ -34,7 +40,7 @@ pub enum CongressionalAmendmentType {
 }
 
 impl CongressionalAmendmentType {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             CongressionalAmendmentType::Hamdt => "hamdt",
             CongressionalAmendmentType::Samdt => "samdt",
@@ -42,3 +48,14 @@ impl CongressionalAmendmentType {
         }
     }
 }
+
+impl_api_enum!(
+    CongressionalAmendmentType,
+    "amendment type",
+    [Hamdt, Samdt, Suamdt],
+    json {
+        Hamdt => "HAMDT",
+        Samdt => "SAMDT",
+        Suamdt => "SUAMDT",
+    }
+);
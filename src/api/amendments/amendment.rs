@@ -42,8 +42,8 @@ impl Endpoint for Amendment {
         params.push("format", self.format);
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
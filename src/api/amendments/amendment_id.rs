@@ -0,0 +1,251 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::api::{
+    amendments::{
+        ActionsBuilder, AmendmentNumberBuilder, AmendmentsBuilder, CongressionalAmendmentType,
+        CosponsorsBuilder, TextBuilder,
+    },
+    calendar::Congress,
+    citation::{self, Citation, Ordinal, ParseCitationError},
+};
+
+/// Identifies an amendment by its congress, type and number.
+///
+/// An `AmendmentId` can be parsed from an official citation such as
+/// "H.Amdt. 173", "S.Amdt. 2137" or "S.Up.Amdt. 5" followed by its congress,
+/// or from a slug such as "samdt2137-117". It converts into the builder of
+/// every endpoint under /amendment/:congress/:amendmentType/:amendmentNumber.
+///
+/// ```rust
+/// use congressdotgov_rs::api::amendments::{self, AmendmentId, CongressionalAmendmentType};
+///
+/// let id: AmendmentId = "samdt2137-117".parse().unwrap();
+/// assert_eq!(id, AmendmentId::new(117, CongressionalAmendmentType::Samdt, 2137));
+/// assert_eq!(id.to_string(), "S.Amdt. 2137 (117th Congress)");
+///
+/// let text = amendments::TextBuilder::from(id).build().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AmendmentId {
+    pub congress: u16,
+    pub amendment_type: CongressionalAmendmentType,
+    pub number: u32,
+}
+
+impl AmendmentId {
+    pub fn new(congress: u16, amendment_type: CongressionalAmendmentType, number: u32) -> Self {
+        Self {
+            congress,
+            amendment_type,
+            number,
+        }
+    }
+
+    /// Parses a citation, using `congress` when the citation does not name
+    /// one, e.g. "H.Amdt. 173".
+    pub fn parse_with_congress(s: &str, congress: u16) -> Result<Self, ParseCitationError> {
+        Self::parse(s, Some(congress))
+    }
+
+    /// The citation without its congress, e.g. "H.Amdt. 173".
+    pub fn citation(&self) -> String {
+        let abbreviation = match self.amendment_type {
+            CongressionalAmendmentType::Hamdt => "H.Amdt.",
+            CongressionalAmendmentType::Samdt => "S.Amdt.",
+            CongressionalAmendmentType::Suamdt => "S.Up.Amdt.",
+        };
+        format!("{} {}", abbreviation, self.number)
+    }
+
+    /// The congress.gov slug of the amendment, e.g. "samdt2137-117".
+    pub fn slug(&self) -> String {
+        format!(
+            "{}{}-{}",
+            self.amendment_type.as_str(),
+            self.number,
+            self.congress
+        )
+    }
+
+    fn parse(s: &str, default_congress: Option<u16>) -> Result<Self, ParseCitationError> {
+        const KIND: &str = "amendment";
+
+        let invalid = || ParseCitationError::unrecognized(KIND, s);
+        let citation = Citation::split(s).ok_or_else(invalid)?;
+        let amendment_type = amendment_type(&citation.prefix).ok_or_else(invalid)?;
+
+        // Slugs carry the congress after the number, as in "samdt2137-117".
+        let (number, slug_congress) = citation.numbers();
+        let number = citation::number(number).ok_or_else(invalid)?;
+        let congress = match slug_congress {
+            Some(congress) => Some(citation::congress(congress).ok_or_else(invalid)?),
+            None => citation.congress,
+        };
+        let congress = match congress {
+            Some(congress) => Congress::new(congress)
+                .map(u16::from)
+                .map_err(|_| ParseCitationError::invalid_congress(KIND, s, congress))?,
+            None => {
+                default_congress.ok_or_else(|| ParseCitationError::missing_congress(KIND, s))?
+            }
+        };
+
+        // Senate unprinted amendments are only available for the 97th and
+        // 98th Congresses.
+        if amendment_type == CongressionalAmendmentType::Suamdt && !(97..=98).contains(&congress) {
+            return Err(ParseCitationError::invalid_congress(KIND, s, congress));
+        }
+
+        Ok(Self::new(congress, amendment_type, number))
+    }
+}
+
+fn amendment_type(prefix: &str) -> Option<CongressionalAmendmentType> {
+    match prefix {
+        "hamdt" => Some(CongressionalAmendmentType::Hamdt),
+        "samdt" => Some(CongressionalAmendmentType::Samdt),
+        "supamdt" | "suamdt" => Some(CongressionalAmendmentType::Suamdt),
+        _ => None,
+    }
+}
+
+impl FromStr for AmendmentId {
+    type Err = ParseCitationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+impl fmt::Display for AmendmentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} Congress)",
+            self.citation(),
+            Ordinal(self.congress.into())
+        )
+    }
+}
+
+macro_rules! impl_builder_from_amendment_id {
+    ($($builder:ident),* $(,)?) => {
+        $(
+            impl From<AmendmentId> for $builder {
+                fn from(id: AmendmentId) -> Self {
+                    let mut builder = Self::default();
+                    builder
+                        .congress(id.congress)
+                        .amendment_type(id.amendment_type)
+                        .amendment_number(id.number);
+                    builder
+                }
+            }
+        )*
+    };
+}
+
+impl_builder_from_amendment_id!(
+    ActionsBuilder,
+    AmendmentNumberBuilder,
+    AmendmentsBuilder,
+    CosponsorsBuilder,
+    TextBuilder,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{amendments::AmendmentNumber, endpoint::Endpoint};
+
+    use super::*;
+
+    #[test]
+    fn parses_citations() {
+        let cases = [
+            (
+                "H.Amdt. 173 (117th Congress)",
+                AmendmentId::new(117, CongressionalAmendmentType::Hamdt, 173),
+            ),
+            (
+                "S.Amdt. 2137, 117th Congress",
+                AmendmentId::new(117, CongressionalAmendmentType::Samdt, 2137),
+            ),
+            (
+                "S.Up.Amdt. 5 (97th Congress)",
+                AmendmentId::new(97, CongressionalAmendmentType::Suamdt, 5),
+            ),
+            (
+                "samdt2137-117",
+                AmendmentId::new(117, CongressionalAmendmentType::Samdt, 2137),
+            ),
+            (
+                "suamdt12-98",
+                AmendmentId::new(98, CongressionalAmendmentType::Suamdt, 12),
+            ),
+        ];
+
+        for (citation, id) in cases {
+            assert_eq!(citation.parse::<AmendmentId>(), Ok(id), "{citation}");
+        }
+
+        assert_eq!(
+            AmendmentId::parse_with_congress("H.Amdt. 173", 117),
+            Ok(AmendmentId::new(
+                117,
+                CongressionalAmendmentType::Hamdt,
+                173
+            ))
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for id in [
+            AmendmentId::new(117, CongressionalAmendmentType::Hamdt, 173),
+            AmendmentId::new(102, CongressionalAmendmentType::Samdt, 2),
+            AmendmentId::new(98, CongressionalAmendmentType::Suamdt, 5),
+        ] {
+            assert_eq!(id.to_string().parse::<AmendmentId>(), Ok(id));
+            assert_eq!(id.slug().parse::<AmendmentId>(), Ok(id));
+        }
+    }
+
+    #[test]
+    fn rejects_unprinted_amendments_outside_97th_and_98th() {
+        assert!(matches!(
+            "S.Up.Amdt. 5 (117th Congress)".parse::<AmendmentId>(),
+            Err(ParseCitationError::InvalidCongress { congress: 117, .. })
+        ));
+        assert!(AmendmentId::parse_with_congress("suamdt5", 96).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_citations() {
+        assert!(matches!(
+            "H.Amdt. 173".parse::<AmendmentId>(),
+            Err(ParseCitationError::MissingCongress { .. })
+        ));
+        for citation in ["", "H.Amdt.", "H.R. 173-117", "hamdt-117", "hamdt173-300"] {
+            assert!(citation.parse::<AmendmentId>().is_err(), "{citation}");
+        }
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let id: AmendmentId = "S.Amdt. 2137 (117th Congress)".parse().unwrap();
+
+        assert_eq!(
+            AmendmentNumberBuilder::from(id).build().unwrap().endpoint(),
+            "amendment/117/samdt/2137"
+        );
+        assert_eq!(
+            CosponsorsBuilder::from(id).build().unwrap().endpoint(),
+            "amendment/117/samdt/2137/cosponsors"
+        );
+        assert_eq!(
+            AmendmentNumber::from(id).endpoint(),
+            "amendment/117/samdt/2137"
+        );
+    }
+}
@@ -4,14 +4,14 @@ use std::borrow::Cow;
 
 use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
 
-use super::CongressionalAmendmentType;
+use super::{AmendmentId, CongressionalAmendmentType};
 
 /// Represents the /amendment/:congress/:amendmentType/:amendmentNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct AmendmentNumber {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(setter(into))]
@@ -26,6 +26,17 @@ impl AmendmentNumber {
     }
 }
 
+impl From<AmendmentId> for AmendmentNumber {
+    fn from(id: AmendmentId) -> Self {
+        Self {
+            congress: id.congress,
+            amendment_type: id.amendment_type,
+            amendment_number: id.number,
+            format: Format::default(),
+        }
+    }
+}
+
 impl Endpoint for AmendmentNumber {
     fn method(&self) -> Method {
         Method::GET
@@ -12,7 +12,7 @@ use super::CongressionalAmendmentType;
 #[builder(setter(strip_option))]
 pub struct AmendmentType {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(default)]
@@ -53,8 +53,8 @@ impl Endpoint for AmendmentType {
         params.push("format", self.format);
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
@@ -11,7 +11,7 @@ use super::CongressionalAmendmentType;
 #[builder(setter(strip_option))]
 pub struct Cosponsors {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(setter(into))]
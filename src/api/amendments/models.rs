@@ -0,0 +1,79 @@
+//! Typed response models for the Amendment endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    amendments::{AmendmentId, CongressionalAmendmentType},
+    common::{LatestAction, Pagination},
+    de,
+    paged::impl_page,
+};
+
+/// An amendment, as returned by the list endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Amendment {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub amendment_type: CongressionalAmendmentType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub description: Option<String>,
+    pub purpose: Option<String>,
+    pub latest_action: Option<LatestAction>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl Amendment {
+    /// The identifier of the amendment, used to query its endpoints.
+    pub fn id(&self) -> AmendmentId {
+        AmendmentId::new(self.congress, self.amendment_type, self.number)
+    }
+}
+
+/// The response of the /amendment, /amendment/:congress and
+/// /amendment/:congress/:amendmentType endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AmendmentsResponse {
+    #[serde(default)]
+    pub amendments: Vec<Amendment>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl_page!(AmendmentsResponse => amendments: Amendment);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn amendments() {
+        let json = r#"{
+            "amendments": [{
+                "congress": 117,
+                "description": "Amends the Watershed Protection and Flood Prevention Act.",
+                "latestAction": {"actionDate": "2021-08-08", "actionTime": "16:51:36", "text": "Amendment SA 2137 agreed to in Senate by Yea-Nay Vote. 69 - 28."},
+                "number": "2137",
+                "purpose": "In the nature of a substitute.",
+                "type": "SAMDT",
+                "updateDate": "2022-02-25T17:34:49Z",
+                "url": "https://api.congress.gov/v3/amendment/117/samdt/2137?format=json"
+            }],
+            "pagination": {"count": 1}
+        }"#;
+
+        let res: AmendmentsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            res.amendments[0].id().to_string(),
+            "S.Amdt. 2137 (117th Congress)"
+        );
+        assert!(res.amendments[0].update_date.is_some());
+    }
+}
@@ -11,7 +11,7 @@ use super::CongressionalAmendmentType;
 #[builder(setter(strip_option))]
 pub struct Text {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     amendment_type: CongressionalAmendmentType,
     #[builder(setter(into))]
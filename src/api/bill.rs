@@ -5,26 +5,42 @@
 mod actions;
 mod amendments;
 mod bill;
+mod bill_id;
 mod bill_number;
 mod bill_type;
 mod committees;
 mod congress;
 mod cosponsors;
+mod dossier;
+mod download;
+mod network;
 mod related_bills;
+mod status;
 mod subjects;
 mod summaries;
 mod text;
 mod titles;
 
+pub mod models;
+pub mod uslm;
+
 pub use self::actions::{Actions, ActionsBuilder, ActionsBuilderError};
 pub use self::amendments::{Amendments, AmendmentsBuilder, AmendmentsBuilderError};
 pub use self::bill::{Bill, BillBuilder, BillBuilderError};
+pub use self::bill_id::BillId;
 pub use self::bill_number::{BillNumber, BillNumberBuilder, BillNumberBuilderError};
 pub use self::bill_type::{BillType, BillTypeBuilder, BillTypeBuilderError};
 pub use self::committees::{Committees, CommitteesBuilder, CommitteesBuilderError};
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
 pub use self::cosponsors::{Cosponsors, CosponsorsBuilder, CosponsorsBuilderError};
+pub use self::dossier::{BillDossier, DossierSection, SectionFailure};
+pub use self::download::{
+    DownloadError, DownloadedText, TextDownload, TextDownloadBuilder, TextDownloadBuilderError,
+    TextManifest,
+};
+pub use self::network::{CosponsorshipEdge, CosponsorshipGraph, MemberNode};
 pub use self::related_bills::{RelatedBills, RelatedBillsBuilder, RelatedBillsBuilderError};
+pub use self::status::{BillStage, BillStatus};
 pub use self::subjects::{Subjects, SubjectsBuilder, SubjectsBuilderError};
 pub use self::summaries::{Summaries, SummariesBuilder, SummariesBuilderError};
 pub use self::text::{Text, TextBuilder, TextBuilderError};
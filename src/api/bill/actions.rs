@@ -13,7 +13,7 @@ use crate::api::{
 #[builder(setter(strip_option))]
 pub struct Actions {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     bill_type: BillType,
     #[builder(setter(into))]
@@ -0,0 +1,266 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::api::{
+    bill::{
+        ActionsBuilder, AmendmentsBuilder, BillNumberBuilder, CommitteesBuilder, CosponsorsBuilder,
+        RelatedBillsBuilder, SubjectsBuilder, SummariesBuilder, TextBuilder, TitlesBuilder,
+    },
+    calendar::Congress,
+    citation::{self, Citation, Ordinal, ParseCitationError},
+    common::BillType,
+};
+
+/// Identifies a bill or resolution by its congress, type and number.
+///
+/// A `BillId` can be parsed from an official citation such as "H.R. 3076",
+/// "S.J.Res. 5" or "H.Con.Res. 12" followed by its congress, or from a slug
+/// such as "hr3076-117". It converts into the builder of every endpoint under
+/// /bill/:congress/:billType/:billNumber.
+///
+/// ```rust
+/// use congressdotgov_rs::api::bill::{self, BillId};
+/// use congressdotgov_rs::api::common::BillType;
+///
+/// let id: BillId = "H.R. 3076 (117th Congress)".parse().unwrap();
+/// assert_eq!(id, BillId::new(117, BillType::Hr, 3076));
+/// assert_eq!(id.to_string(), "H.R. 3076 (117th Congress)");
+/// assert_eq!(id.slug(), "hr3076-117");
+///
+/// let actions = bill::ActionsBuilder::from(id).limit(250).build().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BillId {
+    pub congress: u16,
+    pub bill_type: BillType,
+    pub number: u32,
+}
+
+impl BillId {
+    pub fn new(congress: u16, bill_type: BillType, number: u32) -> Self {
+        Self {
+            congress,
+            bill_type,
+            number,
+        }
+    }
+
+    /// Parses a citation, using `congress` when the citation does not name
+    /// one, e.g. "H.R. 3076".
+    pub fn parse_with_congress(s: &str, congress: u16) -> Result<Self, ParseCitationError> {
+        Self::parse(s, Some(congress))
+    }
+
+    /// The citation without its congress, e.g. "H.R. 3076".
+    pub fn citation(&self) -> String {
+        format!("{} {}", self.bill_type.citation(), self.number)
+    }
+
+    /// The congress.gov slug of the bill, e.g. "hr3076-117".
+    pub fn slug(&self) -> String {
+        format!(
+            "{}{}-{}",
+            self.bill_type.as_str(),
+            self.number,
+            self.congress
+        )
+    }
+
+    fn parse(s: &str, default_congress: Option<u16>) -> Result<Self, ParseCitationError> {
+        const KIND: &str = "bill";
+
+        let invalid = || ParseCitationError::unrecognized(KIND, s);
+        let citation = Citation::split(s).ok_or_else(invalid)?;
+        let bill_type = bill_type(&citation.prefix).ok_or_else(invalid)?;
+
+        // Slugs carry the congress after the number, as in "hr3076-117".
+        let (number, slug_congress) = citation.numbers();
+        let number = citation::number(number).ok_or_else(invalid)?;
+        let congress = match slug_congress {
+            Some(congress) => Some(citation::congress(congress).ok_or_else(invalid)?),
+            None => citation.congress,
+        };
+        let congress = match congress {
+            Some(congress) => Congress::new(congress)
+                .map(u16::from)
+                .map_err(|_| ParseCitationError::invalid_congress(KIND, s, congress))?,
+            None => {
+                default_congress.ok_or_else(|| ParseCitationError::missing_congress(KIND, s))?
+            }
+        };
+
+        Ok(Self::new(congress, bill_type, number))
+    }
+}
+
+fn bill_type(prefix: &str) -> Option<BillType> {
+    let bill_type = match prefix {
+        "hr" => BillType::Hr,
+        "s" => BillType::S,
+        "hjres" => BillType::Hjres,
+        "sjres" => BillType::Sjres,
+        "hconres" => BillType::Hconres,
+        "sconres" => BillType::Sconres,
+        "hres" => BillType::Hres,
+        "sres" => BillType::Sres,
+        _ => return None,
+    };
+    Some(bill_type)
+}
+
+impl FromStr for BillId {
+    type Err = ParseCitationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+impl fmt::Display for BillId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({} Congress)",
+            self.citation(),
+            Ordinal(self.congress.into())
+        )
+    }
+}
+
+macro_rules! impl_builder_from_bill_id {
+    ($($builder:ident),* $(,)?) => {
+        $(
+            impl From<BillId> for $builder {
+                fn from(id: BillId) -> Self {
+                    let mut builder = Self::default();
+                    builder
+                        .congress(id.congress)
+                        .bill_type(id.bill_type)
+                        .bill_number(id.number);
+                    builder
+                }
+            }
+        )*
+    };
+}
+
+impl_builder_from_bill_id!(
+    ActionsBuilder,
+    AmendmentsBuilder,
+    BillNumberBuilder,
+    CommitteesBuilder,
+    CosponsorsBuilder,
+    RelatedBillsBuilder,
+    SubjectsBuilder,
+    SummariesBuilder,
+    TextBuilder,
+    TitlesBuilder,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{bill::BillNumber, endpoint::Endpoint};
+
+    use super::*;
+
+    #[test]
+    fn parses_citations() {
+        let cases = [
+            (
+                "H.R. 3076 (117th Congress)",
+                BillId::new(117, BillType::Hr, 3076),
+            ),
+            (
+                "S.J.Res. 5, 118th Congress",
+                BillId::new(118, BillType::Sjres, 5),
+            ),
+            (
+                "H.Con.Res. 12 (117th)",
+                BillId::new(117, BillType::Hconres, 12),
+            ),
+            (
+                "H. J. Res. 7 116th Congress",
+                BillId::new(116, BillType::Hjres, 7),
+            ),
+            ("hr3076-117", BillId::new(117, BillType::Hr, 3076)),
+            ("sres 12-95", BillId::new(95, BillType::Sres, 12)),
+            ("SCONRES3-118", BillId::new(118, BillType::Sconres, 3)),
+        ];
+
+        for (citation, id) in cases {
+            assert_eq!(citation.parse::<BillId>(), Ok(id), "{citation}");
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for bill_type in [
+            BillType::Hr,
+            BillType::S,
+            BillType::Hjres,
+            BillType::Sjres,
+            BillType::Hconres,
+            BillType::Sconres,
+            BillType::Hres,
+            BillType::Sres,
+        ] {
+            let id = BillId::new(101, bill_type, 42);
+            assert_eq!(id.to_string().parse::<BillId>(), Ok(id));
+            assert_eq!(id.slug().parse::<BillId>(), Ok(id));
+        }
+    }
+
+    #[test]
+    fn needs_a_congress() {
+        assert!(matches!(
+            "H.R. 3076".parse::<BillId>(),
+            Err(ParseCitationError::MissingCongress { .. })
+        ));
+        assert_eq!(
+            BillId::parse_with_congress("H.R. 3076", 117),
+            Ok(BillId::new(117, BillType::Hr, 3076))
+        );
+        // A congress named by the citation wins over the default.
+        assert_eq!(
+            BillId::parse_with_congress("hr3076-116", 117),
+            Ok(BillId::new(116, BillType::Hr, 3076))
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_citations() {
+        for citation in [
+            "",
+            "H.R.",
+            "X. 12-117",
+            "H.R. 12a-117",
+            "hr3076-",
+            "hr3076-0",
+        ] {
+            assert!(citation.parse::<BillId>().is_err(), "{citation}");
+        }
+        assert!(matches!(
+            "hr1-300".parse::<BillId>(),
+            Err(ParseCitationError::InvalidCongress { congress: 300, .. })
+        ));
+        assert_eq!(
+            "hr1-120".parse::<BillId>(),
+            Ok(BillId::new(120, BillType::Hr, 1))
+        );
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let id: BillId = "S.J.Res. 5 (118th Congress)".parse().unwrap();
+
+        assert_eq!(BillNumber::from(id).endpoint(), "bill/118/sjres/5");
+        assert_eq!(
+            ActionsBuilder::from(id).build().unwrap().endpoint(),
+            "bill/118/sjres/5/actions"
+        );
+        assert_eq!(
+            TitlesBuilder::from(id).build().unwrap().endpoint(),
+            "bill/118/sjres/5/titles"
+        );
+    }
+}
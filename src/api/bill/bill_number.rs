@@ -2,14 +2,16 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::bill::BillId, api::common::Format, api::endpoint::Endpoint, api::params::QueryParams,
+};
 
 /// Represents the /bill/:congress/:billtype/:billnumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct BillNumber {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     bill_type: crate::api::common::BillType,
     #[builder(setter(into))]
@@ -24,6 +26,17 @@ impl BillNumber {
     }
 }
 
+impl From<BillId> for BillNumber {
+    fn from(id: BillId) -> Self {
+        Self {
+            congress: id.congress,
+            bill_type: id.bill_type,
+            bill_number: id.number,
+            format: Format::default(),
+        }
+    }
+}
+
 impl Endpoint for BillNumber {
     fn method(&self) -> Method {
         Method::GET
@@ -14,7 +14,7 @@ use crate::{
 #[builder(setter(strip_option))]
 pub struct BillType {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     bill_type: crate::api::common::BillType,
     #[builder(default)]
@@ -52,8 +52,8 @@ impl Endpoint for BillType {
         params.push("format", self.format);
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
         params.push_opt("sort", self.sort);
 
         params
@@ -0,0 +1,400 @@
+use std::error::Error;
+use std::fmt;
+
+use futures_util::join;
+
+use crate::api::{
+    bill::{
+        models::{
+            Bill, BillAction, BillActionsResponse, BillAmendment, BillAmendmentsResponse,
+            BillCommitteesResponse, BillCosponsorsResponse, BillResponse, BillSubjectsResponse,
+            BillSummariesResponse, BillSummary, BillTextResponse, BillTitle, BillTitlesResponse,
+            Cosponsor, LegislativeSubject, RelatedBill, RelatedBillsResponse, TextVersion,
+        },
+        ActionsBuilder, AmendmentsBuilder, BillId, BillNumber, BillStatus, CommitteesBuilder,
+        CosponsorsBuilder, RelatedBillsBuilder, SubjectsBuilder, SummariesBuilder, TextBuilder,
+        TitlesBuilder,
+    },
+    client::Client,
+    committee::models::CommitteeReferral,
+    error::ApiError,
+    paged::all_pages,
+    query::Query,
+};
+
+/// One of the endpoints making up a [`BillDossier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DossierSection {
+    Bill,
+    Actions,
+    Amendments,
+    Committees,
+    Cosponsors,
+    RelatedBills,
+    Subjects,
+    Summaries,
+    Text,
+    Titles,
+}
+
+impl DossierSection {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DossierSection::Bill => "bill",
+            DossierSection::Actions => "actions",
+            DossierSection::Amendments => "amendments",
+            DossierSection::Committees => "committees",
+            DossierSection::Cosponsors => "cosponsors",
+            DossierSection::RelatedBills => "related bills",
+            DossierSection::Subjects => "subjects",
+            DossierSection::Summaries => "summaries",
+            DossierSection::Text => "text",
+            DossierSection::Titles => "titles",
+        }
+    }
+}
+
+impl fmt::Display for DossierSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A section of a [`BillDossier`] which could not be fetched completely.
+#[derive(Debug)]
+pub struct SectionFailure {
+    pub section: DossierSection,
+    pub error: Box<dyn Error + Send + Sync>,
+}
+
+impl fmt::Display for SectionFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to fetch {}: {}", self.section, self.error)
+    }
+}
+
+/// A bill together with everything the API knows about it.
+///
+/// The dossier is fetched from the bill's ten endpoints concurrently, with
+/// every paginated section exhausted. A section which fails does not fail
+/// the dossier: it is recorded in `failures`, and any items fetched before
+/// the failure are kept.
+///
+/// ```no_run
+/// # async fn example(client: &congressdotgov_rs::Cdg) {
+/// use congressdotgov_rs::api::bill::{BillDossier, BillId};
+/// use congressdotgov_rs::api::common::BillType;
+///
+/// let dossier = BillDossier::fetch(client, BillId::new(117, BillType::Hr, 3076)).await;
+/// for failure in &dossier.failures {
+///     eprintln!("{failure}");
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub struct BillDossier {
+    pub id: BillId,
+    /// The bill itself, including its sponsors, laws and policy area.
+    pub bill: Option<Bill>,
+    pub actions: Vec<BillAction>,
+    pub amendments: Vec<BillAmendment>,
+    pub committees: Vec<CommitteeReferral>,
+    pub cosponsors: Vec<Cosponsor>,
+    pub related_bills: Vec<RelatedBill>,
+    pub subjects: Vec<LegislativeSubject>,
+    pub summaries: Vec<BillSummary>,
+    pub text_versions: Vec<TextVersion>,
+    pub titles: Vec<BillTitle>,
+    pub failures: Vec<SectionFailure>,
+}
+
+impl BillDossier {
+    /// Fetches every section of the bill's dossier.
+    pub async fn fetch<C: Client>(client: &C, id: BillId) -> Self {
+        let bill = async {
+            Query::<BillResponse, C>::query(&BillNumber::from(id), client)
+                .await
+                .map(|res| res.bill)
+        };
+        let (
+            bill,
+            actions,
+            amendments,
+            committees,
+            cosponsors,
+            related,
+            subjects,
+            summaries,
+            text,
+            titles,
+        ) = join!(
+            bill,
+            all_pages::<BillActionsResponse, _, _, _>(client, |offset, limit| {
+                ActionsBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillAmendmentsResponse, _, _, _>(client, |offset, limit| {
+                AmendmentsBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillCommitteesResponse, _, _, _>(client, |offset, limit| {
+                CommitteesBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillCosponsorsResponse, _, _, _>(client, |offset, limit| {
+                CosponsorsBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<RelatedBillsResponse, _, _, _>(client, |offset, limit| {
+                RelatedBillsBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillSubjectsResponse, _, _, _>(client, |offset, limit| {
+                SubjectsBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillSummariesResponse, _, _, _>(client, |offset, limit| {
+                SummariesBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillTextResponse, _, _, _>(client, |offset, limit| {
+                TextBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<BillTitlesResponse, _, _, _>(client, |offset, limit| {
+                TitlesBuilder::from(id)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+        );
+
+        let mut failures = Vec::new();
+        let bill = match bill {
+            Ok(bill) => Some(bill),
+            Err(err) => {
+                failures.push(SectionFailure::new(DossierSection::Bill, err));
+                None
+            }
+        };
+        let mut sections = Sections::default();
+        let actions = sections.collect(DossierSection::Actions, actions);
+        let amendments = sections.collect(DossierSection::Amendments, amendments);
+        let committees = sections.collect(DossierSection::Committees, committees);
+        let cosponsors = sections.collect(DossierSection::Cosponsors, cosponsors);
+        let related_bills = sections.collect(DossierSection::RelatedBills, related);
+        let subjects = sections.collect(DossierSection::Subjects, subjects);
+        let summaries = sections.collect(DossierSection::Summaries, summaries);
+        let text_versions = sections.collect(DossierSection::Text, text);
+        let titles = sections.collect(DossierSection::Titles, titles);
+        failures.extend(sections.failures);
+
+        BillDossier {
+            id,
+            bill,
+            actions,
+            amendments,
+            committees,
+            cosponsors,
+            related_bills,
+            subjects,
+            summaries,
+            text_versions,
+            titles,
+            failures,
+        }
+    }
+
+    /// Where the bill stands, classified from its actions.
+    pub fn status(&self) -> BillStatus {
+        BillStatus::from_actions(&self.actions)
+    }
+
+    /// Whether every section was fetched completely.
+    pub fn is_complete(&self) -> bool {
+        self.failures.is_empty()
+    }
+
+    /// The failure of a section, if it could not be fetched completely.
+    pub fn failure(&self, section: DossierSection) -> Option<&SectionFailure> {
+        self.failures.iter().find(|f| f.section == section)
+    }
+}
+
+/// Collects the failures of the paginated sections.
+#[derive(Default)]
+struct Sections {
+    failures: Vec<SectionFailure>,
+}
+
+impl Sections {
+    fn collect<T, E>(
+        &mut self,
+        section: DossierSection,
+        (items, err): (Vec<T>, Option<ApiError<E>>),
+    ) -> Vec<T>
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        if let Some(err) = err {
+            self.failures.push(SectionFailure::new(section, err));
+        }
+        items
+    }
+}
+
+impl SectionFailure {
+    fn new<E>(section: DossierSection, err: ApiError<E>) -> Self
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        SectionFailure {
+            section,
+            error: Box::new(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::{api::common::BillType, test::MockClient};
+
+    use super::*;
+
+    const BILL: &str = r#"{"bill": {"congress": 117, "number": "3076", "type": "HR", "title": "Postal Service Reform Act of 2022", "laws": [{"number": "117-108", "type": "Public Law"}]}}"#;
+
+    fn action(text: &str) -> String {
+        format!(r#"{{"actionDate": "2022-04-06", "text": "{text}", "type": "Floor"}}"#)
+    }
+
+    fn cosponsor(bioguide_id: &str) -> String {
+        format!(r#"{{"bioguideId": "{bioguide_id}", "isOriginalCosponsor": true}}"#)
+    }
+
+    /// A client answering every section of H.R. 3076 with an empty page.
+    fn client() -> MockClient {
+        let empty = r#"{"pagination": {"count": 0}}"#;
+        [
+            "actions",
+            "amendments",
+            "committees",
+            "cosponsors",
+            "relatedbills",
+            "subjects",
+            "summaries",
+            "text",
+            "titles",
+        ]
+        .into_iter()
+        .fold(
+            MockClient::new().respond("bill/117/hr/3076", BILL),
+            |client, section| {
+                client.respond(
+                    &format!("bill/117/hr/3076/{section}?offset=0&limit=250"),
+                    empty,
+                )
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn exhausts_pagination() {
+        let client = client()
+            .respond(
+                "bill/117/hr/3076/actions?offset=0&limit=250",
+                &format!(
+                    r#"{{"actions": [{}, {}], "pagination": {{"count": 3, "next": "https://api.congress.gov/v3/bill/117/hr/3076/actions?offset=2&limit=250"}}}}"#,
+                    action("Became Public Law No: 117-108."),
+                    action("Signed by President."),
+                ),
+            )
+            .respond(
+                "bill/117/hr/3076/actions?offset=2&limit=250",
+                &format!(
+                    r#"{{"actions": [{}], "pagination": {{"count": 3}}}}"#,
+                    action("Presented to President."),
+                ),
+            );
+
+        let dossier = BillDossier::fetch(&client, BillId::new(117, BillType::Hr, 3076)).await;
+
+        assert!(dossier.is_complete(), "{:?}", dossier.failures);
+        assert!(dossier.bill.unwrap().is_law());
+        assert_eq!(dossier.actions.len(), 3);
+        assert_eq!(
+            dossier.actions[2].text.as_deref(),
+            Some("Presented to President.")
+        );
+        assert!(dossier.cosponsors.is_empty());
+        assert!(!client
+            .requests()
+            .contains(&"bill/117/hr/3076/actions?offset=3&limit=250".to_string()));
+    }
+
+    #[tokio::test]
+    async fn reports_partial_failures() {
+        let client = client()
+            .respond(
+                "bill/117/hr/3076/cosponsors?offset=0&limit=250",
+                &format!(
+                    r#"{{"cosponsors": [{}], "pagination": {{"count": 2, "next": "https://api.congress.gov/v3/bill/117/hr/3076/cosponsors?offset=1&limit=250"}}}}"#,
+                    cosponsor("F000450"),
+                ),
+            )
+            .respond_with(
+                "bill/117/hr/3076/cosponsors?offset=1&limit=250",
+                StatusCode::INTERNAL_SERVER_ERROR,
+                r#"{"error": "internal error"}"#,
+            )
+            .respond_with(
+                "bill/117/hr/3076/summaries?offset=0&limit=250",
+                StatusCode::TOO_MANY_REQUESTS,
+                r#"{"error": "rate limited"}"#,
+            );
+
+        let dossier = BillDossier::fetch(&client, BillId::new(117, BillType::Hr, 3076)).await;
+
+        assert!(!dossier.is_complete());
+        assert_eq!(dossier.failures.len(), 2);
+        assert!(dossier.bill.is_some());
+        assert_eq!(dossier.cosponsors.len(), 1);
+        assert_eq!(dossier.cosponsors[0].bioguide_id, "F000450");
+        assert!(dossier.failure(DossierSection::Cosponsors).is_some());
+        assert_eq!(
+            dossier
+                .failure(DossierSection::Summaries)
+                .unwrap()
+                .to_string(),
+            "failed to fetch summaries: HTTP error: 429 Too Many Requests"
+        );
+        assert!(dossier.failure(DossierSection::Actions).is_none());
+    }
+}
@@ -0,0 +1,438 @@
+//! Downloading the published text of a bill.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use derive_builder::Builder;
+use http::{header, Method, Request};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::api::{
+    bill::{models::BillTextResponse, BillId, TextBuilder},
+    client::Client,
+    common::{BillType, TextFormatKind},
+    error::ApiError,
+    paged::all_pages,
+};
+
+/// Errors which can occur while downloading bill text.
+#[derive(Debug, Error)]
+pub enum DownloadError<E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    #[error("failed to fetch text: {}", source)]
+    Api {
+        #[from]
+        source: ApiError<E>,
+    },
+    #[error("failed to write text: {}", source)]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+    #[error("could not parse data from JSON: {}", source)]
+    DataType {
+        #[from]
+        source: serde_json::Error,
+    },
+    #[error("HTTP error fetching {}: {}", url, status)]
+    Http {
+        url: String,
+        status: http::StatusCode,
+    },
+    #[error("expected {} from {}, got {}", expected.as_str(), url, found.as_deref().unwrap_or("no content type"))]
+    ContentType {
+        url: String,
+        expected: TextFormatKind,
+        found: Option<String>,
+    },
+}
+
+/// A text file stored by a [`TextDownload`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DownloadedText {
+    /// The version of the text, e.g. "Enrolled Bill".
+    pub version: Option<String>,
+    pub date: Option<DateTime<Utc>>,
+    pub format: TextFormatKind,
+    pub is_errata: bool,
+    pub url: String,
+    /// The name of the file, relative to the download directory.
+    pub file: String,
+    pub content_type: String,
+    pub bytes: u64,
+}
+
+/// The files downloaded for a bill, stored next to them as
+/// `<slug>.manifest.json`, e.g. `hr3076-117.manifest.json`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TextManifest {
+    pub congress: u16,
+    pub bill_type: BillType,
+    pub number: u32,
+    pub files: Vec<DownloadedText>,
+}
+
+impl TextManifest {
+    /// The path of the manifest of a bill's text in `dir`.
+    pub fn path_for(dir: &Path, bill: BillId) -> PathBuf {
+        dir.join(format!("{}.manifest.json", bill.slug()))
+    }
+
+    /// Loads the manifest of a bill's text in `dir`, if there is one.
+    pub fn load(dir: &Path, bill: BillId) -> io::Result<Option<Self>> {
+        match fs::read(Self::path_for(dir, bill)) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn save(&self, dir: &Path, bill: BillId) -> io::Result<()> {
+        let path = Self::path_for(dir, bill);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(tmp, path)
+    }
+}
+
+/// A download of the text versions of a bill in one format.
+///
+/// The versions are listed with the /bill/:congress/:billType/:billNumber/text
+/// endpoint and each file is fetched through the client without the API key,
+/// since the text is hosted outside of the API. Files are checked to have the
+/// content type of their format and stored under their published names, such
+/// as `BILLS-117hr3076enr.xml`, with a [`TextManifest`] listing them. Files
+/// already in the manifest are not downloaded again.
+///
+/// ```no_run
+/// # async fn example(client: &congressdotgov_rs::Cdg) -> Result<(), Box<dyn std::error::Error>> {
+/// use congressdotgov_rs::api::bill::{BillId, TextDownload};
+/// use congressdotgov_rs::api::common::{BillType, TextFormatKind};
+///
+/// let download = TextDownload::builder()
+///     .bill(BillId::new(117, BillType::Hr, 3076))
+///     .format(TextFormatKind::FormattedXml)
+///     .versions(vec!["enrolled".to_string()])
+///     .build()?;
+/// let manifest = download.run(client, "text").await?;
+/// println!("{}", manifest.files[0].file);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Builder)]
+#[builder(setter(strip_option))]
+pub struct TextDownload {
+    bill: BillId,
+    format: TextFormatKind,
+    /// The versions to download, matched against the version type ignoring
+    /// case, e.g. "introduced" or "enrolled". Every version if empty.
+    #[builder(default)]
+    versions: Vec<String>,
+}
+
+impl TextDownload {
+    pub fn builder() -> TextDownloadBuilder {
+        TextDownloadBuilder::default()
+    }
+
+    /// Downloads the text into `dir`, creating it if needed.
+    pub async fn run<C: Client>(
+        &self,
+        client: &C,
+        dir: impl AsRef<Path>,
+    ) -> Result<TextManifest, DownloadError<C::Error>> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+
+        let (versions, err) = all_pages::<BillTextResponse, _, _, _>(client, |offset, limit| {
+            TextBuilder::from(self.bill)
+                .offset(offset)
+                .limit(limit)
+                .build()
+                .unwrap()
+        })
+        .await;
+        if let Some(err) = err {
+            return Err(err.into());
+        }
+
+        let mut manifest = TextManifest::load(dir, self.bill)?.unwrap_or(TextManifest {
+            congress: self.bill.congress,
+            bill_type: self.bill.bill_type,
+            number: self.bill.number,
+            files: Vec::new(),
+        });
+
+        for version in versions
+            .iter()
+            .filter(|v| self.wants(v.version_type.as_deref()))
+        {
+            for format in version.formats.iter().filter(|f| f.kind == self.format) {
+                let downloaded = manifest
+                    .files
+                    .iter()
+                    .any(|file| file.url == format.url && dir.join(&file.file).is_file());
+                if downloaded {
+                    continue;
+                }
+
+                let (content_type, body) = fetch(client, &format.url, self.format).await?;
+                let file = file_name(&format.url, self.bill, manifest.files.len());
+                let tmp = dir.join(format!("{file}.tmp"));
+                fs::write(&tmp, &body)?;
+                fs::rename(tmp, dir.join(&file))?;
+
+                manifest.files.retain(|f| f.url != format.url);
+                manifest.files.push(DownloadedText {
+                    version: version.version_type.clone(),
+                    date: version.date,
+                    format: format.kind,
+                    is_errata: format.is_errata.unwrap_or(false),
+                    url: format.url.clone(),
+                    file,
+                    content_type,
+                    bytes: body.len() as u64,
+                });
+                manifest.save(dir, self.bill)?;
+            }
+        }
+
+        Ok(manifest)
+    }
+
+    fn wants(&self, version: Option<&str>) -> bool {
+        if self.versions.is_empty() {
+            return true;
+        }
+        let Some(version) = version.map(str::to_lowercase) else {
+            return false;
+        };
+        self.versions
+            .iter()
+            .any(|wanted| version.contains(&wanted.to_lowercase()))
+    }
+}
+
+/// The content types a format may be served as, or `None` to accept any.
+fn content_types(format: TextFormatKind) -> Option<&'static [&'static str]> {
+    match format {
+        TextFormatKind::FormattedText | TextFormatKind::GeneratedHtml => Some(&["text/html"]),
+        TextFormatKind::Pdf => Some(&["application/pdf"]),
+        TextFormatKind::FormattedXml => Some(&["application/xml", "text/xml"]),
+        TextFormatKind::Other => None,
+    }
+}
+
+/// Fetches a text file, checking that it succeeded with the content type of
+/// its format.
+async fn fetch<C: Client>(
+    client: &C,
+    url: &str,
+    format: TextFormatKind,
+) -> Result<(String, bytes::Bytes), DownloadError<C::Error>> {
+    let uri = url.parse::<http::Uri>().map_err(ApiError::from)?;
+    let req = Request::builder().method(Method::GET).uri(uri);
+    let rsp = client.rest(req, Vec::new()).await?;
+
+    let status = rsp.status();
+    if !status.is_success() {
+        return Err(DownloadError::Http {
+            url: url.to_string(),
+            status,
+        });
+    }
+
+    let found = rsp
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let media_type = found
+        .as_deref()
+        .and_then(|found| found.split(';').next())
+        .map(|media_type| media_type.trim().to_ascii_lowercase());
+    let accepted = match content_types(format) {
+        None => true,
+        Some(expected) => media_type.is_some_and(|media_type| expected.contains(&&*media_type)),
+    };
+    if !accepted {
+        return Err(DownloadError::ContentType {
+            url: url.to_string(),
+            expected: format,
+            found,
+        });
+    }
+
+    Ok((found.unwrap_or_default(), rsp.into_body()))
+}
+
+/// The published name of a file, or a name made from the bill when its URL
+/// has none.
+fn file_name(url: &str, bill: BillId, index: usize) -> String {
+    url.split(['?', '#'])
+        .next()
+        .and_then(|path| path.rsplit('/').next())
+        .filter(|name| !name.is_empty() && *name != "." && *name != "..")
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("{}-{index}", bill.slug()))
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+    use url::Url;
+
+    use crate::{auth::Auth, cdg::Cdg, test::StubServer};
+
+    use super::*;
+
+    const XML: &[u8] = b"<?xml version=\"1.0\"?><bill/>";
+
+    fn dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cdg-text-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    /// A server answering the text endpoint of H.R. 3076 and its files, and a
+    /// client pointed at it.
+    fn server(xml_type: &str) -> (StubServer, Cdg) {
+        let server = StubServer::start();
+        let text = format!(
+            r#"{{"textVersions": [
+                {{"date": "2022-04-06T04:00:00Z", "type": "Enrolled Bill", "formats": [
+                    {{"type": "Formatted Text", "url": "{}"}},
+                    {{"type": "Formatted XML", "url": "{}"}}
+                ]}},
+                {{"date": "2021-05-11T04:00:00Z", "type": "Introduced in House", "formats": [
+                    {{"type": "Formatted XML", "url": "{}"}}
+                ]}}
+            ], "pagination": {{"count": 2}}}}"#,
+            server.url("/117/bills/hr3076/BILLS-117hr3076enr.htm"),
+            server.url("/117/bills/hr3076/BILLS-117hr3076enr.xml"),
+            server.url("/117/bills/hr3076/BILLS-117hr3076ih.xml"),
+        );
+        let server = server
+            .route("/v3/bill/117/hr/3076/text", "application/json", text)
+            .route(
+                "/117/bills/hr3076/BILLS-117hr3076enr.htm",
+                "text/html",
+                "<html></html>",
+            )
+            .route("/117/bills/hr3076/BILLS-117hr3076enr.xml", xml_type, XML)
+            .route("/117/bills/hr3076/BILLS-117hr3076ih.xml", xml_type, XML);
+
+        let client = Cdg {
+            base_url: Url::parse(&server.url("/v3/")).unwrap(),
+            ..Cdg::new(Auth::Token("KEY".into())).unwrap()
+        };
+        (server, client)
+    }
+
+    fn download(versions: &[&str]) -> TextDownload {
+        TextDownload::builder()
+            .bill(BillId::new(117, BillType::Hr, 3076))
+            .format(TextFormatKind::FormattedXml)
+            .versions(versions.iter().map(|v| v.to_string()).collect())
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn downloads_versions() {
+        let (_server, client) = server("application/xml; charset=utf-8");
+        let dir = dir("versions");
+
+        let manifest = download(&[]).run(&client, &dir).await.unwrap();
+
+        let files: Vec<_> = manifest.files.iter().map(|f| f.file.as_str()).collect();
+        assert_eq!(files, ["BILLS-117hr3076enr.xml", "BILLS-117hr3076ih.xml"]);
+        assert_eq!(manifest.files[0].version.as_deref(), Some("Enrolled Bill"));
+        assert_eq!(manifest.files[0].bytes, XML.len() as u64);
+        assert_eq!(fs::read(dir.join("BILLS-117hr3076ih.xml")).unwrap(), XML);
+
+        let saved = TextManifest::load(&dir, BillId::new(117, BillType::Hr, 3076))
+            .unwrap()
+            .unwrap();
+        assert_eq!(saved, manifest);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn filters_versions_and_skips_downloaded() {
+        let (_server, client) = server("text/xml");
+        let dir = dir("filter");
+
+        let manifest = download(&["ENROLLED"]).run(&client, &dir).await.unwrap();
+        assert_eq!(manifest.files.len(), 1);
+        assert_eq!(manifest.files[0].file, "BILLS-117hr3076enr.xml");
+
+        // A second run only adds the versions which are missing.
+        fs::write(dir.join("BILLS-117hr3076enr.xml"), b"kept").unwrap();
+        let manifest = download(&[]).run(&client, &dir).await.unwrap();
+        assert_eq!(manifest.files.len(), 2);
+        assert_eq!(
+            fs::read(dir.join("BILLS-117hr3076enr.xml")).unwrap(),
+            b"kept"
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn rejects_wrong_content_type() {
+        let (_server, client) = server("text/html");
+        let dir = dir("content-type");
+
+        let err = download(&["introduced"])
+            .run(&client, &dir)
+            .await
+            .unwrap_err();
+
+        assert!(
+            matches!(
+                &err,
+                DownloadError::ContentType { expected: TextFormatKind::FormattedXml, found, .. }
+                    if found.as_deref() == Some("text/html")
+            ),
+            "{err:?}"
+        );
+        assert!(!dir.join("BILLS-117hr3076ih.xml").exists());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn reports_missing_files() {
+        let (server, client) = server("application/xml");
+
+        let err = fetch(
+            &client,
+            &server.url("/117/bills/hr3076/missing.xml"),
+            TextFormatKind::FormattedXml,
+        )
+        .await
+        .unwrap_err();
+
+        assert!(
+            matches!(
+                err,
+                DownloadError::Http {
+                    status: StatusCode::NOT_FOUND,
+                    ..
+                }
+            ),
+            "{err:?}"
+        );
+    }
+}
@@ -0,0 +1,602 @@
+//! Typed response models for the Bill endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    amendments::{AmendmentId, CongressionalAmendmentType},
+    bill::BillId,
+    committee::models::{CommitteeRef, CommitteeReferral},
+    common::{BillType, CommitteeChamber, LatestAction, Pagination, ResourceCount, TextFormat},
+    de,
+    paged::impl_page,
+};
+
+/// The sponsor of a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Sponsor {
+    pub bioguide_id: String,
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    pub full_name: Option<String>,
+    pub party: Option<String>,
+    pub state: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub district: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_flag")]
+    pub is_by_request: Option<bool>,
+    pub url: Option<String>,
+}
+
+/// A law a bill became, e.g. "117-108".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillLaw {
+    pub number: String,
+    #[serde(rename = "type")]
+    pub law_type: Option<String>,
+}
+
+/// A cost estimate of a bill by the Congressional Budget Office.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CboCostEstimate {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub pub_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// A committee report on a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillCommitteeReport {
+    pub citation: String,
+    pub url: Option<String>,
+}
+
+/// The policy area of a bill, one per bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PolicyArea {
+    pub name: String,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// A bill, as returned by both the list and detail endpoints.
+///
+/// The list endpoints only return the congress, type, number, title, origin
+/// chamber, latest action and update dates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Bill {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub bill_type: BillType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub title: Option<String>,
+    pub origin_chamber: Option<CommitteeChamber>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub introduced_date: Option<NaiveDate>,
+    pub latest_action: Option<LatestAction>,
+    pub policy_area: Option<PolicyArea>,
+    #[serde(default)]
+    pub sponsors: Vec<Sponsor>,
+    #[serde(default)]
+    pub laws: Vec<BillLaw>,
+    #[serde(default)]
+    pub cbo_cost_estimates: Vec<CboCostEstimate>,
+    #[serde(default)]
+    pub committee_reports: Vec<BillCommitteeReport>,
+    pub constitutional_authority_statement_text: Option<String>,
+    pub actions: Option<ResourceCount>,
+    pub amendments: Option<ResourceCount>,
+    pub committees: Option<ResourceCount>,
+    pub cosponsors: Option<ResourceCount>,
+    pub related_bills: Option<ResourceCount>,
+    pub subjects: Option<ResourceCount>,
+    pub summaries: Option<ResourceCount>,
+    pub text_versions: Option<ResourceCount>,
+    pub titles: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date_including_text: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl Bill {
+    /// The identifier of the bill, used to query its other endpoints.
+    pub fn id(&self) -> BillId {
+        BillId::new(self.congress, self.bill_type, self.number)
+    }
+
+    /// Whether the bill has become a public or private law.
+    pub fn is_law(&self) -> bool {
+        !self.laws.is_empty()
+    }
+}
+
+/// The system that recorded an action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SourceSystem {
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub code: Option<u32>,
+    pub name: String,
+}
+
+/// A roll call vote taken as part of an action.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecordedVote {
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(deserialize_with = "de::number")]
+    pub roll_number: u32,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session_number: Option<u32>,
+    pub url: Option<String>,
+}
+
+/// An action taken on a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillAction {
+    pub action_code: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub action_date: Option<NaiveDate>,
+    pub action_time: Option<String>,
+    pub text: Option<String>,
+    #[serde(rename = "type")]
+    pub action_type: Option<String>,
+    pub source_system: Option<SourceSystem>,
+    #[serde(default)]
+    pub committees: Vec<CommitteeRef>,
+    #[serde(default)]
+    pub recorded_votes: Vec<RecordedVote>,
+}
+
+impl BillAction {
+    /// The chamber which took the action, from the system which recorded it.
+    ///
+    /// Actions recorded by the Library of Congress have no chamber.
+    pub fn chamber(&self) -> Option<CommitteeChamber> {
+        let source = self.source_system.as_ref()?;
+        match source.code {
+            Some(0) => Some(CommitteeChamber::Senate),
+            Some(1 | 2) => Some(CommitteeChamber::House),
+            Some(_) => None,
+            None if source.name.starts_with("House") => Some(CommitteeChamber::House),
+            None if source.name.starts_with("Senate") => Some(CommitteeChamber::Senate),
+            None => None,
+        }
+    }
+}
+
+/// An amendment to a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillAmendment {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub amendment_type: CongressionalAmendmentType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub description: Option<String>,
+    pub purpose: Option<String>,
+    pub latest_action: Option<LatestAction>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl BillAmendment {
+    /// The identifier of the amendment, used to query its endpoints.
+    pub fn id(&self) -> AmendmentId {
+        AmendmentId::new(self.congress, self.amendment_type, self.number)
+    }
+}
+
+/// A cosponsor of a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Cosponsor {
+    pub bioguide_id: String,
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    pub full_name: Option<String>,
+    pub party: Option<String>,
+    pub state: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub district: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_flag")]
+    pub is_original_cosponsor: Option<bool>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub sponsorship_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub sponsorship_withdrawn_date: Option<NaiveDate>,
+    pub url: Option<String>,
+}
+
+impl Cosponsor {
+    /// Whether the member has withdrawn their cosponsorship.
+    pub fn is_withdrawn(&self) -> bool {
+        self.sponsorship_withdrawn_date.is_some()
+    }
+}
+
+/// How a related bill is related, and who identified the relationship.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelationshipDetail {
+    pub identified_by: Option<String>,
+    #[serde(rename = "type")]
+    pub relationship_type: String,
+}
+
+/// A bill related to another bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelatedBill {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub bill_type: BillType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub title: Option<String>,
+    pub latest_action: Option<LatestAction>,
+    #[serde(default)]
+    pub relationship_details: Vec<RelationshipDetail>,
+    pub url: Option<String>,
+}
+
+impl RelatedBill {
+    /// The identifier of the related bill.
+    pub fn id(&self) -> BillId {
+        BillId::new(self.congress, self.bill_type, self.number)
+    }
+}
+
+/// A legislative subject term of a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct LegislativeSubject {
+    pub name: String,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// The subjects of a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillSubjects {
+    #[serde(default)]
+    pub legislative_subjects: Vec<LegislativeSubject>,
+    pub policy_area: Option<PolicyArea>,
+}
+
+/// A summary of a bill written by the Congressional Research Service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillSummary {
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub action_date: Option<NaiveDate>,
+    pub action_desc: Option<String>,
+    /// The summary as HTML.
+    pub text: Option<String>,
+    pub version_code: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// A published version of the text of a bill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TextVersion {
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub date: Option<DateTime<Utc>>,
+    #[serde(rename = "type")]
+    pub version_type: Option<String>,
+    #[serde(default)]
+    pub formats: Vec<TextFormat>,
+}
+
+/// A title of a bill, such as its display, official or short title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillTitle {
+    pub title: String,
+    pub title_type: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub title_type_code: Option<u32>,
+    pub bill_text_version_code: Option<String>,
+    pub bill_text_version_name: Option<String>,
+    pub chamber_code: Option<String>,
+    pub chamber_name: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// The response of the /bill, /bill/:congress and /bill/:congress/:billType
+/// endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillsResponse {
+    #[serde(default)]
+    pub bills: Vec<Bill>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillResponse {
+    pub bill: Bill,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/actions
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillActionsResponse {
+    #[serde(default)]
+    pub actions: Vec<BillAction>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/amendments
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillAmendmentsResponse {
+    #[serde(default)]
+    pub amendments: Vec<BillAmendment>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/committees
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillCommitteesResponse {
+    #[serde(default)]
+    pub committees: Vec<CommitteeReferral>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/cosponsors
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillCosponsorsResponse {
+    #[serde(default)]
+    pub cosponsors: Vec<Cosponsor>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/relatedbills
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelatedBillsResponse {
+    #[serde(default)]
+    pub related_bills: Vec<RelatedBill>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/subjects
+/// endpoint. Only the legislative subjects are paginated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillSubjectsResponse {
+    #[serde(default)]
+    pub subjects: BillSubjects,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/summaries
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillSummariesResponse {
+    #[serde(default)]
+    pub summaries: Vec<BillSummary>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/text endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillTextResponse {
+    #[serde(default)]
+    pub text_versions: Vec<TextVersion>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /bill/:congress/:billType/:billNumber/titles
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BillTitlesResponse {
+    #[serde(default)]
+    pub titles: Vec<BillTitle>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl_page!(
+    BillsResponse => bills: Bill,
+    BillActionsResponse => actions: BillAction,
+    BillAmendmentsResponse => amendments: BillAmendment,
+    BillCommitteesResponse => committees: CommitteeReferral,
+    BillCosponsorsResponse => cosponsors: Cosponsor,
+    RelatedBillsResponse => related_bills: RelatedBill,
+    BillSummariesResponse => summaries: BillSummary,
+    BillTextResponse => text_versions: TextVersion,
+    BillTitlesResponse => titles: BillTitle,
+);
+
+impl crate::api::paged::Page for BillSubjectsResponse {
+    type Item = LegislativeSubject;
+
+    fn into_page(self) -> (Vec<LegislativeSubject>, Pagination) {
+        (self.subjects.legislative_subjects, self.pagination)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bill_detail() {
+        let json = r#"{
+            "bill": {
+                "actions": {"count": 74, "url": "https://api.congress.gov/v3/bill/117/hr/3076/actions?format=json"},
+                "cboCostEstimates": [{"description": "As ordered reported by the House Committee on Oversight and Reform on May 13, 2021\n", "pubDate": "2021-07-14T17:27:00Z", "title": "H.R. 3076, Postal Service Reform Act of 2021", "url": "https://www.cbo.gov/publication/57356"}],
+                "committeeReports": [{"citation": "H. Rept. 117-89,Part 1", "url": "https://api.congress.gov/v3/committee-report/117/HRPT/89?format=json"}],
+                "congress": 117,
+                "cosponsors": {"count": 102, "countIncludingWithdrawnCosponsors": 102, "url": "https://api.congress.gov/v3/bill/117/hr/3076/cosponsors?format=json"},
+                "introducedDate": "2021-05-11",
+                "latestAction": {"actionDate": "2022-04-06", "text": "Became Public Law No: 117-108."},
+                "laws": [{"number": "117-108", "type": "Public Law"}],
+                "number": "3076",
+                "originChamber": "House",
+                "originChamberCode": "H",
+                "policyArea": {"name": "Government Operations and Politics"},
+                "sponsors": [{"bioguideId": "M000087", "district": 12, "firstName": "CAROLYN", "fullName": "Rep. Maloney, Carolyn B. [D-NY-12]", "isByRequest": "N", "lastName": "MALONEY", "middleName": "B.", "party": "D", "state": "NY", "url": "https://api.congress.gov/v3/member/M000087?format=json"}],
+                "title": "Postal Service Reform Act of 2022",
+                "type": "HR",
+                "updateDate": "2022-09-29T03:27:05Z",
+                "updateDateIncludingText": "2022-09-29T03:27:05Z"
+            }
+        }"#;
+
+        let bill = serde_json::from_str::<BillResponse>(json).unwrap().bill;
+        assert_eq!(bill.id().to_string(), "H.R. 3076 (117th Congress)");
+        assert!(bill.is_law());
+        assert_eq!(bill.origin_chamber, Some(CommitteeChamber::House));
+        assert_eq!(bill.sponsors[0].district, Some(12));
+        assert_eq!(bill.sponsors[0].is_by_request, Some(false));
+        assert_eq!(bill.cosponsors.unwrap().count, 102);
+        assert_eq!(
+            bill.policy_area.unwrap().name,
+            "Government Operations and Politics"
+        );
+    }
+
+    #[test]
+    fn bill_actions() {
+        let json = r#"{
+            "actions": [
+                {"actionCode": "36000", "actionDate": "2022-04-06", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Became Public Law No: 117-108.", "type": "BecameLaw"},
+                {"actionDate": "2022-03-08", "committees": [], "recordedVotes": [{"chamber": "Senate", "congress": 117, "date": "2022-03-08T23:20:43Z", "rollNumber": 71, "sessionNumber": 2, "url": "https://www.senate.gov/legislative/LIS/roll_call_votes/vote1172/vote_117_2_00071.xml"}], "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Passed Senate without amendment by Yea-Nay Vote. 79 - 19. Record Vote Number: 71.", "type": "Floor"}
+            ],
+            "pagination": {"count": 74, "next": "https://api.congress.gov/v3/bill/117/hr/3076/actions?offset=2&limit=2&format=json"}
+        }"#;
+
+        let res: BillActionsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.actions[0].action_type.as_deref(), Some("BecameLaw"));
+        assert_eq!(res.actions[1].recorded_votes[0].roll_number, 71);
+        assert_eq!(
+            res.actions[1].recorded_votes[0].chamber,
+            Some(CommitteeChamber::Senate)
+        );
+        assert_eq!(res.pagination.count, 74);
+    }
+
+    #[test]
+    fn bill_sections() {
+        let cosponsors: BillCosponsorsResponse = serde_json::from_str(
+            r#"{"cosponsors": [{"bioguideId": "F000450", "district": 5, "firstName": "Virginia", "fullName": "Rep. Foxx, Virginia [R-NC-5]", "isOriginalCosponsor": true, "lastName": "Foxx", "party": "R", "sponsorshipDate": "2021-05-11", "state": "NC"}], "pagination": {"count": 102, "countIncludingWithdrawnCosponsors": 102}}"#,
+        )
+        .unwrap();
+        assert_eq!(cosponsors.cosponsors[0].is_original_cosponsor, Some(true));
+        assert!(!cosponsors.cosponsors[0].is_withdrawn());
+
+        let related: RelatedBillsResponse = serde_json::from_str(
+            r#"{"relatedBills": [{"congress": 117, "latestAction": {"actionDate": "2021-10-21", "text": "Referred to the Committee on Homeland Security and Governmental Affairs."}, "number": 1720, "relationshipDetails": [{"identifiedBy": "CRS", "type": "Related bill"}], "title": "Postal Service Reform Act of 2021", "type": "S"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            related.related_bills[0].id().to_string(),
+            "S. 1720 (117th Congress)"
+        );
+
+        let subjects: BillSubjectsResponse = serde_json::from_str(
+            r#"{"pagination": {"count": 17}, "subjects": {"legislativeSubjects": [{"name": "Congressional oversight", "updateDate": "2022-04-14T16:47:03Z"}], "policyArea": {"name": "Government Operations and Politics"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(subjects.subjects.legislative_subjects.len(), 1);
+
+        let text: BillTextResponse = serde_json::from_str(
+            r#"{"textVersions": [{"date": null, "formats": [{"type": "Formatted Text", "url": "https://www.congress.gov/117/bills/hr3076/BILLS-117hr3076enr.htm"}], "type": "Enrolled Bill"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(text.text_versions[0].date, None);
+        assert_eq!(text.text_versions[0].formats.len(), 1);
+
+        let titles: BillTitlesResponse = serde_json::from_str(
+            r#"{"titles": [{"title": "Postal Service Reform Act of 2022", "titleType": "Display Title", "titleTypeCode": 45, "updateDate": "2022-04-14T16:47:03Z"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(titles.titles[0].title_type_code, Some(45));
+
+        let amendments: BillAmendmentsResponse = serde_json::from_str(
+            r#"{"amendments": [{"congress": 117, "description": "An amendment numbered 1 printed in Part B of House Report 117-243 to ...", "number": "173", "type": "HAMDT", "updateDate": "2022-08-06T00:43:33Z"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            amendments.amendments[0].id().to_string(),
+            "H.Amdt. 173 (117th Congress)"
+        );
+    }
+}
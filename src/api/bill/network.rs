@@ -0,0 +1,545 @@
+//! A member-to-member graph of who cosponsors whose bills.
+
+use std::collections::BTreeMap;
+use std::io::{self, Write};
+
+use futures_util::{join, stream, StreamExt};
+
+use crate::api::{
+    bill::{
+        models::{Bill, BillCosponsorsResponse, BillResponse, BillsResponse, Cosponsor, Sponsor},
+        BillId, BillNumber, Congress, CosponsorsBuilder,
+    },
+    client::Client,
+    error::ApiError,
+    paged::all_pages,
+    query::Query,
+};
+
+/// The number of bills whose details and cosponsors are fetched at once.
+const FETCH_CONCURRENCY: usize = 8;
+
+/// A member appearing in a [`CosponsorshipGraph`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MemberNode {
+    pub bioguide_id: String,
+    pub name: Option<String>,
+    pub party: Option<String>,
+    pub state: Option<String>,
+    /// The number of bills in the graph the member sponsored.
+    pub sponsored: u32,
+}
+
+impl MemberNode {
+    fn update(&mut self, name: Option<&String>, party: Option<&String>, state: Option<&String>) {
+        self.name = self.name.take().or_else(|| name.cloned());
+        self.party = self.party.take().or_else(|| party.cloned());
+        self.state = self.state.take().or_else(|| state.cloned());
+    }
+}
+
+/// The cosponsorships of one member on another member's bills.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CosponsorshipEdge {
+    /// Bills cosponsored on the day they were introduced.
+    pub original: u32,
+    /// Bills cosponsored after they were introduced.
+    pub later: u32,
+    /// Bills whose cosponsorship was later withdrawn.
+    pub withdrawn: u32,
+}
+
+impl CosponsorshipEdge {
+    /// The number of bills the cosponsor is still a cosponsor of.
+    pub fn weight(&self) -> u32 {
+        self.original + self.later
+    }
+}
+
+/// A directed graph with an edge from each cosponsor to the sponsor of every
+/// bill they cosponsored, weighted by the number of such bills.
+///
+/// The graph is built from bill details and their cosponsors, either with
+/// [`add_bill`](Self::add_bill) or by fetching them, and can be exported to
+/// GraphML, GEXF or a CSV edge list. Edges whose cosponsorships were all
+/// withdrawn are kept in the graph but left out of exports.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CosponsorshipGraph {
+    nodes: BTreeMap<String, MemberNode>,
+    edges: BTreeMap<(String, String), CosponsorshipEdge>,
+    bills: u32,
+}
+
+impl CosponsorshipGraph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Fetches the details and cosponsors of the given bills, several bills
+    /// at a time, and builds their graph.
+    pub async fn fetch_bills<C, I>(client: &C, ids: I) -> Result<Self, ApiError<C::Error>>
+    where
+        C: Client,
+        I: IntoIterator<Item = BillId>,
+    {
+        let mut fetches = stream::iter(ids)
+            .map(|id| async move {
+                let bill = BillNumber::from(id);
+                let (bill, cosponsors) = join!(
+                    bill.query(client),
+                    all_pages::<BillCosponsorsResponse, _, _, _>(client, |offset, limit| {
+                        CosponsorsBuilder::from(id)
+                            .offset(offset)
+                            .limit(limit)
+                            .build()
+                            .unwrap()
+                    }),
+                );
+
+                let BillResponse { bill } = bill?;
+                match cosponsors {
+                    (_, Some(err)) => Err(err),
+                    (cosponsors, None) => Ok((bill, cosponsors)),
+                }
+            })
+            .buffered(FETCH_CONCURRENCY);
+
+        let mut graph = Self::new();
+        while let Some(fetched) = fetches.next().await {
+            let (bill, cosponsors) = fetched?;
+            graph.add_bill(&bill, &cosponsors);
+        }
+
+        Ok(graph)
+    }
+
+    /// Fetches every bill of a congress and builds their graph.
+    ///
+    /// This makes at least two requests per bill, so a whole congress takes
+    /// tens of thousands of requests.
+    pub async fn fetch_congress<C: Client>(
+        client: &C,
+        congress: u16,
+    ) -> Result<Self, ApiError<C::Error>> {
+        let (bills, err) = all_pages::<BillsResponse, _, _, _>(client, |offset, limit| {
+            Congress::builder()
+                .congress(congress)
+                .offset(offset)
+                .limit(limit)
+                .build()
+                .unwrap()
+        })
+        .await;
+        if let Some(err) = err {
+            return Err(err);
+        }
+
+        Self::fetch_bills(client, bills.iter().map(Bill::id)).await
+    }
+
+    /// Adds a bill's sponsors and cosponsors to the graph.
+    pub fn add_bill(&mut self, bill: &Bill, cosponsors: &[Cosponsor]) {
+        self.bills += 1;
+
+        for Sponsor {
+            bioguide_id,
+            full_name,
+            party,
+            state,
+            ..
+        } in &bill.sponsors
+        {
+            let node = self.node(bioguide_id);
+            node.update(full_name.as_ref(), party.as_ref(), state.as_ref());
+            node.sponsored += 1;
+        }
+
+        for cosponsor in cosponsors {
+            self.node(&cosponsor.bioguide_id).update(
+                cosponsor.full_name.as_ref(),
+                cosponsor.party.as_ref(),
+                cosponsor.state.as_ref(),
+            );
+
+            for sponsor in &bill.sponsors {
+                if sponsor.bioguide_id == cosponsor.bioguide_id {
+                    continue;
+                }
+
+                let edge = self
+                    .edges
+                    .entry((cosponsor.bioguide_id.clone(), sponsor.bioguide_id.clone()))
+                    .or_default();
+                if cosponsor.is_withdrawn() {
+                    edge.withdrawn += 1;
+                } else if cosponsor.is_original_cosponsor == Some(true) {
+                    edge.original += 1;
+                } else {
+                    edge.later += 1;
+                }
+            }
+        }
+    }
+
+    fn node(&mut self, bioguide_id: &str) -> &mut MemberNode {
+        self.nodes
+            .entry(bioguide_id.to_string())
+            .or_insert_with(|| MemberNode {
+                bioguide_id: bioguide_id.to_string(),
+                ..MemberNode::default()
+            })
+    }
+
+    /// The number of bills added to the graph.
+    pub fn bills(&self) -> u32 {
+        self.bills
+    }
+
+    /// The members in the graph, by Bioguide ID.
+    pub fn nodes(&self) -> impl Iterator<Item = &MemberNode> {
+        self.nodes.values()
+    }
+
+    /// A member in the graph.
+    pub fn node_for(&self, bioguide_id: &str) -> Option<&MemberNode> {
+        self.nodes.get(bioguide_id)
+    }
+
+    /// The edges of the graph as (cosponsor, sponsor, edge), by cosponsor
+    /// and then sponsor.
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str, &CosponsorshipEdge)> {
+        self.edges
+            .iter()
+            .map(|((source, target), edge)| (source.as_str(), target.as_str(), edge))
+    }
+
+    /// The edges written by the exports, leaving out those of weight 0.
+    fn exported_edges(&self) -> impl Iterator<Item = (&str, &str, &CosponsorshipEdge)> {
+        self.edges().filter(|(_, _, edge)| edge.weight() > 0)
+    }
+
+    /// The edge from a cosponsor to a sponsor.
+    pub fn edge(&self, cosponsor: &str, sponsor: &str) -> Option<&CosponsorshipEdge> {
+        self.edges
+            .get(&(cosponsor.to_string(), sponsor.to_string()))
+    }
+
+    /// Writes the graph as GraphML.
+    pub fn write_graphml<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            out,
+            r#"<graphml xmlns="http://graphml.graphdrawing.org/xmlns">"#
+        )?;
+        for (id, target, name, kind) in [
+            ("name", "node", "name", "string"),
+            ("party", "node", "party", "string"),
+            ("state", "node", "state", "string"),
+            ("sponsored", "node", "sponsored", "int"),
+            ("weight", "edge", "weight", "int"),
+            ("original", "edge", "original", "int"),
+            ("later", "edge", "later", "int"),
+            ("withdrawn", "edge", "withdrawn", "int"),
+        ] {
+            writeln!(
+                out,
+                r#"  <key id="{id}" for="{target}" attr.name="{name}" attr.type="{kind}"/>"#
+            )?;
+        }
+        writeln!(
+            out,
+            r#"  <graph id="cosponsorship" edgedefault="directed">"#
+        )?;
+
+        for node in self.nodes() {
+            writeln!(out, r#"    <node id="{}">"#, xml_escape(&node.bioguide_id))?;
+            for (key, value) in node_attributes(node) {
+                writeln!(
+                    out,
+                    r#"      <data key="{key}">{}</data>"#,
+                    xml_escape(&value)
+                )?;
+            }
+            writeln!(out, "    </node>")?;
+        }
+
+        for (source, target, edge) in self.exported_edges() {
+            writeln!(
+                out,
+                r#"    <edge source="{}" target="{}">"#,
+                xml_escape(source),
+                xml_escape(target),
+            )?;
+            for (key, value) in edge_attributes(edge) {
+                writeln!(out, r#"      <data key="{key}">{value}</data>"#)?;
+            }
+            writeln!(out, "    </edge>")?;
+        }
+
+        writeln!(out, "  </graph>")?;
+        writeln!(out, "</graphml>")
+    }
+
+    /// Writes the graph as GEXF 1.3.
+    pub fn write_gexf<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(out, r#"<gexf xmlns="http://gexf.net/1.3" version="1.3">"#)?;
+        writeln!(out, r#"  <graph mode="static" defaultedgetype="directed">"#)?;
+        writeln!(out, r#"    <attributes class="node">"#)?;
+        for (id, title, kind) in [
+            (0, "party", "string"),
+            (1, "state", "string"),
+            (2, "sponsored", "integer"),
+        ] {
+            writeln!(
+                out,
+                r#"      <attribute id="{id}" title="{title}" type="{kind}"/>"#
+            )?;
+        }
+        writeln!(out, "    </attributes>")?;
+        writeln!(out, r#"    <attributes class="edge">"#)?;
+        for (id, title) in [(0, "original"), (1, "later"), (2, "withdrawn")] {
+            writeln!(
+                out,
+                r#"      <attribute id="{id}" title="{title}" type="integer"/>"#
+            )?;
+        }
+        writeln!(out, "    </attributes>")?;
+
+        writeln!(out, "    <nodes>")?;
+        for node in self.nodes() {
+            let label = node.name.as_deref().unwrap_or(&node.bioguide_id);
+            writeln!(
+                out,
+                r#"      <node id="{}" label="{}">"#,
+                xml_escape(&node.bioguide_id),
+                xml_escape(label),
+            )?;
+            writeln!(out, "        <attvalues>")?;
+            let values = [
+                node.party.clone(),
+                node.state.clone(),
+                Some(node.sponsored.to_string()),
+            ];
+            for (id, value) in values.iter().enumerate() {
+                if let Some(value) = value {
+                    writeln!(
+                        out,
+                        r#"          <attvalue for="{id}" value="{}"/>"#,
+                        xml_escape(value)
+                    )?;
+                }
+            }
+            writeln!(out, "        </attvalues>")?;
+            writeln!(out, "      </node>")?;
+        }
+        writeln!(out, "    </nodes>")?;
+
+        writeln!(out, "    <edges>")?;
+        for (id, (source, target, edge)) in self.exported_edges().enumerate() {
+            writeln!(
+                out,
+                r#"      <edge id="{id}" source="{}" target="{}" weight="{}">"#,
+                xml_escape(source),
+                xml_escape(target),
+                edge.weight(),
+            )?;
+            writeln!(out, "        <attvalues>")?;
+            for (id, value) in [edge.original, edge.later, edge.withdrawn]
+                .iter()
+                .enumerate()
+            {
+                writeln!(out, r#"          <attvalue for="{id}" value="{value}"/>"#)?;
+            }
+            writeln!(out, "        </attvalues>")?;
+            writeln!(out, "      </edge>")?;
+        }
+        writeln!(out, "    </edges>")?;
+
+        writeln!(out, "  </graph>")?;
+        writeln!(out, "</gexf>")
+    }
+
+    /// Writes the edges of the graph as CSV, with a header row of `source`,
+    /// `target`, `weight`, `original`, `later` and `withdrawn`.
+    pub fn write_csv<W: Write>(&self, mut out: W) -> io::Result<()> {
+        writeln!(out, "source,target,weight,original,later,withdrawn")?;
+        for (source, target, edge) in self.exported_edges() {
+            writeln!(
+                out,
+                "{},{},{},{},{},{}",
+                csv_escape(source),
+                csv_escape(target),
+                edge.weight(),
+                edge.original,
+                edge.later,
+                edge.withdrawn,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn node_attributes(node: &MemberNode) -> Vec<(&'static str, String)> {
+    [
+        ("name", node.name.clone()),
+        ("party", node.party.clone()),
+        ("state", node.state.clone()),
+        ("sponsored", Some(node.sponsored.to_string())),
+    ]
+    .into_iter()
+    .filter_map(|(key, value)| Some((key, value?)))
+    .collect()
+}
+
+fn edge_attributes(edge: &CosponsorshipEdge) -> [(&'static str, u32); 4] {
+    [
+        ("weight", edge.weight()),
+        ("original", edge.original),
+        ("later", edge.later),
+        ("withdrawn", edge.withdrawn),
+    ]
+}
+
+fn xml_escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{api::common::BillType, test::MockClient};
+
+    use super::*;
+
+    fn bill(number: u32, sponsor: &str) -> String {
+        format!(
+            r#"{{"bill": {{"congress": 118, "type": "HR", "number": "{number}", "sponsors": [{{"bioguideId": "{sponsor}", "fullName": "Rep. {sponsor} [D-CA-1]", "party": "D", "state": "CA"}}]}}}}"#
+        )
+    }
+
+    fn cosponsors(cosponsors: &[(&str, bool, bool)]) -> String {
+        let cosponsors = cosponsors
+            .iter()
+            .map(|(bioguide, original, withdrawn)| {
+                let withdrawn = if *withdrawn {
+                    r#", "sponsorshipWithdrawnDate": "2023-03-01""#
+                } else {
+                    ""
+                };
+                format!(
+                    r#"{{"bioguideId": "{bioguide}", "fullName": "Rep. {bioguide} & co", "party": "R", "isOriginalCosponsor": {original}{withdrawn}}}"#
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        format!(r#"{{"cosponsors": [{cosponsors}], "pagination": {{"count": 0}}}}"#)
+    }
+
+    async fn graph() -> CosponsorshipGraph {
+        let client = MockClient::new()
+            .respond("bill/118/hr/1", &bill(1, "A000001"))
+            .respond(
+                "bill/118/hr/1/cosponsors?offset=0&limit=250",
+                &cosponsors(&[("B000002", true, false), ("C000003", false, true)]),
+            )
+            .respond("bill/118/hr/2", &bill(2, "A000001"))
+            .respond(
+                "bill/118/hr/2/cosponsors?offset=0&limit=250",
+                &cosponsors(&[("B000002", false, false)]),
+            );
+
+        CosponsorshipGraph::fetch_bills(
+            &client,
+            [1, 2].map(|number| BillId::new(118, BillType::Hr, number)),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn weights_edges() {
+        let graph = graph().await;
+
+        assert_eq!(graph.bills(), 2);
+        assert_eq!(graph.nodes().count(), 3);
+        assert_eq!(graph.node_for("A000001").unwrap().sponsored, 2);
+        assert_eq!(
+            graph.node_for("B000002").unwrap().party.as_deref(),
+            Some("R")
+        );
+
+        let edge = graph.edge("B000002", "A000001").unwrap();
+        assert_eq!((edge.original, edge.later, edge.withdrawn), (1, 1, 0));
+        assert_eq!(edge.weight(), 2);
+
+        let edge = graph.edge("C000003", "A000001").unwrap();
+        assert_eq!(edge.weight(), 0);
+        assert_eq!(edge.withdrawn, 1);
+        assert!(graph.edge("A000001", "B000002").is_none());
+    }
+
+    #[tokio::test]
+    async fn exports_csv() {
+        let mut csv = Vec::new();
+        graph().await.write_csv(&mut csv).unwrap();
+
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "source,target,weight,original,later,withdrawn\n\
+             B000002,A000001,2,1,1,0\n"
+        );
+        assert_eq!(csv_escape(r#"a "b", c"#), r#""a ""b"", c""#);
+    }
+
+    /// The elements with the given tag name in a well-formed document.
+    fn count_elements(xml: &[u8], name: &str) -> usize {
+        let xml = std::str::from_utf8(xml).unwrap();
+        let document = roxmltree::Document::parse(xml).unwrap();
+        document
+            .descendants()
+            .filter(|node| node.has_tag_name(name))
+            .count()
+    }
+
+    #[tokio::test]
+    async fn exports_xml() {
+        let graph = graph().await;
+
+        let mut graphml = Vec::new();
+        graph.write_graphml(&mut graphml).unwrap();
+        assert_eq!(count_elements(&graphml, "node"), 3);
+        assert_eq!(count_elements(&graphml, "edge"), 1);
+        let graphml = String::from_utf8(graphml).unwrap();
+        assert!(graphml.contains(r#"<data key="name">Rep. B000002 &amp; co</data>"#));
+        assert!(graphml.contains(r#"<edge source="B000002" target="A000001">"#));
+
+        let mut gexf = Vec::new();
+        graph.write_gexf(&mut gexf).unwrap();
+        assert_eq!(count_elements(&gexf, "node"), 3);
+        assert_eq!(count_elements(&gexf, "edge"), 1);
+        let gexf = String::from_utf8(gexf).unwrap();
+        assert!(gexf.contains(r#"<node id="B000002" label="Rep. B000002 &amp; co">"#));
+        assert!(gexf.contains(r#"<edge id="0" source="B000002" target="A000001" weight="2">"#));
+    }
+}
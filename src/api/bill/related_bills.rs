@@ -9,7 +9,7 @@ use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryPara
 #[builder(setter(strip_option))]
 pub struct RelatedBills {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     bill_type: crate::api::common::BillType,
     #[builder(setter(into))]
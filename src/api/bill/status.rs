@@ -0,0 +1,398 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::NaiveDate;
+
+use crate::api::{bill::models::BillAction, common::CommitteeChamber};
+
+/// A stage of the legislative process a bill can reach.
+///
+/// Stages are ordered by how far along the process they are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BillStage {
+    Introduced,
+    Referred,
+    Reported,
+    PassedHouse,
+    PassedSenate,
+    ResolvingDifferences,
+    PresentedToPresident,
+    Vetoed,
+    VetoOverridden,
+    BecameLaw,
+}
+
+impl BillStage {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BillStage::Introduced => "introduced",
+            BillStage::Referred => "referred",
+            BillStage::Reported => "reported",
+            BillStage::PassedHouse => "passed House",
+            BillStage::PassedSenate => "passed Senate",
+            BillStage::ResolvingDifferences => "resolving differences",
+            BillStage::PresentedToPresident => "presented to President",
+            BillStage::Vetoed => "vetoed",
+            BillStage::VetoOverridden => "veto overridden",
+            BillStage::BecameLaw => "became law",
+        }
+    }
+
+    /// Classifies a single action, returning the stage it shows the bill
+    /// reaching.
+    ///
+    /// The Library of Congress action code is used when present, otherwise
+    /// the action type and text are. A vote passing the bill over a veto in
+    /// one chamber is classified as `VetoOverridden`, though the veto is only
+    /// overridden once both chambers have voted; see [`BillStatus`].
+    pub fn classify(action: &BillAction) -> Option<BillStage> {
+        let code = action.action_code.as_deref().unwrap_or_default();
+        let kind = action.action_type.as_deref().unwrap_or_default();
+        let text = action.text.as_deref().unwrap_or_default();
+        let lower = text.to_lowercase();
+
+        let stage = if code == "36000"
+            || kind == "BecameLaw"
+            || text.starts_with("Became Public Law")
+            || text.starts_with("Became Private Law")
+        {
+            BillStage::BecameLaw
+        } else if is_override(&lower) {
+            BillStage::VetoOverridden
+        } else if code == "31000" || lower.contains("vetoed by president") {
+            BillStage::Vetoed
+        } else if code == "28000" || text.starts_with("Presented to President") {
+            BillStage::PresentedToPresident
+        } else if kind == "ResolvingDifferences" || text.starts_with("Resolving differences") {
+            BillStage::ResolvingDifferences
+        } else if let Some(chamber) = passed(code, text, action) {
+            match chamber {
+                CommitteeChamber::Senate => BillStage::PassedSenate,
+                _ => BillStage::PassedHouse,
+            }
+        } else if code == "5000"
+            || code == "14000"
+            || (matches!(kind, "Committee" | "Calendars" | "IntroReferral")
+                && lower.contains("reported")
+                && !lower.contains("ordered to be reported"))
+        {
+            BillStage::Reported
+        } else if code == "H11100" || (kind == "IntroReferral" && lower.contains("referred to")) {
+            BillStage::Referred
+        } else if matches!(code, "Intro-H" | "1000" | "10000") || text.starts_with("Introduced in")
+        {
+            BillStage::Introduced
+        } else {
+            return None;
+        };
+
+        Some(stage)
+    }
+}
+
+impl fmt::Display for BillStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Whether the text records a successful vote to pass a bill over a veto.
+fn is_override(lower: &str) -> bool {
+    (lower.contains("over veto")
+        || lower.contains("over the president's veto")
+        || lower.contains("objections of the president to the contrary notwithstanding"))
+        && !lower.contains("failed")
+}
+
+/// The chamber which passed the bill in the action, if it records a passage.
+fn passed(code: &str, text: &str, action: &BillAction) -> Option<CommitteeChamber> {
+    if code == "8000"
+        || text.starts_with("Passed/agreed to in House")
+        || text.starts_with("Passed House")
+        || text.starts_with("On passage Passed")
+        || (text.starts_with("On motion to suspend the rules and pass")
+            && text.contains("Agreed to"))
+    {
+        Some(CommitteeChamber::House)
+    } else if code == "17000"
+        || text.starts_with("Passed/agreed to in Senate")
+        || text.starts_with("Passed Senate")
+    {
+        Some(CommitteeChamber::Senate)
+    } else if code.is_empty()
+        && action.action_type.as_deref() == Some("Floor")
+        && text.starts_with("Passed")
+    {
+        action.chamber()
+    } else {
+        None
+    }
+}
+
+/// The chamber which voted to override a veto in the action.
+fn override_chamber(action: &BillAction) -> Option<CommitteeChamber> {
+    let text = action.text.as_deref().unwrap_or_default();
+    if text.contains("House") {
+        Some(CommitteeChamber::House)
+    } else if text.contains("Senate") {
+        Some(CommitteeChamber::Senate)
+    } else {
+        action.chamber()
+    }
+}
+
+/// Where a bill stands, classified from its actions.
+///
+/// Each stage the bill reached carries the dates of the actions showing it,
+/// sorted and deduplicated; the same step is often recorded by both a
+/// chamber and the Library of Congress.
+///
+/// ```
+/// use congressdotgov_rs::api::bill::{BillStage, BillStatus};
+///
+/// let status = BillStatus::from_actions(&[]);
+/// assert_eq!(status.stage(), None);
+/// assert!(!status.reached(BillStage::Introduced));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BillStatus {
+    stages: BTreeMap<BillStage, Vec<NaiveDate>>,
+}
+
+impl BillStatus {
+    /// Classifies the actions of a bill, in any order.
+    pub fn from_actions<'a, I>(actions: I) -> Self
+    where
+        I: IntoIterator<Item = &'a BillAction>,
+    {
+        let mut stages: BTreeMap<BillStage, Vec<NaiveDate>> = BTreeMap::new();
+        let (mut house_overrides, mut senate_overrides) = (Vec::new(), Vec::new());
+
+        for action in actions {
+            let (Some(stage), Some(date)) = (BillStage::classify(action), action.action_date)
+            else {
+                continue;
+            };
+            if stage == BillStage::VetoOverridden {
+                match override_chamber(action) {
+                    Some(CommitteeChamber::House) => house_overrides.push(date),
+                    Some(CommitteeChamber::Senate) => senate_overrides.push(date),
+                    _ => {}
+                }
+            } else {
+                stages.entry(stage).or_default().push(date);
+            }
+        }
+
+        if !house_overrides.is_empty() && !senate_overrides.is_empty() {
+            house_overrides.append(&mut senate_overrides);
+            stages.insert(BillStage::VetoOverridden, house_overrides);
+        }
+        for dates in stages.values_mut() {
+            dates.sort_unstable();
+            dates.dedup();
+        }
+
+        BillStatus { stages }
+    }
+
+    /// The stage the bill stands at: the furthest along of the stages it
+    /// reached.
+    ///
+    /// Referrals and reports in the second chamber are recorded under
+    /// [`BillStage::Referred`] and [`BillStage::Reported`] without moving the
+    /// bill back from having passed the first.
+    pub fn stage(&self) -> Option<BillStage> {
+        self.stages.keys().next_back().copied()
+    }
+
+    /// Whether the bill reached a stage.
+    pub fn reached(&self, stage: BillStage) -> bool {
+        self.stages.contains_key(&stage)
+    }
+
+    /// The dates on which the bill reached a stage.
+    pub fn dates(&self, stage: BillStage) -> &[NaiveDate] {
+        self.stages
+            .get(&stage)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// The date on which the bill first reached a stage.
+    pub fn first_date(&self, stage: BillStage) -> Option<NaiveDate> {
+        self.dates(stage).first().copied()
+    }
+
+    /// The stages the bill reached along with their dates, in stage order.
+    pub fn iter(&self) -> impl Iterator<Item = (BillStage, &[NaiveDate])> {
+        self.stages
+            .iter()
+            .map(|(stage, dates)| (*stage, dates.as_slice()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn actions(json: &str) -> Vec<BillAction> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn date(s: &str) -> NaiveDate {
+        s.parse().unwrap()
+    }
+
+    /// H.R. 3076 (117th Congress), the Postal Service Reform Act of 2022.
+    const HR_3076_117: &str = r#"[
+        {"actionCode": "36000", "actionDate": "2022-04-06", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Became Public Law No: 117-108.", "type": "BecameLaw"},
+        {"actionCode": "E40000", "actionDate": "2022-04-06", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Signed by President.", "type": "President"},
+        {"actionCode": "28000", "actionDate": "2022-03-30", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Presented to President.", "type": "President"},
+        {"actionCode": "17000", "actionDate": "2022-03-08", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Passed/agreed to in Senate: Passed Senate without amendment by Yea-Nay Vote. 79 - 19. Record Vote Number: 71.", "type": "Floor"},
+        {"actionDate": "2022-03-08", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Passed Senate without amendment by Yea-Nay Vote. 79 - 19. Record Vote Number: 71.", "type": "Floor"},
+        {"actionCode": "H38310", "actionDate": "2022-02-08", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "On passage Passed by the Yeas and Nays: 342 - 92 (Roll no. 30).", "type": "Floor"},
+        {"actionCode": "8000", "actionDate": "2022-02-08", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Passed/agreed to in House: On passage Passed by the Yeas and Nays: 342 - 92 (Roll no. 30).", "type": "Floor"},
+        {"actionCode": "H12300", "actionDate": "2021-07-13", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Placed on the Union Calendar, Calendar No. 68.", "type": "Calendars"},
+        {"actionCode": "H12200", "actionDate": "2021-07-13", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Reported (Amended) by the Committee on Oversight and Reform. H. Rept. 117-89, Part I.", "type": "Committee"},
+        {"actionCode": "5000", "actionDate": "2021-07-13", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Reported (Amended) by the Committee on Oversight and Reform. H. Rept. 117-89, Part I.", "type": "Committee"},
+        {"actionDate": "2021-05-13", "sourceSystem": {"code": 1, "name": "House committee actions"}, "text": "Ordered to be Reported (Amended) by the Yeas and Nays: 38 - 4.", "type": "Committee"},
+        {"actionCode": "H11100", "actionDate": "2021-05-11", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Referred to the Committee on Oversight and Reform, and in addition to the Committee on Ways and Means.", "type": "IntroReferral"},
+        {"actionCode": "Intro-H", "actionDate": "2021-05-11", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Introduced in House", "type": "IntroReferral"},
+        {"actionCode": "1000", "actionDate": "2021-05-11", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Introduced in House", "type": "IntroReferral"}
+    ]"#;
+
+    /// H.R. 6395 (116th Congress), the National Defense Authorization Act for
+    /// Fiscal Year 2021, which became law over a veto.
+    const HR_6395_116: &str = r#"[
+        {"actionCode": "36000", "actionDate": "2021-01-01", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Became Public Law No: 116-283.", "type": "BecameLaw"},
+        {"actionDate": "2021-01-01", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Passed Senate over veto by Yea-Nay Vote. 81 - 13. Record Vote Number: 292.", "type": "Veto"},
+        {"actionCode": "H38310", "actionDate": "2020-12-28", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "On passage, the objections of the President to the contrary notwithstanding Passed by the Yeas and Nays: (2/3 required): 322 - 87 (Roll no. 253).", "type": "Veto"},
+        {"actionCode": "31000", "actionDate": "2020-12-23", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Vetoed by President.", "type": "Veto"},
+        {"actionCode": "28000", "actionDate": "2020-12-11", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Presented to President.", "type": "President"},
+        {"actionDate": "2020-12-11", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Senate agreed to conference report by Yea-Nay Vote. 84 - 13. Record Vote Number: 266.", "type": "ResolvingDifferences"},
+        {"actionCode": "H8D000", "actionDate": "2020-12-08", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "On agreeing to the conference report Agreed to by the Yeas and Nays: 335 - 78 (Roll no. 238).", "type": "ResolvingDifferences"},
+        {"actionDate": "2020-07-23", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Passed Senate with an amendment by Unanimous Consent.", "type": "Floor"},
+        {"actionCode": "H38310", "actionDate": "2020-07-21", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "On passage Passed by the Yeas and Nays: 295 - 125 (Roll no. 152).", "type": "Floor"},
+        {"actionCode": "H12200", "actionDate": "2020-07-09", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Reported (Amended) by the Committee on Armed Services. H. Rept. 116-442.", "type": "Committee"},
+        {"actionCode": "H11100", "actionDate": "2020-03-26", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Referred to the House Committee on Armed Services.", "type": "IntroReferral"},
+        {"actionCode": "Intro-H", "actionDate": "2020-03-26", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Introduced in House", "type": "IntroReferral"}
+    ]"#;
+
+    /// H.J.Res. 30 (118th Congress), vetoed with a failed override in the
+    /// House.
+    const HJRES_30_118: &str = r#"[
+        {"actionCode": "H38310", "actionDate": "2023-03-23", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "On passage, the objections of the President to the contrary notwithstanding Failed by the Yeas and Nays: (2/3 required): 219 - 200 (Roll no. 155).", "type": "Veto"},
+        {"actionCode": "31000", "actionDate": "2023-03-20", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Vetoed by President.", "type": "Veto"},
+        {"actionCode": "28000", "actionDate": "2023-03-20", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Presented to President.", "type": "President"},
+        {"actionDate": "2023-03-01", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Passed Senate without amendment by Yea-Nay Vote. 50 - 46. Record Vote Number: 43.", "type": "Floor"},
+        {"actionCode": "H38310", "actionDate": "2023-02-28", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "On passage Passed by the Yeas and Nays: 216 - 204 (Roll no. 124).", "type": "Floor"},
+        {"actionCode": "H11100", "actionDate": "2023-02-01", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Referred to the House Committee on Education and the Workforce.", "type": "IntroReferral"},
+        {"actionCode": "Intro-H", "actionDate": "2023-02-01", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Introduced in House", "type": "IntroReferral"}
+    ]"#;
+
+    /// A House bill received, referred and reported in the Senate after
+    /// passing the House.
+    const HOUSE_PASSED_IN_SENATE: &str = r#"[
+        {"actionDate": "2022-03-10", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Committee on Finance. Reported by Senator Wyden without amendment. Without written report.", "type": "Committee"},
+        {"actionDate": "2022-03-01", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Received in the Senate and Read twice and referred to the Committee on Finance.", "type": "IntroReferral"},
+        {"actionCode": "8000", "actionDate": "2022-02-08", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Passed/agreed to in House: On passage Passed by the Yeas and Nays: 222 - 210 (Roll no. 31).", "type": "Floor"},
+        {"actionCode": "H11100", "actionDate": "2021-07-19", "sourceSystem": {"code": 2, "name": "House floor actions"}, "text": "Referred to the Committee on Ways and Means.", "type": "IntroReferral"},
+        {"actionCode": "Intro-H", "actionDate": "2021-07-19", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Introduced in House", "type": "IntroReferral"}
+    ]"#;
+
+    /// S. 1720 (117th Congress), which never left committee.
+    const S_1720_117: &str = r#"[
+        {"actionDate": "2021-05-19", "sourceSystem": {"code": 0, "name": "Senate"}, "text": "Read twice and referred to the Committee on Homeland Security and Governmental Affairs.", "type": "IntroReferral"},
+        {"actionCode": "10000", "actionDate": "2021-05-19", "sourceSystem": {"code": 9, "name": "Library of Congress"}, "text": "Introduced in Senate", "type": "IntroReferral"}
+    ]"#;
+
+    #[test]
+    fn became_law() {
+        let status = BillStatus::from_actions(&actions(HR_3076_117));
+
+        assert_eq!(status.stage(), Some(BillStage::BecameLaw));
+        assert_eq!(
+            status.iter().map(|(stage, _)| stage).collect::<Vec<_>>(),
+            [
+                BillStage::Introduced,
+                BillStage::Referred,
+                BillStage::Reported,
+                BillStage::PassedHouse,
+                BillStage::PassedSenate,
+                BillStage::PresentedToPresident,
+                BillStage::BecameLaw,
+            ]
+        );
+        assert_eq!(status.dates(BillStage::Reported), [date("2021-07-13")]);
+        assert_eq!(status.dates(BillStage::PassedHouse), [date("2022-02-08")]);
+        assert_eq!(
+            status.first_date(BillStage::BecameLaw),
+            Some(date("2022-04-06"))
+        );
+        assert!(!status.reached(BillStage::Vetoed));
+    }
+
+    #[test]
+    fn veto_overridden() {
+        let status = BillStatus::from_actions(&actions(HR_6395_116));
+
+        assert_eq!(status.stage(), Some(BillStage::BecameLaw));
+        assert_eq!(
+            status.dates(BillStage::ResolvingDifferences),
+            [date("2020-12-08"), date("2020-12-11")]
+        );
+        assert_eq!(status.dates(BillStage::Vetoed), [date("2020-12-23")]);
+        assert_eq!(
+            status.dates(BillStage::VetoOverridden),
+            [date("2020-12-28"), date("2021-01-01")]
+        );
+        assert_eq!(status.dates(BillStage::PassedSenate), [date("2020-07-23")]);
+    }
+
+    #[test]
+    fn vetoed() {
+        let status = BillStatus::from_actions(&actions(HJRES_30_118));
+
+        assert_eq!(status.stage(), Some(BillStage::Vetoed));
+        assert!(!status.reached(BillStage::VetoOverridden));
+        assert!(!status.reached(BillStage::Reported));
+        assert_eq!(
+            status.first_date(BillStage::PresentedToPresident),
+            Some(date("2023-03-20"))
+        );
+    }
+
+    #[test]
+    fn one_chamber_override_is_not_overridden() {
+        let actions = actions(HR_6395_116);
+        let status = BillStatus::from_actions(actions.iter().filter(|action| {
+            action.chamber() != Some(CommitteeChamber::Senate)
+                || action.action_type.as_deref() != Some("Veto")
+        }));
+
+        assert!(!status.reached(BillStage::VetoOverridden));
+        assert_eq!(status.stage(), Some(BillStage::BecameLaw));
+    }
+
+    #[test]
+    fn second_chamber_referral_keeps_passage() {
+        let status = BillStatus::from_actions(&actions(HOUSE_PASSED_IN_SENATE));
+
+        assert_eq!(status.stage(), Some(BillStage::PassedHouse));
+        assert_eq!(
+            status.dates(BillStage::Referred),
+            [date("2021-07-19"), date("2022-03-01")]
+        );
+        assert_eq!(status.dates(BillStage::Reported), [date("2022-03-10")]);
+    }
+
+    #[test]
+    fn referred() {
+        let status = BillStatus::from_actions(&actions(S_1720_117));
+
+        assert_eq!(status.stage(), Some(BillStage::Referred));
+        assert_eq!(status.dates(BillStage::Introduced), [date("2021-05-19")]);
+        assert!(!status.reached(BillStage::PassedSenate));
+    }
+}
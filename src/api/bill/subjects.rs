@@ -10,7 +10,7 @@ use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryPara
 #[builder(setter(strip_option))]
 pub struct Subjects {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     bill_type: crate::api::common::BillType,
     #[builder(setter(into))]
@@ -54,8 +54,8 @@ impl Endpoint for Subjects {
         params.push("format", self.format);
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
 
         params
     }
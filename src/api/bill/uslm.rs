@@ -0,0 +1,839 @@
+//! Parsing the Formatted XML text of bills into their structure.
+//!
+//! Bill texts are published in two XML vocabularies: the older Bill DTD,
+//! used by most versions, and the United States Legislative Markup (USLM)
+//! schema, used by some enrolled bills and resolutions. Both are read into
+//! the same [`Document`] of nested [`Provision`]s, which can be flattened
+//! into plain text keeping the path of every line:
+//!
+//! ```rust
+//! use congressdotgov_rs::api::bill::uslm::Document;
+//!
+//! let xml = r#"<bill>
+//!     <form><official-title>To name a post office.</official-title></form>
+//!     <legis-body>
+//!         <section><enum>1.</enum><header>Designation</header>
+//!             <subsection><enum>(a)</enum><text>The facility shall be known as the Post Office.</text></subsection>
+//!         </section>
+//!     </legis-body>
+//! </bill>"#;
+//!
+//! let document = Document::parse(xml).unwrap();
+//! assert_eq!(document.title.as_deref(), Some("To name a post office."));
+//! assert_eq!(
+//!     document.to_plain_text(),
+//!     "sec. 1 Designation\nsec. 1(a) The facility shall be known as the Post Office.\n"
+//! );
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use roxmltree::{Node, ParsingOptions};
+use thiserror::Error;
+
+/// Errors which can occur when parsing bill XML.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("could not parse XML: {}", source)]
+    Xml {
+        #[from]
+        source: roxmltree::Error,
+    },
+    #[error("no bill body found")]
+    MissingBody,
+}
+
+/// A level of the structure of a bill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ProvisionKind {
+    Division,
+    Title,
+    Subtitle,
+    Part,
+    Subpart,
+    Chapter,
+    Subchapter,
+    Section,
+    Subsection,
+    Paragraph,
+    Subparagraph,
+    Clause,
+    Subclause,
+    Item,
+    Subitem,
+}
+
+impl ProvisionKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ProvisionKind::Division => "division",
+            ProvisionKind::Title => "title",
+            ProvisionKind::Subtitle => "subtitle",
+            ProvisionKind::Part => "part",
+            ProvisionKind::Subpart => "subpart",
+            ProvisionKind::Chapter => "chapter",
+            ProvisionKind::Subchapter => "subchapter",
+            ProvisionKind::Section => "section",
+            ProvisionKind::Subsection => "subsection",
+            ProvisionKind::Paragraph => "paragraph",
+            ProvisionKind::Subparagraph => "subparagraph",
+            ProvisionKind::Clause => "clause",
+            ProvisionKind::Subclause => "subclause",
+            ProvisionKind::Item => "item",
+            ProvisionKind::Subitem => "subitem",
+        }
+    }
+
+    /// The kind named by an element of either vocabulary, which share these
+    /// names.
+    fn from_tag(tag: &str) -> Option<Self> {
+        Some(match tag {
+            "division" => ProvisionKind::Division,
+            "title" => ProvisionKind::Title,
+            "subtitle" => ProvisionKind::Subtitle,
+            "part" => ProvisionKind::Part,
+            "subpart" => ProvisionKind::Subpart,
+            "chapter" => ProvisionKind::Chapter,
+            "subchapter" => ProvisionKind::Subchapter,
+            "section" => ProvisionKind::Section,
+            "subsection" => ProvisionKind::Subsection,
+            "paragraph" => ProvisionKind::Paragraph,
+            "subparagraph" => ProvisionKind::Subparagraph,
+            "clause" => ProvisionKind::Clause,
+            "subclause" => ProvisionKind::Subclause,
+            "item" => ProvisionKind::Item,
+            "subitem" => ProvisionKind::Subitem,
+            _ => return None,
+        })
+    }
+
+    /// The abbreviation starting the path of a provision above subsections,
+    /// e.g. "sec." or "title".
+    fn abbreviation(self) -> Option<&'static str> {
+        match self {
+            ProvisionKind::Division => Some("div."),
+            ProvisionKind::Title => Some("title"),
+            ProvisionKind::Subtitle => Some("subtitle"),
+            ProvisionKind::Part => Some("pt."),
+            ProvisionKind::Subpart => Some("subpt."),
+            ProvisionKind::Chapter => Some("ch."),
+            ProvisionKind::Subchapter => Some("subch."),
+            ProvisionKind::Section => Some("sec."),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ProvisionKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A division, title, section, paragraph or other level of a bill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Provision {
+    pub kind: ProvisionKind,
+    pub id: Option<String>,
+    /// The enumerator as printed, e.g. "SEC. 2." or "(a)".
+    pub enumerator: Option<String>,
+    pub header: Option<String>,
+    /// The text before any nested provisions.
+    pub text: Option<String>,
+    pub children: Vec<Block>,
+    /// The text following the nested provisions.
+    pub continuation: Option<String>,
+    designation: Option<String>,
+}
+
+impl Provision {
+    /// The enumerator without its decoration, e.g. "2" for "SEC. 2." or "a"
+    /// for "(a)".
+    pub fn designation(&self) -> Option<&str> {
+        self.designation.as_deref()
+    }
+
+    /// The nested provisions, leaving out text and quoted blocks.
+    pub fn provisions(&self) -> impl Iterator<Item = &Provision> {
+        self.children.iter().filter_map(|block| match block {
+            Block::Provision(provision) => Some(provision),
+            _ => None,
+        })
+    }
+
+    /// The path of this provision below a parent path, e.g. "sec. 2(a)".
+    ///
+    /// Sections and the levels above them start a new path, since sections
+    /// are numbered throughout a bill.
+    fn path(&self, parent: &str) -> String {
+        let designation = self.designation().unwrap_or("?");
+        match self.kind.abbreviation() {
+            Some(abbreviation) => format!("{abbreviation} {designation}"),
+            None => format!("{parent}({designation})"),
+        }
+    }
+
+    fn flatten_into(&self, parent: &str, lines: &mut Vec<Line>) {
+        let path = self.path(parent);
+        let text = match (&self.header, &self.text) {
+            (Some(header), Some(text)) => Some(format!("{} {text}", with_stop(header))),
+            (header, text) => header.clone().or_else(|| text.clone()),
+        };
+        if let Some(text) = text {
+            lines.push(Line {
+                path: path.clone(),
+                text,
+            });
+        }
+        for block in &self.children {
+            block.flatten_into(&path, lines);
+        }
+        if let Some(text) = &self.continuation {
+            lines.push(Line {
+                path,
+                text: text.clone(),
+            });
+        }
+    }
+}
+
+/// Text quoted by a bill, usually the provisions it inserts into a law.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct QuotedBlock {
+    pub blocks: Vec<Block>,
+    /// The text following the closing quote, such as "; and".
+    pub after: Option<String>,
+}
+
+impl QuotedBlock {
+    /// The quoted text on a single line.
+    pub fn text(&self) -> String {
+        let mut lines = Vec::new();
+        for block in &self.blocks {
+            block.flatten_into("", &mut lines);
+        }
+        lines
+            .into_iter()
+            .map(|line| line.text)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// An entry of the body of a bill or of a provision.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Block {
+    Provision(Provision),
+    QuotedBlock(QuotedBlock),
+    /// Text outside of any provision, such as a preamble clause.
+    Text(String),
+}
+
+impl Block {
+    fn flatten_into(&self, parent: &str, lines: &mut Vec<Line>) {
+        match self {
+            Block::Provision(provision) => provision.flatten_into(parent, lines),
+            Block::QuotedBlock(quoted) => lines.push(Line {
+                path: parent.to_string(),
+                text: format!(
+                    "\u{201c}{}\u{201d}{}",
+                    quoted.text(),
+                    quoted.after.as_deref().unwrap_or_default()
+                ),
+            }),
+            Block::Text(text) => lines.push(Line {
+                path: parent.to_string(),
+                text: text.clone(),
+            }),
+        }
+    }
+}
+
+/// An entry of the table of contents of a bill.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TocEntry {
+    /// The level of the entry, e.g. "section" or "title".
+    pub level: Option<String>,
+    /// The designator of the entry, e.g. "Sec. 1.", when marked up apart
+    /// from its label.
+    pub designator: Option<String>,
+    pub label: String,
+    /// The ID of the provision the entry refers to.
+    pub target: Option<String>,
+}
+
+/// A line of plain text along with the path of its provision, e.g.
+/// "sec. 2(a)(1)". Text outside of any provision has an empty path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Line {
+    pub path: String,
+    pub text: String,
+}
+
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path.is_empty() {
+            f.write_str(&self.text)
+        } else {
+            write!(f, "{} {}", self.path, self.text)
+        }
+    }
+}
+
+/// The structured text of a bill or resolution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Document {
+    /// The official title, e.g. "To amend title 10, United States Code...".
+    pub title: Option<String>,
+    pub table_of_contents: Vec<TocEntry>,
+    pub body: Vec<Block>,
+}
+
+impl Document {
+    /// Parses the Formatted XML text of a bill in either the Bill DTD or the
+    /// USLM vocabulary.
+    pub fn parse(xml: &str) -> Result<Self, ParseError> {
+        let options = ParsingOptions {
+            allow_dtd: true,
+            ..ParsingOptions::default()
+        };
+        let xml = roxmltree::Document::parse_with_options(xml, options)?;
+        let root = xml.root_element();
+
+        let body = root
+            .descendants()
+            .find(|node| {
+                matches!(
+                    node.tag_name().name(),
+                    "legis-body" | "resolution-body" | "engrossed-amendment-body" | "main"
+                )
+            })
+            .ok_or(ParseError::MissingBody)?;
+
+        let title = ["official-title", "officialTitle", "docTitle", "title"]
+            .iter()
+            .find_map(|tag| {
+                root.descendants()
+                    .filter(|node| !is_within(*node, body))
+                    .find(|node| node.tag_name().name() == *tag)
+                    .map(text_of)
+                    .filter(|title| !title.is_empty())
+            });
+
+        let table_of_contents = body
+            .descendants()
+            .filter(|node| node.tag_name().name() == "toc" && !in_quote(*node, body))
+            .flat_map(|toc| toc.children().filter_map(toc_entry))
+            .collect();
+
+        Ok(Self {
+            title,
+            table_of_contents,
+            body: blocks(body),
+        })
+    }
+
+    /// Every provision of the bill outside of quoted blocks, in document
+    /// order.
+    pub fn provisions(&self) -> Vec<&Provision> {
+        fn walk<'a>(blocks: &'a [Block], out: &mut Vec<&'a Provision>) {
+            for block in blocks {
+                if let Block::Provision(provision) = block {
+                    out.push(provision);
+                    walk(&provision.children, out);
+                }
+            }
+        }
+
+        let mut provisions = Vec::new();
+        walk(&self.body, &mut provisions);
+        provisions
+    }
+
+    /// The sections of the bill, wherever they are nested.
+    pub fn sections(&self) -> impl Iterator<Item = &Provision> {
+        self.provisions()
+            .into_iter()
+            .filter(|provision| provision.kind == ProvisionKind::Section)
+    }
+
+    /// The text of the bill as lines with the path of their provision.
+    pub fn flatten(&self) -> Vec<Line> {
+        let mut lines = Vec::new();
+        for block in &self.body {
+            block.flatten_into("", &mut lines);
+        }
+        lines
+    }
+
+    /// The text of the bill with one line per provision, each starting with
+    /// its path, e.g. "sec. 2(a)(1) In general.--".
+    pub fn to_plain_text(&self) -> String {
+        self.flatten()
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect()
+    }
+}
+
+impl FromStr for Document {
+    type Err = ParseError;
+
+    fn from_str(xml: &str) -> Result<Self, Self::Err> {
+        Self::parse(xml)
+    }
+}
+
+/// Reads the blocks among the children of a body, provision or quote.
+fn blocks(parent: Node) -> Vec<Block> {
+    let mut blocks = Vec::new();
+    for node in parent.children().filter(Node::is_element) {
+        let tag = node.tag_name().name();
+        if let Some(kind) = ProvisionKind::from_tag(tag) {
+            blocks.push(Block::Provision(provision(node, kind)));
+        } else if is_quote(tag) {
+            blocks.push(Block::QuotedBlock(quoted_block(node)));
+        } else if !is_skipped(tag) {
+            // A wrapper such as a preamble, whose provisions are hoisted and
+            // whose text is kept as is.
+            blocks.extend(blocks_or_text(node));
+        }
+    }
+    blocks
+}
+
+fn blocks_or_text(node: Node) -> Vec<Block> {
+    let has_structure = node.descendants().skip(1).any(|n| {
+        let tag = n.tag_name().name();
+        ProvisionKind::from_tag(tag).is_some() || is_quote(tag)
+    });
+    if has_structure {
+        blocks(node)
+    } else {
+        let text = text_of(node);
+        if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![Block::Text(text)]
+        }
+    }
+}
+
+fn provision(node: Node, kind: ProvisionKind) -> Provision {
+    let mut provision = Provision {
+        kind,
+        id: node
+            .attribute("id")
+            .or_else(|| node.attribute("identifier"))
+            .map(str::to_string),
+        enumerator: None,
+        header: None,
+        text: None,
+        children: Vec::new(),
+        continuation: None,
+        designation: None,
+    };
+
+    let mut text = Vec::new();
+    for child in node.children().filter(Node::is_element) {
+        let tag = child.tag_name().name();
+        match tag {
+            "enum" | "num" => {
+                let enumerator = text_of(child);
+                provision.designation = child
+                    .attribute("value")
+                    .map(str::to_string)
+                    .or_else(|| designation(&enumerator));
+                provision.enumerator = Some(enumerator).filter(|e| !e.is_empty());
+            }
+            "header" | "heading" => {
+                provision.header = Some(text_of(child)).filter(|h| !h.is_empty());
+            }
+            "text" | "content" | "chapeau" => {
+                let own = text_without_quotes(child);
+                if !own.is_empty() {
+                    if provision.children.is_empty() {
+                        text.push(own);
+                    } else {
+                        provision.children.push(Block::Text(own));
+                    }
+                }
+                provision.children.extend(
+                    quotes_within(child)
+                        .into_iter()
+                        .map(|quote| Block::QuotedBlock(quoted_block(quote))),
+                );
+            }
+            "continuation-text" | "continuation" => {
+                provision.continuation = Some(text_of(child)).filter(|c| !c.is_empty());
+            }
+            _ if is_skipped(tag) => {}
+            _ => {
+                if let Some(kind) = ProvisionKind::from_tag(tag) {
+                    provision
+                        .children
+                        .push(Block::Provision(self::provision(child, kind)));
+                } else if is_quote(tag) {
+                    provision
+                        .children
+                        .push(Block::QuotedBlock(quoted_block(child)));
+                } else {
+                    provision.children.extend(blocks_or_text(child));
+                }
+            }
+        }
+    }
+
+    provision.text = Some(text.join(" ")).filter(|t| !t.is_empty());
+    provision
+}
+
+fn quoted_block(node: Node) -> QuotedBlock {
+    let after = node
+        .children()
+        .find(|child| child.tag_name().name() == "after-quoted-block")
+        .or_else(|| {
+            // USLM puts the closing text after the quote, in the same
+            // element.
+            node.next_sibling()
+                .filter(|next| next.is_text())
+                .filter(|_| node.tag_name().name() == "quotedContent")
+        })
+        .map(text_of)
+        .filter(|after| !after.is_empty());
+
+    let blocks = if node
+        .descendants()
+        .skip(1)
+        .any(|n| ProvisionKind::from_tag(n.tag_name().name()).is_some())
+    {
+        blocks(node)
+    } else {
+        let text = text_without(node, |n| n.tag_name().name() == "after-quoted-block");
+        if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![Block::Text(text)]
+        }
+    };
+
+    QuotedBlock { blocks, after }
+}
+
+fn toc_entry(node: Node) -> Option<TocEntry> {
+    match node.tag_name().name() {
+        "toc-entry" => Some(TocEntry {
+            level: node.attribute("level").map(str::to_string),
+            designator: None,
+            label: text_of(node),
+            target: node.attribute("idref").map(str::to_string),
+        }),
+        "referenceItem" => {
+            let part = |tag| {
+                node.children()
+                    .find(|child| child.tag_name().name() == tag)
+                    .map(text_of)
+            };
+            Some(TocEntry {
+                level: node.attribute("role").map(str::to_string),
+                designator: part("designator"),
+                label: part("label").unwrap_or_else(|| text_of(node)),
+                target: node.attribute("idref").map(str::to_string),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn is_quote(tag: &str) -> bool {
+    matches!(tag, "quoted-block" | "quotedContent")
+}
+
+/// Elements which are not part of the text of a bill.
+fn is_skipped(tag: &str) -> bool {
+    matches!(tag, "toc" | "after-quoted-block" | "meta" | "metadata")
+}
+
+fn is_within(node: Node, ancestor: Node) -> bool {
+    node.ancestors().any(|n| n == ancestor)
+}
+
+/// Whether a node of the body is inside a quoted block.
+fn in_quote(node: Node, body: Node) -> bool {
+    node.ancestors()
+        .take_while(|n| *n != body)
+        .any(|n| is_quote(n.tag_name().name()))
+}
+
+/// The quoted blocks directly within a text element.
+fn quotes_within<'a, 'input>(node: Node<'a, 'input>) -> Vec<Node<'a, 'input>> {
+    node.descendants()
+        .skip(1)
+        .filter(|n| is_quote(n.tag_name().name()))
+        .filter(|n| {
+            !n.ancestors()
+                .skip(1)
+                .take_while(|a| *a != node)
+                .any(|a| is_quote(a.tag_name().name()))
+        })
+        .collect()
+}
+
+fn text_of(node: Node) -> String {
+    match node.text().filter(|_| node.is_text()) {
+        Some(text) => text.split_whitespace().collect::<Vec<_>>().join(" "),
+        None => text_without(node, |_| false),
+    }
+}
+
+/// The text of a text element, leaving out the quotes within it and, in USLM,
+/// the text closing them.
+fn text_without_quotes(node: Node) -> String {
+    text_without(node, |n| {
+        let tag = n.tag_name().name();
+        is_quote(tag)
+            || tag == "toc"
+            || (n.is_text()
+                && n.prev_sibling()
+                    .is_some_and(|prev| prev.tag_name().name() == "quotedContent"))
+    })
+}
+
+/// The text of a node with whitespace collapsed, leaving out the nodes
+/// matching `skip`.
+fn text_without(node: Node, skip: impl Fn(Node) -> bool) -> String {
+    fn collect(node: Node, skip: &dyn Fn(Node) -> bool, out: &mut String) {
+        for child in node.children() {
+            if skip(child) {
+                continue;
+            }
+            if child.is_text() {
+                out.push_str(child.text().unwrap_or_default());
+            } else if child.is_element() {
+                collect(child, skip, out);
+            }
+        }
+    }
+
+    let mut text = String::new();
+    collect(node, &skip, &mut text);
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Strips the decoration from a printed enumerator, e.g. "SEC. 2." to "2" or
+/// "(a)" to "a".
+fn designation(enumerator: &str) -> Option<String> {
+    let lower = enumerator.to_lowercase();
+    let mut rest = enumerator;
+    for prefix in [
+        "section",
+        "sec.",
+        "subtitle",
+        "title",
+        "division",
+        "subpart",
+        "part",
+        "subchapter",
+        "chapter",
+    ] {
+        if lower.starts_with(prefix) {
+            rest = &enumerator[prefix.len()..];
+            break;
+        }
+    }
+    let designation = rest.trim_matches(|c: char| {
+        c.is_whitespace() || matches!(c, '.' | '(' | ')' | '\u{2014}' | '-')
+    });
+    (!designation.is_empty()).then(|| designation.to_string())
+}
+
+/// Ends a header with a period unless it already ends with punctuation.
+fn with_stop(header: &str) -> String {
+    if header.ends_with(['.', ':', '?', '!', '-', '\u{2014}']) {
+        header.to_string()
+    } else {
+        format!("{header}.")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BILL_DTD: &str = r#"<?xml version="1.0"?>
+<?xml-stylesheet type="text/xsl" href="billres.xsl"?>
+<!DOCTYPE bill PUBLIC "-//US Congress//DTDs/bill.dtd//EN" "bill.dtd">
+<bill bill-stage="Introduced-in-House" dms-id="H1" public-private="public">
+<metadata xmlns:dc="http://purl.org/dc/elements/1.1/"><dublinCore><dc:title>117 HR 3076 IH: Postal Service Reform Act of 2021</dc:title></dublinCore></metadata>
+<form>
+<congress>117th CONGRESS</congress>
+<official-title>To provide stability to and enhance the services of the <term>United States Postal Service</term>, and for other purposes.</official-title>
+</form>
+<legis-body id="H1" style="OLC">
+<section id="S1" section-type="section-one"><enum>1.</enum><header>Short title; table of contents</header>
+<subsection id="a"><enum>(a)</enum><header>Short title</header><text>This Act may be cited as the <quote>Postal Service Reform Act of 2021</quote>.</text></subsection>
+<subsection id="b"><enum>(b)</enum><header>Table of contents</header><text>The table of contents for this Act is as follows:</text>
+<toc regeneration="yes-regeneration">
+<toc-entry idref="S1" level="section">Sec. 1. Short title; table of contents.</toc-entry>
+<toc-entry idref="T1" level="title">TITLE I—Financial reforms</toc-entry>
+<toc-entry idref="S101" level="section">Sec. 101. Postal Service Health Benefits Program.</toc-entry>
+</toc>
+</subsection>
+</section>
+<title id="T1"><enum>I</enum><header>Financial reforms</header>
+<section id="S101"><enum>101.</enum><header>Postal Service Health Benefits Program</header>
+<subsection id="S101a"><enum>(a)</enum><header>In general</header><text>Chapter 89 of title 5, United States Code, is amended by inserting after section 8903c the following:</text>
+<quoted-block display-inline="no-display-inline" id="Q1" style="OLC">
+<section id="Q1S"><enum>8903d.</enum><header>Postal Service Health Benefits Program</header>
+<subsection id="Q1Sa"><enum>(a)</enum><text>The Office shall establish the program.</text></subsection>
+</section>
+<after-quoted-block>.</after-quoted-block></quoted-block>
+</subsection>
+<subsection id="S101b"><enum>(b)</enum><header>Definitions</header><text>In this section:</text>
+<paragraph id="S101b1"><enum>(1)</enum><header>Covered employee</header><text>The term <term>covered employee</term> means—</text>
+<subparagraph id="S101b1A"><enum>(A)</enum><text>a Postal Service employee; and</text></subparagraph>
+<subparagraph id="S101b1B"><enum>(B)</enum><text>an annuitant,</text></subparagraph>
+<continuation-text continuation-text-level="paragraph">as determined by the Office.</continuation-text>
+</paragraph>
+</subsection>
+</section>
+</title>
+</legis-body>
+</bill>"#;
+
+    const USLM: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<bill xmlns="http://schemas.gpo.gov/xml/uslm" xmlns:dc="http://purl.org/dc/elements/1.1/" xml:lang="en">
+<meta><dc:title>An Act to designate a facility.</dc:title></meta>
+<preface><longTitle><docTitle>AN ACT</docTitle><officialTitle>To designate the facility of the United States Postal Service located at 1 Main Street.</officialTitle></longTitle></preface>
+<main>
+<section identifier="/us/bill/118/hr/1/s1"><num value="1">SECTION 1. </num><heading>Designation.</heading>
+<subsection identifier="/us/bill/118/hr/1/s1/a"><num value="a">(a) </num><heading>Designation</heading><content>The facility shall be known as the <quotedText>“Jane Doe Post Office”</quotedText>.</content></subsection>
+<subsection identifier="/us/bill/118/hr/1/s1/b"><num value="b">(b) </num><chapeau>Section 2 of the Act is amended—</chapeau>
+<paragraph identifier="/us/bill/118/hr/1/s1/b/1"><num value="1">(1) </num><content>by striking “a” and inserting the following: <quotedContent><section><num value="3">“SEC. 3. </num><heading>Reports.</heading><content>Reports shall be made annually.</content></section></quotedContent>; and</content></paragraph>
+</subsection>
+</section>
+</main>
+</bill>"#;
+
+    #[test]
+    fn parses_bill_dtd() {
+        let document = Document::parse(BILL_DTD).unwrap();
+
+        assert_eq!(
+            document.title.as_deref(),
+            Some("To provide stability to and enhance the services of the United States Postal Service, and for other purposes.")
+        );
+        assert_eq!(document.table_of_contents.len(), 3);
+        assert_eq!(
+            document.table_of_contents[1],
+            TocEntry {
+                level: Some("title".into()),
+                designator: None,
+                label: "TITLE I—Financial reforms".into(),
+                target: Some("T1".into()),
+            }
+        );
+
+        let sections: Vec<_> = document
+            .sections()
+            .map(|section| section.designation().unwrap())
+            .collect();
+        assert_eq!(sections, ["1", "101"]);
+
+        let Block::Provision(title) = &document.body[1] else {
+            panic!("expected a title");
+        };
+        assert_eq!(title.kind, ProvisionKind::Title);
+        assert_eq!(title.designation(), Some("I"));
+
+        let section = title.provisions().next().unwrap();
+        assert_eq!(section.enumerator.as_deref(), Some("101."));
+        let subsection = section.provisions().next().unwrap();
+        let Block::QuotedBlock(quoted) = &subsection.children[0] else {
+            panic!("expected a quoted block");
+        };
+        assert_eq!(quoted.after.as_deref(), Some("."));
+        assert_eq!(
+            quoted.text(),
+            "Postal Service Health Benefits Program The Office shall establish the program."
+        );
+    }
+
+    #[test]
+    fn flattens_with_paths() {
+        let document = Document::parse(BILL_DTD).unwrap();
+
+        assert_eq!(
+            document.to_plain_text(),
+            "sec. 1 Short title; table of contents\n\
+             sec. 1(a) Short title. This Act may be cited as the Postal Service Reform Act of 2021.\n\
+             sec. 1(b) Table of contents. The table of contents for this Act is as follows:\n\
+             title I Financial reforms\n\
+             sec. 101 Postal Service Health Benefits Program\n\
+             sec. 101(a) In general. Chapter 89 of title 5, United States Code, is amended by inserting after section 8903c the following:\n\
+             sec. 101(a) \u{201c}Postal Service Health Benefits Program The Office shall establish the program.\u{201d}.\n\
+             sec. 101(b) Definitions. In this section:\n\
+             sec. 101(b)(1) Covered employee. The term covered employee means—\n\
+             sec. 101(b)(1)(A) a Postal Service employee; and\n\
+             sec. 101(b)(1)(B) an annuitant,\n\
+             sec. 101(b)(1) as determined by the Office.\n"
+        );
+    }
+
+    #[test]
+    fn parses_uslm() {
+        let document: Document = USLM.parse().unwrap();
+
+        assert_eq!(
+            document.title.as_deref(),
+            Some("To designate the facility of the United States Postal Service located at 1 Main Street.")
+        );
+        assert!(document.table_of_contents.is_empty());
+
+        let section = document.sections().next().unwrap();
+        assert_eq!(section.enumerator.as_deref(), Some("SECTION 1."));
+        assert_eq!(section.id.as_deref(), Some("/us/bill/118/hr/1/s1"));
+
+        // The quoted section is not a section of the bill.
+        assert_eq!(document.sections().count(), 1);
+
+        let lines: Vec<_> = document.flatten().iter().map(Line::to_string).collect();
+        assert_eq!(
+            lines,
+            [
+                "sec. 1 Designation.",
+                "sec. 1(a) Designation. The facility shall be known as the “Jane Doe Post Office”.",
+                "sec. 1(b) Section 2 of the Act is amended—",
+                "sec. 1(b)(1) by striking “a” and inserting the following:",
+                "sec. 1(b)(1) \u{201c}Reports. Reports shall be made annually.\u{201d}; and",
+            ]
+        );
+    }
+
+    #[test]
+    fn strips_enumerators() {
+        assert_eq!(designation("SEC. 2.").as_deref(), Some("2"));
+        assert_eq!(designation("Sec. 101.").as_deref(), Some("101"));
+        assert_eq!(designation("(iv)").as_deref(), Some("iv"));
+        assert_eq!(designation("TITLE II—").as_deref(), Some("II"));
+        assert_eq!(designation("").as_deref(), None);
+    }
+
+    #[test]
+    fn requires_a_body() {
+        assert!(matches!(
+            Document::parse("<bill><form/></bill>"),
+            Err(ParseError::MissingBody)
+        ));
+        assert!(matches!(
+            Document::parse("<bill>"),
+            Err(ParseError::Xml { .. })
+        ));
+    }
+}
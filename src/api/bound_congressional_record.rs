@@ -7,6 +7,8 @@ mod day;
 mod month;
 mod year;
 
+pub mod models;
+
 pub use self::bound_congressional_record::{
     BoundCongressionalRecord, BoundCongressionalRecordBuilder, BoundCongressionalRecordBuilderError,
 };
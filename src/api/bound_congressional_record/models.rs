@@ -0,0 +1,103 @@
+//! Typed response models for the Bound Congressional Record endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    common::{Pagination, RecordSection},
+    de,
+};
+
+/// A day of the bound Congressional Record.
+///
+/// The /bound-congressional-record/:year/:month/:day endpoint also returns
+/// the Daily Digest and the page ranges of each section.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BoundRecord {
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session_number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub volume_number: Option<u32>,
+    pub daily_digest: Option<RecordSection>,
+    #[serde(default)]
+    pub sections: Vec<RecordSection>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The response of all Bound Congressional Record endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct BoundCongressionalRecordResponse {
+    #[serde(default)]
+    pub bound_congressional_record: Vec<BoundRecord>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::{RecordLinkKind, RecordPage};
+
+    use super::*;
+
+    #[test]
+    fn bound_congressional_record_list() {
+        let json = r#"{
+            "boundCongressionalRecord": [
+                {"congress": 104, "date": "1995-01-04", "sessionNumber": 1, "updateDate": "2022-08-04", "url": "https://api.congress.gov/v3/bound-congressional-record/1995/1/4?format=json", "volumeNumber": 141}
+            ],
+            "pagination": {"count": 2}
+        }"#;
+
+        let res: BoundCongressionalRecordResponse = serde_json::from_str(json).unwrap();
+        let record = &res.bound_congressional_record[0];
+        assert_eq!(record.date, NaiveDate::from_ymd_opt(1995, 1, 4));
+        assert_eq!(record.volume_number, Some(141));
+        assert!(record.update_date.is_some());
+    }
+
+    #[test]
+    fn bound_congressional_record_day() {
+        let json = r#"{
+            "boundCongressionalRecord": [
+                {
+                    "congress": 80,
+                    "dailyDigest": {"endPage": 2143, "startPage": 2139, "text": [{"type": "PDF", "url": "https://www.congress.gov/bound-congressional-record/1948/05/19/daily-digest.pdf"}]},
+                    "date": "1948-05-19",
+                    "sections": [{"endPage": 6156, "name": "Senate", "startPage": 6101}, {"endPage": 6213, "name": "House", "startPage": 6157}],
+                    "sessionNumber": 2,
+                    "updateDate": "2022-08-04",
+                    "volumeNumber": 94
+                }
+            ],
+            "pagination": {"count": 1}
+        }"#;
+
+        let res: BoundCongressionalRecordResponse = serde_json::from_str(json).unwrap();
+        let record = &res.bound_congressional_record[0];
+
+        let digest = record.daily_digest.as_ref().unwrap();
+        assert_eq!(digest.start_page.unwrap().number, 2139);
+        assert_eq!(digest.links(RecordLinkKind::Pdf).count(), 1);
+
+        let senate = &record.sections[0];
+        assert_eq!(senate.name.as_deref(), Some("Senate"));
+        assert_eq!(
+            senate.end_page,
+            Some(RecordPage {
+                prefix: None,
+                number: 6156
+            })
+        );
+        assert!(senate.text.is_empty());
+    }
+}
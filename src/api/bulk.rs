@@ -0,0 +1,628 @@
+//! Bulk export of whole resources to newline-delimited JSON.
+//!
+//! A [`BulkExport`] walks the list endpoint of a resource, for one congress
+//! or for all of them, and writes every record it returns as one line of
+//! JSON. Records are written as returned by the API, or replaced by the
+//! response of their detail endpoint when hydrating.
+//!
+//! Progress is kept in a manifest next to the output, updated after every
+//! page. Running an interrupted export again resumes after the last page
+//! recorded in the manifest, discarding anything written after it.
+//!
+//! Pages are requested by offset into a list the API orders by latest
+//! update, so records updated while an export is running move within it.
+//! An export, and above all one resumed after a while, can therefore
+//! contain duplicates of some records and miss others. Deduplicate on the
+//! record's ID downstream, and use [`sync`](crate::api::sync) to pick up
+//! changes made since the export started.
+//!
+//! ```no_run
+//! # async fn example(client: &congressdotgov_rs::Cdg) -> Result<(), Box<dyn std::error::Error>> {
+//! use congressdotgov_rs::api::bulk::{BulkExport, BulkResource};
+//!
+//! let export = BulkExport::builder()
+//!     .resource(BulkResource::Bills)
+//!     .congress(118_u16)
+//!     .build()?;
+//! let manifest = export.run(client, "bills-118.ndjson").await?;
+//! assert!(manifest.complete);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use derive_builder::Builder;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::api::{
+    amendments, bill,
+    client::Client,
+    committee::{self, CommitteeSystemCode},
+    committee_report,
+    common::{impl_api_enum, Pagination},
+    endpoint::Endpoint,
+    error::ApiError,
+    member, nomination,
+    query::Query,
+    treaty::{self, TreatyDetail},
+};
+
+/// A resource which can be exported in bulk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BulkResource {
+    Bills,
+    Amendments,
+    Members,
+    Committees,
+    Nominations,
+    Treaties,
+    /// Committee reports.
+    Reports,
+}
+
+impl BulkResource {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            BulkResource::Bills => "bills",
+            BulkResource::Amendments => "amendments",
+            BulkResource::Members => "members",
+            BulkResource::Committees => "committees",
+            BulkResource::Nominations => "nominations",
+            BulkResource::Treaties => "treaties",
+            BulkResource::Reports => "reports",
+        }
+    }
+
+    /// The field of a list response holding its records.
+    fn list_field(self) -> &'static str {
+        match self {
+            BulkResource::Bills => "bills",
+            BulkResource::Amendments => "amendments",
+            BulkResource::Members => "members",
+            BulkResource::Committees => "committees",
+            BulkResource::Nominations => "nominations",
+            BulkResource::Treaties => "treaties",
+            BulkResource::Reports => "reports",
+        }
+    }
+
+    /// The field of a detail response holding its record.
+    fn detail_field(self) -> &'static str {
+        match self {
+            BulkResource::Bills => "bill",
+            BulkResource::Amendments => "amendment",
+            BulkResource::Members => "member",
+            BulkResource::Committees => "committee",
+            BulkResource::Nominations => "nomination",
+            BulkResource::Treaties => "treaty",
+            BulkResource::Reports => "committeeReports",
+        }
+    }
+
+    /// Fetches a page of the resource's list endpoint.
+    async fn list<C: Client>(
+        self,
+        client: &C,
+        congress: Option<u16>,
+        offset: u32,
+        limit: u8,
+    ) -> Result<Value, ApiError<C::Error>> {
+        macro_rules! page {
+            ($all:ty, $by_congress:ty) => {
+                match congress {
+                    Some(congress) => {
+                        query(
+                            client,
+                            <$by_congress>::builder()
+                                .congress(congress)
+                                .offset(offset)
+                                .limit(limit)
+                                .build()
+                                .unwrap(),
+                        )
+                        .await
+                    }
+                    None => {
+                        query(
+                            client,
+                            <$all>::builder()
+                                .offset(offset)
+                                .limit(limit)
+                                .build()
+                                .unwrap(),
+                        )
+                        .await
+                    }
+                }
+            };
+        }
+
+        match self {
+            BulkResource::Bills => page!(bill::Bill, bill::Congress),
+            BulkResource::Amendments => page!(amendments::Amendment, amendments::Congress),
+            BulkResource::Members => page!(member::Member, member::Congress),
+            BulkResource::Committees => page!(committee::Committee, committee::Congress),
+            BulkResource::Nominations => page!(nomination::Nomination, nomination::Congress),
+            BulkResource::Treaties => page!(treaty::Treaty, treaty::Congress),
+            BulkResource::Reports => {
+                page!(
+                    committee_report::CommitteeReport,
+                    committee_report::Congress
+                )
+            }
+        }
+    }
+
+    /// Fetches the detail of a record returned by the list endpoint.
+    async fn detail<C: Client>(
+        self,
+        client: &C,
+        record: &Value,
+    ) -> Result<Value, BulkError<C::Error>> {
+        let unidentified = || BulkError::UnidentifiedRecord {
+            resource: self,
+            record: record.to_string(),
+        };
+
+        let (id, response) = match self {
+            BulkResource::Bills => {
+                let bill: bill::models::Bill = parse(record).ok_or_else(unidentified)?;
+                let id = bill.id();
+                (
+                    id.to_string(),
+                    query(client, bill::BillNumber::from(id)).await,
+                )
+            }
+            BulkResource::Amendments => {
+                let amendment: amendments::models::Amendment =
+                    parse(record).ok_or_else(unidentified)?;
+                let id = amendment.id();
+                (
+                    id.to_string(),
+                    query(client, amendments::AmendmentNumber::from(id)).await,
+                )
+            }
+            BulkResource::Members => {
+                let member: member::models::Member = parse(record).ok_or_else(unidentified)?;
+                let endpoint = member::BioguideId::builder()
+                    .bioguide_id(member.bioguide_id.as_str())
+                    .build()
+                    .unwrap();
+                (member.bioguide_id.clone(), query(client, endpoint).await)
+            }
+            BulkResource::Committees => {
+                let committee: committee::models::Committee =
+                    parse(record).ok_or_else(unidentified)?;
+                let code: CommitteeSystemCode =
+                    committee.system_code.parse().map_err(|_| unidentified())?;
+                let endpoint = committee::CommitteeCodeBuilder::from(code).build().unwrap();
+                (code.to_string(), query(client, endpoint).await)
+            }
+            BulkResource::Nominations => {
+                let nomination: nomination::models::Nomination =
+                    parse(record).ok_or_else(unidentified)?;
+                let id = nomination.id().ok_or_else(unidentified)?;
+                (
+                    id.to_string(),
+                    query(client, nomination::NominationNumber::from(id)).await,
+                )
+            }
+            BulkResource::Treaties => {
+                let treaty: treaty::models::Treaty = parse(record).ok_or_else(unidentified)?;
+                let id = treaty.id().ok_or_else(unidentified)?;
+                (id.to_string(), query(client, TreatyDetail::from(id)).await)
+            }
+            BulkResource::Reports => {
+                let report: committee_report::models::CommitteeReport =
+                    parse(record).ok_or_else(unidentified)?;
+                let id = report.id().ok_or_else(unidentified)?;
+                (
+                    id.to_string(),
+                    query(client, committee_report::ReportNumber::from(id)).await,
+                )
+            }
+        };
+        let response = response.map_err(|source| BulkError::Hydrate {
+            resource: self,
+            id,
+            source,
+        })?;
+
+        match response.get(self.detail_field()) {
+            // Committee reports are returned as a list of their parts.
+            Some(Value::Array(records)) if records.len() == 1 => Ok(records[0].clone()),
+            Some(detail) => Ok(detail.clone()),
+            None => Err(unidentified()),
+        }
+    }
+}
+
+impl_api_enum!(
+    BulkResource,
+    "bulk resource",
+    [
+        Bills,
+        Amendments,
+        Members,
+        Committees,
+        Nominations,
+        Treaties,
+        Reports
+    ]
+);
+
+async fn query<C, E>(client: &C, endpoint: E) -> Result<Value, ApiError<C::Error>>
+where
+    C: Client,
+    E: Endpoint,
+{
+    endpoint.query(client).await
+}
+
+fn parse<T: DeserializeOwned>(record: &Value) -> Option<T> {
+    serde_json::from_value(record.clone()).ok()
+}
+
+/// The progress of a bulk export, kept next to its output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Manifest {
+    pub resource: BulkResource,
+    pub congress: Option<u16>,
+    pub hydrate: bool,
+    /// The number of records written, which is also the offset of the next
+    /// page.
+    pub records: u32,
+    /// The length of the output after the last record written.
+    pub bytes: u64,
+    /// The number of records the API reported for the resource.
+    pub total: Option<u32>,
+    /// Whether every page has been written.
+    pub complete: bool,
+}
+
+impl Manifest {
+    /// The path of the manifest of an output, e.g. `bills.manifest.json`
+    /// for `bills.ndjson`.
+    pub fn path_for(output: &Path) -> PathBuf {
+        output.with_extension("manifest.json")
+    }
+
+    /// Loads the manifest of an output, if one was written.
+    pub fn load(output: &Path) -> io::Result<Option<Self>> {
+        match fs::read(Self::path_for(output)) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn save(&self, output: &Path) -> io::Result<()> {
+        let path = Self::path_for(output);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(self)?)?;
+        fs::rename(tmp, path)
+    }
+}
+
+/// Errors which can occur during a bulk export.
+#[derive(Debug, Error)]
+pub enum BulkError<E>
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    #[error("failed to fetch records: {}", source)]
+    Api {
+        #[from]
+        source: ApiError<E>,
+    },
+    #[error("failed to write export: {}", source)]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+    #[error("could not parse data from JSON: {}", source)]
+    DataType {
+        #[from]
+        source: serde_json::Error,
+    },
+    #[error("{} belongs to a different export", path.display())]
+    ManifestMismatch { path: PathBuf },
+    #[error("failed to hydrate {} record {}: {}", resource, id, source)]
+    Hydrate {
+        resource: BulkResource,
+        id: String,
+        source: ApiError<E>,
+    },
+    #[error("could not identify {} record: {}", resource, record)]
+    UnidentifiedRecord {
+        resource: BulkResource,
+        record: String,
+    },
+}
+
+/// An export of every record of a resource to a newline-delimited JSON file.
+#[derive(Debug, Clone, Copy, Builder)]
+#[builder(setter(strip_option))]
+pub struct BulkExport {
+    resource: BulkResource,
+    /// The congress to export, or every congress if unset.
+    #[builder(default, setter(into))]
+    congress: Option<u16>,
+    /// Whether to replace each record with the response of its detail
+    /// endpoint, at the cost of a request per record.
+    #[builder(default)]
+    hydrate: bool,
+    #[builder(default = "250")]
+    limit: u8,
+}
+
+impl BulkExport {
+    pub fn builder() -> BulkExportBuilder {
+        BulkExportBuilder::default()
+    }
+
+    /// Exports the resource to `output`, resuming an earlier run of the same
+    /// export if its manifest is found.
+    ///
+    /// Fails with [`BulkError::ManifestMismatch`] rather than overwrite the
+    /// output of a different export. A failed detail request when hydrating
+    /// fails the export with [`BulkError::Hydrate`], naming the record, and
+    /// leaves its page to be fetched again on the next run.
+    ///
+    /// A resumed export can hold duplicated or missing records, see the
+    /// [module documentation](self).
+    pub async fn run<C: Client>(
+        &self,
+        client: &C,
+        output: impl AsRef<Path>,
+    ) -> Result<Manifest, BulkError<C::Error>> {
+        let output = output.as_ref();
+        let mut manifest = match Manifest::load(output)? {
+            Some(manifest)
+                if manifest.resource == self.resource
+                    && manifest.congress == self.congress
+                    && manifest.hydrate == self.hydrate =>
+            {
+                manifest
+            }
+            Some(_) => {
+                return Err(BulkError::ManifestMismatch {
+                    path: Manifest::path_for(output),
+                })
+            }
+            None => Manifest {
+                resource: self.resource,
+                congress: self.congress,
+                hydrate: self.hydrate,
+                records: 0,
+                bytes: 0,
+                total: None,
+                complete: false,
+            },
+        };
+        if manifest.complete {
+            return Ok(manifest);
+        }
+
+        let mut file = open(output, manifest.bytes)?;
+        while !manifest.complete {
+            let page = self
+                .resource
+                .list(client, self.congress, manifest.records, self.limit)
+                .await?;
+            let (records, pagination) = split(page, self.resource.list_field())?;
+
+            let mut lines = Vec::new();
+            for record in &records {
+                let record = if self.hydrate {
+                    &self.resource.detail(client, record).await?
+                } else {
+                    record
+                };
+                serde_json::to_writer(&mut lines, record)?;
+                lines.push(b'\n');
+            }
+            file.write_all(&lines)?;
+            file.sync_data()?;
+
+            manifest.records += records.len() as u32;
+            manifest.bytes += lines.len() as u64;
+            manifest.total = Some(pagination.count);
+            manifest.complete = records.is_empty()
+                || (pagination.next.is_none() && manifest.records >= pagination.count);
+            manifest.save(output)?;
+        }
+
+        Ok(manifest)
+    }
+}
+
+/// Opens the output, discarding anything written after the last recorded
+/// page.
+fn open(output: &Path, bytes: u64) -> io::Result<File> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(output)?;
+    file.set_len(bytes)?;
+    file.seek(SeekFrom::End(0))?;
+    Ok(file)
+}
+
+/// Splits a list response into its records and pagination.
+fn split(mut page: Value, field: &str) -> Result<(Vec<Value>, Pagination), serde_json::Error> {
+    let records = match page.get_mut(field).map(Value::take) {
+        Some(records) => serde_json::from_value(records)?,
+        None => Vec::new(),
+    };
+    let pagination = match page.get_mut("pagination").map(Value::take) {
+        Some(pagination) => serde_json::from_value(pagination)?,
+        None => Pagination::default(),
+    };
+    Ok((records, pagination))
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::test::MockClient;
+
+    use super::*;
+
+    fn bill(number: u32) -> String {
+        format!(
+            r#"{{"congress": 118, "type": "HR", "number": "{number}", "title": "Bill {number}"}}"#
+        )
+    }
+
+    fn output(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("cdg-bulk-{}-{name}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir.join("bills.ndjson")
+    }
+
+    fn lines(output: &Path) -> Vec<Value> {
+        fs::read_to_string(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    fn export(hydrate: bool) -> BulkExport {
+        BulkExport::builder()
+            .resource(BulkResource::Bills)
+            .congress(118_u16)
+            .hydrate(hydrate)
+            .limit(2)
+            .build()
+            .unwrap()
+    }
+
+    /// The first page of a three bill export, answered by `client`.
+    fn first_page(client: MockClient) -> MockClient {
+        client.respond(
+            "bill/118?offset=0&limit=2",
+            &format!(
+                r#"{{"bills": [{}, {}], "pagination": {{"count": 3, "next": "https://api.congress.gov/v3/bill/118?offset=2&limit=2"}}}}"#,
+                bill(1),
+                bill(2),
+            ),
+        )
+    }
+
+    #[tokio::test]
+    async fn resumes_interrupted_export() {
+        let output = output("resume");
+        let client = first_page(MockClient::new()).respond_with(
+            "bill/118?offset=2&limit=2",
+            StatusCode::BAD_GATEWAY,
+            r#"{"error": "bad gateway"}"#,
+        );
+
+        let err = export(false).run(&client, &output).await.unwrap_err();
+        assert!(matches!(err, BulkError::Api { .. }));
+        let manifest = Manifest::load(&output).unwrap().unwrap();
+        assert_eq!(manifest.records, 2);
+        assert_eq!(manifest.total, Some(3));
+        assert!(!manifest.complete);
+
+        // A line cut short when the process was killed.
+        OpenOptions::new()
+            .append(true)
+            .open(&output)
+            .unwrap()
+            .write_all(br#"{"congress": 11"#)
+            .unwrap();
+
+        let client = MockClient::new().respond(
+            "bill/118?offset=2&limit=2",
+            &format!(
+                r#"{{"bills": [{}], "pagination": {{"count": 3}}}}"#,
+                bill(3)
+            ),
+        );
+        let manifest = export(false).run(&client, &output).await.unwrap();
+        assert!(manifest.complete);
+        assert_eq!(manifest.records, 3);
+        assert_eq!(client.requests(), ["bill/118?offset=2&limit=2"]);
+
+        let lines = lines(&output);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[2]["number"], "3");
+
+        let client = MockClient::new();
+        export(false).run(&client, &output).await.unwrap();
+        assert!(client.requests().is_empty());
+
+        fs::remove_dir_all(output.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn hydrates_records() {
+        let output = output("hydrate");
+        let client = MockClient::new()
+            .respond(
+                "bill/118?offset=0&limit=2",
+                &format!(r#"{{"bills": [{}], "pagination": {{"count": 1}}}}"#, bill(1)),
+            )
+            .respond(
+                "bill/118/hr/1",
+                r#"{"bill": {"congress": 118, "type": "HR", "number": "1", "title": "Lower Energy Costs Act", "introducedDate": "2023-03-14"}}"#,
+            );
+
+        let manifest = export(true).run(&client, &output).await.unwrap();
+        assert!(manifest.complete);
+        assert_eq!(lines(&output)[0]["introducedDate"], "2023-03-14");
+
+        fs::remove_dir_all(output.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn names_record_failing_hydration() {
+        let output = output("hydrate-error");
+        let client = first_page(MockClient::new())
+            .respond(
+                "bill/118/hr/1",
+                r#"{"bill": {"congress": 118, "type": "HR", "number": "1"}}"#,
+            )
+            .respond_with(
+                "bill/118/hr/2",
+                StatusCode::INTERNAL_SERVER_ERROR,
+                r#"{"error": "internal error"}"#,
+            );
+
+        let err = export(true).run(&client, &output).await.unwrap_err();
+        match err {
+            BulkError::Hydrate { resource, id, .. } => {
+                assert_eq!(resource, BulkResource::Bills);
+                assert_eq!(id, "H.R. 2 (118th Congress)");
+            }
+            err => panic!("unexpected error: {err:?}"),
+        }
+        assert_eq!(Manifest::load(&output).unwrap(), None);
+
+        fs::remove_dir_all(output.parent().unwrap()).unwrap();
+    }
+
+    #[tokio::test]
+    async fn refuses_output_of_other_export() {
+        let output = output("mismatch");
+        let client = first_page(MockClient::new());
+        export(false).run(&client, &output).await.unwrap_err();
+
+        let err = export(true).run(&client, &output).await.unwrap_err();
+        assert!(matches!(err, BulkError::ManifestMismatch { .. }));
+
+        fs::remove_dir_all(output.parent().unwrap()).unwrap();
+    }
+}
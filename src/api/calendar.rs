@@ -0,0 +1,467 @@
+//! The calendar of congresses and their sessions.
+//!
+//! Each congress runs for two years. From the 1st through the 72nd, a
+//! congress began on March 4 of an odd-numbered year; since the 20th
+//! Amendment, congresses begin on January 3, starting with the 74th. The
+//! 73rd began on March 4, 1933 and ended on January 3, 1935. A congress
+//! ends at noon on the day its successor begins, so that day is counted as
+//! part of the new congress.
+//!
+//! A [`Congress`] is valid from the 1st through the one after the congress
+//! in session today, so citations of a congress which has just begun are
+//! accepted. The calendar only knows the nominal sessions of modern
+//! congresses, each assumed to run from January 3 through January 2 of the
+//! next year. It can be brought up to date with the sessions reported by
+//! the [`congress::Congress`](crate::api::congress::Congress) and
+//! [`congress::SpecificCongress`](crate::api::congress::SpecificCongress)
+//! endpoints, which also adds any congress they report beyond those.
+//!
+//! Only a `Congress` and the citations parsed into IDs, such as
+//! [`BillId`](crate::api::bill::BillId), are checked against the calendar.
+//! Endpoint builders take any number as a congress and leave it to the API
+//! to reject one that does not exist.
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use congressdotgov_rs::api::{bill, calendar::Congress};
+//!
+//! let date = NaiveDate::from_ymd_opt(2019, 3, 4).unwrap();
+//! let congress = Congress::on(date).unwrap();
+//! assert_eq!(congress.to_string(), "116th Congress");
+//! assert_eq!(congress.start_date(), NaiveDate::from_ymd_opt(2019, 1, 3).unwrap());
+//!
+//! let endpoint = bill::Congress::builder().congress(congress).build().unwrap();
+//! ```
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::{LazyLock, PoisonError, RwLock, RwLockReadGuard};
+
+use chrono::{Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::api::{
+    citation::{self, Ordinal},
+    common::CommitteeChamber,
+    congress::models::{CongressInfo, SessionType},
+};
+
+/// The first congress to begin on January 3.
+const FIRST_JANUARY: u16 = 74;
+
+static CALENDAR: LazyLock<RwLock<Calendar>> = LazyLock::new(|| RwLock::new(Calendar::default()));
+
+/// Errors that can occur when looking up or refreshing the calendar.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum CalendarError {
+    #[error("the {} Congress is not in the calendar", Ordinal(u32::from(*congress)))]
+    UnknownCongress { congress: u16 },
+    #[error("congress {name:?} does not have a number")]
+    MissingNumber { name: String },
+    #[error("the {} Congress did not begin in {year}", Ordinal(u32::from(*congress)))]
+    MismatchedYear { congress: u16, year: u32 },
+}
+
+/// A congress known to the calendar.
+///
+/// A `Congress` can be used wherever an endpoint builder takes a congress,
+/// and is displayed as e.g. "117th Congress".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(try_from = "u16", into = "u16")]
+pub struct Congress(u16);
+
+impl Congress {
+    /// The congress with the given number, if it is in the calendar.
+    pub fn new(number: u16) -> Result<Self, CalendarError> {
+        if read().contains(number, Utc::now().date_naive()) {
+            Ok(Self(number))
+        } else {
+            Err(CalendarError::UnknownCongress { congress: number })
+        }
+    }
+
+    /// The congress in session today.
+    pub fn current() -> Option<Self> {
+        Self::on(Utc::now().date_naive())
+    }
+
+    /// The congress in session on the given date.
+    pub fn on(date: NaiveDate) -> Option<Self> {
+        Self::new(number_on(date)?).ok()
+    }
+
+    /// The sessions of every chamber in session on the given date.
+    pub fn sessions_on(date: NaiveDate) -> Vec<Session> {
+        Self::on(date)
+            .map(|congress| {
+                congress
+                    .sessions()
+                    .into_iter()
+                    .filter(|session| session.contains(date))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn number(self) -> u16 {
+        self.0
+    }
+
+    /// The day the congress began.
+    pub fn start_date(self) -> NaiveDate {
+        start_date(self.0)
+    }
+
+    /// The day the congress ended, which is the day its successor began.
+    pub fn end_date(self) -> NaiveDate {
+        start_date(self.0 + 1)
+    }
+
+    /// The sessions of the congress, ordered by number and then chamber.
+    pub fn sessions(self) -> Vec<Session> {
+        read().sessions(self.0)
+    }
+
+    /// The congress before this one.
+    pub fn previous(self) -> Option<Self> {
+        Self::new(self.0.checked_sub(1)?).ok()
+    }
+
+    /// The congress after this one, if it is in the calendar.
+    pub fn next(self) -> Option<Self> {
+        Self::new(self.0.checked_add(1)?).ok()
+    }
+}
+
+impl TryFrom<u16> for Congress {
+    type Error = CalendarError;
+
+    fn try_from(number: u16) -> Result<Self, Self::Error> {
+        Self::new(number)
+    }
+}
+
+impl From<Congress> for u16 {
+    fn from(congress: Congress) -> Self {
+        congress.0
+    }
+}
+
+impl FromStr for Congress {
+    type Err = CalendarError;
+
+    /// Parses a congress such as "117", "117th" or "117th Congress".
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number =
+            citation::congress(s).ok_or_else(|| CalendarError::MissingNumber { name: s.into() })?;
+        Self::new(number)
+    }
+}
+
+impl fmt::Display for Congress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} Congress", Ordinal(self.0.into()))
+    }
+}
+
+/// A session of a congress.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Session {
+    pub congress: Congress,
+    pub number: u8,
+    /// The chamber of the session, or `None` for a session of both chambers.
+    pub chamber: Option<CommitteeChamber>,
+    pub session_type: SessionType,
+    pub start_date: NaiveDate,
+    /// The day the session adjourned, or `None` while it is in progress.
+    pub end_date: Option<NaiveDate>,
+}
+
+impl Session {
+    /// Whether the session was in progress on the given date, counting the
+    /// day it adjourned.
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start_date <= date && self.end_date.is_none_or(|end| date <= end)
+    }
+}
+
+/// Replaces the sessions of a congress in the calendar with those of a
+/// /congress, /congress/:congress or /congress/current response, adding the
+/// congress if it is newer than the calendar.
+///
+/// ```rust,no_run
+/// use congressdotgov_rs::api::{calendar, congress, congress::models::CongressesResponse, Query};
+/// # use congressdotgov_rs::{Auth, Cdg};
+///
+/// # tokio_test::block_on(async {
+/// # let client = Cdg::new(Auth::Token("API_KEY".into())).unwrap();
+/// let endpoint = congress::Congress::builder().limit(250).build().unwrap();
+/// let res: CongressesResponse = endpoint.query(&client).await.unwrap();
+/// for congress in &res.congresses {
+///     calendar::refresh(congress).unwrap();
+/// }
+/// # })
+/// ```
+pub fn refresh(info: &CongressInfo) -> Result<Congress, CalendarError> {
+    CALENDAR
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .refresh(info)
+}
+
+fn read() -> RwLockReadGuard<'static, Calendar> {
+    CALENDAR.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// The sessions of the congresses refreshed from the API, by number. Any
+/// other congress has its nominal sessions.
+#[derive(Default)]
+struct Calendar(BTreeMap<u16, Vec<Session>>);
+
+impl Calendar {
+    /// Whether a congress is valid on the given day: it is at most one past
+    /// the congress then in session, or has been refreshed.
+    fn contains(&self, number: u16, today: NaiveDate) -> bool {
+        let latest = number_on(today)
+            .map(|current| current + 1)
+            .into_iter()
+            .chain(self.0.keys().next_back().copied())
+            .max();
+        number > 0 && latest.is_some_and(|latest| number <= latest)
+    }
+
+    fn sessions(&self, number: u16) -> Vec<Session> {
+        self.0
+            .get(&number)
+            .cloned()
+            .unwrap_or_else(|| nominal_sessions(number))
+    }
+
+    fn refresh(&mut self, info: &CongressInfo) -> Result<Congress, CalendarError> {
+        let number = info
+            .congress_number()
+            .filter(|&number| number > 0)
+            .ok_or_else(|| CalendarError::MissingNumber {
+                name: info.name.clone(),
+            })?;
+        if let Some(year) = info.start_year {
+            if i32::try_from(year).ok() != Some(start_date(number).year()) {
+                return Err(CalendarError::MismatchedYear {
+                    congress: number,
+                    year,
+                });
+            }
+        }
+
+        let congress = Congress(number);
+        let mut sessions: Vec<_> = info
+            .sessions
+            .iter()
+            .filter_map(|session| {
+                Some(Session {
+                    congress,
+                    number: u8::try_from(session.number?).ok()?,
+                    chamber: session.chamber,
+                    session_type: session.session_type.unwrap_or(SessionType::Regular),
+                    start_date: session.start_date?,
+                    end_date: session.end_date,
+                })
+            })
+            .collect();
+        sessions
+            .sort_by_key(|session| (session.number, session.start_date, chamber_order(session)));
+
+        let entry = self
+            .0
+            .entry(number)
+            .or_insert_with(|| nominal_sessions(number));
+        if !sessions.is_empty() {
+            *entry = sessions;
+        }
+
+        Ok(congress)
+    }
+}
+
+/// The number of the congress in session on the given date.
+fn number_on(date: NaiveDate) -> Option<u16> {
+    let years = date.year().checked_sub(1789)?;
+    let number = u16::try_from(years / 2 + 1).ok()?;
+    if date < start_date(number) {
+        number.checked_sub(1).filter(|&number| number > 0)
+    } else {
+        Some(number)
+    }
+}
+
+fn start_date(number: u16) -> NaiveDate {
+    let year = 1789 + 2 * (i32::from(number) - 1);
+    let date = if number < FIRST_JANUARY {
+        NaiveDate::from_ymd_opt(year, 3, 4)
+    } else {
+        NaiveDate::from_ymd_opt(year, 1, 3)
+    };
+    date.expect("congresses begin on valid dates")
+}
+
+/// The sessions of a modern congress as the 20th Amendment sets them out.
+/// Sessions of earlier congresses were irregular and are only known once
+/// refreshed.
+fn nominal_sessions(number: u16) -> Vec<Session> {
+    if number < FIRST_JANUARY {
+        return Vec::new();
+    }
+
+    let start = start_date(number);
+    let mid = start.with_year(start.year() + 1).expect("January 3 exists");
+    let day_before = |date: NaiveDate| date.pred_opt().expect("January 2 exists");
+    [
+        (1, start, day_before(mid)),
+        (2, mid, day_before(start_date(number + 1))),
+    ]
+    .into_iter()
+    .map(|(session, start_date, end_date)| Session {
+        congress: Congress(number),
+        number: session,
+        chamber: None,
+        session_type: SessionType::Regular,
+        start_date,
+        end_date: Some(end_date),
+    })
+    .collect()
+}
+
+fn chamber_order(session: &Session) -> u8 {
+    match session.chamber {
+        None => 0,
+        Some(CommitteeChamber::House) => 1,
+        Some(CommitteeChamber::Senate) => 2,
+        Some(CommitteeChamber::NoChamber) => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn maps_dates_to_congresses() {
+        for (on, congress) in [
+            (date(2019, 3, 4), Some(116)),
+            (date(2021, 1, 2), Some(116)),
+            (date(2021, 1, 3), Some(117)),
+            (date(2022, 12, 31), Some(117)),
+            (date(1789, 3, 4), Some(1)),
+            (date(1789, 3, 3), None),
+            (date(1791, 3, 4), Some(2)),
+            (date(1933, 3, 3), Some(72)),
+            (date(1933, 3, 4), Some(73)),
+            (date(1935, 1, 2), Some(73)),
+            (date(1935, 1, 3), Some(74)),
+            (date(2500, 1, 1), None),
+        ] {
+            assert_eq!(Congress::on(on).map(Congress::number), congress, "{on}");
+        }
+    }
+
+    #[test]
+    fn congress_dates() {
+        let congress = Congress::new(117).unwrap();
+        assert_eq!(congress.start_date(), date(2021, 1, 3));
+        assert_eq!(congress.end_date(), date(2023, 1, 3));
+
+        let congress = Congress::new(73).unwrap();
+        assert_eq!(congress.start_date(), date(1933, 3, 4));
+        assert_eq!(congress.end_date(), date(1935, 1, 3));
+
+        assert_eq!(congress.previous(), Congress::new(72).ok());
+        assert_eq!(Congress::new(1).unwrap().previous(), None);
+    }
+
+    #[test]
+    fn nominal_sessions() {
+        let sessions = Congress::new(110).unwrap().sessions();
+        assert_eq!(sessions.len(), 2);
+        assert_eq!(sessions[1].number, 2);
+        assert_eq!(sessions[1].start_date, date(2008, 1, 3));
+        assert_eq!(sessions[1].end_date, Some(date(2009, 1, 2)));
+        assert!(sessions[1].contains(date(2009, 1, 2)));
+        assert!(!sessions[1].contains(date(2009, 1, 3)));
+
+        let sessions = Congress::sessions_on(date(2007, 6, 1));
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].number, 1);
+
+        assert!(Congress::new(40).unwrap().sessions().is_empty());
+    }
+
+    #[test]
+    fn validates_congresses() {
+        assert!(Congress::new(0).is_err());
+        assert!(Congress::new(400).is_err());
+        assert_eq!("117th Congress".parse(), Ok(Congress(117)));
+        assert_eq!(Congress(117).to_string(), "117th Congress");
+        assert_eq!(serde_json::to_string(&Congress(117)).unwrap(), "117");
+        assert!(serde_json::from_str::<Congress>("400").is_err());
+
+        let calendar = Calendar::default();
+        let today = date(2026, 10, 19);
+        assert!(calendar.contains(119, today));
+        assert!(calendar.contains(120, today));
+        assert!(!calendar.contains(121, today));
+        assert!(calendar.contains(121, date(2027, 1, 3)));
+        assert!(!calendar.contains(0, today));
+    }
+
+    /// Refreshes a calendar of the tests' own, leaving the global one as
+    /// built in for the other tests.
+    #[test]
+    fn refreshes_from_responses() {
+        let mut calendar = Calendar::default();
+        let info: CongressInfo = serde_json::from_str(
+            r#"{"endYear": "2014", "name": "113th Congress", "number": 113, "sessions": [{"chamber": "Senate", "endDate": "2014-01-03", "number": 1, "startDate": "2013-01-03", "type": "R"}, {"chamber": "House of Representatives", "endDate": "2014-01-03", "number": 1, "startDate": "2013-01-03", "type": "R"}, {"chamber": "House of Representatives", "endDate": "2014-12-16", "number": 2, "startDate": "2014-01-03", "type": "R"}], "startYear": "2013"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(calendar.refresh(&info), Ok(Congress(113)));
+        let sessions = calendar.sessions(113);
+        assert_eq!(sessions.len(), 3);
+        assert_eq!(sessions[0].chamber, Some(CommitteeChamber::House));
+        assert_eq!(sessions[2].end_date, Some(date(2014, 12, 16)));
+        assert!(sessions[2].contains(date(2014, 12, 16)));
+        assert!(!sessions[2].contains(date(2014, 12, 17)));
+        assert!(!sessions.iter().any(|s| s.contains(date(2014, 12, 20))));
+        assert_eq!(Congress(113).sessions().len(), 2);
+
+        let info: CongressInfo =
+            serde_json::from_str(r#"{"name": "113th Congress", "startYear": "2015"}"#).unwrap();
+        assert!(matches!(
+            calendar.refresh(&info),
+            Err(CalendarError::MismatchedYear { year: 2015, .. })
+        ));
+    }
+
+    #[test]
+    fn refresh_adds_newer_congresses() {
+        let mut calendar = Calendar::default();
+        let info: CongressInfo = serde_json::from_str(
+            r#"{"name": "121st Congress", "sessions": [{"chamber": "Senate", "number": 1, "startDate": "2029-01-03", "type": "R"}], "startYear": "2029"}"#,
+        )
+        .unwrap();
+
+        let congress = calendar.refresh(&info).unwrap();
+        assert_eq!(congress.number(), 121);
+        let today = date(2026, 10, 19);
+        assert!(calendar.contains(121, today));
+        assert!(!calendar.contains(122, today));
+        assert!(calendar.sessions(121)[0].contains(date(2030, 6, 1)));
+    }
+}
@@ -0,0 +1,219 @@
+//! Citation parsing shared by the identifier types of each resource, such as
+//! [`bill::BillId`](crate::api::bill::BillId).
+//!
+//! Citations are matched loosely: case, periods and spacing in the
+//! abbreviation are ignored, so "H.R. 3076", "HR 3076" and "hr3076" are the
+//! same citation. A congress may be given after the citation as
+//! "(117th Congress)", ", 117th Congress", "— 117th Congress" or
+//! "117th Congress".
+
+use std::fmt;
+
+use thiserror::Error;
+
+/// Errors that can occur when parsing a citation.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[non_exhaustive]
+pub enum ParseCitationError {
+    #[error("unrecognized {kind} citation: {input:?}")]
+    Unrecognized { kind: &'static str, input: String },
+    #[error("{kind} citation {input:?} does not name a congress")]
+    MissingCongress { kind: &'static str, input: String },
+    #[error("{kind} citation {input:?} is not valid in the {} Congress", Ordinal(u32::from(*congress)))]
+    InvalidCongress {
+        kind: &'static str,
+        input: String,
+        congress: u16,
+    },
+}
+
+impl ParseCitationError {
+    pub(crate) fn unrecognized(kind: &'static str, input: &str) -> Self {
+        Self::Unrecognized {
+            kind,
+            input: input.into(),
+        }
+    }
+
+    pub(crate) fn missing_congress(kind: &'static str, input: &str) -> Self {
+        Self::MissingCongress {
+            kind,
+            input: input.into(),
+        }
+    }
+
+    pub(crate) fn invalid_congress(kind: &'static str, input: &str, congress: u16) -> Self {
+        Self::InvalidCongress {
+            kind,
+            input: input.into(),
+            congress,
+        }
+    }
+}
+
+/// Formats a number as an English ordinal, e.g. "117th" or "2nd".
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Ordinal(pub(crate) u32);
+
+impl fmt::Display for Ordinal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = match (self.0 % 10, self.0 % 100) {
+            (_, 11..=13) => "th",
+            (1, _) => "st",
+            (2, _) => "nd",
+            (3, _) => "rd",
+            _ => "th",
+        };
+        write!(f, "{}{}", self.0, suffix)
+    }
+}
+
+/// A citation split into its abbreviation and the numbers following it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Citation<'a> {
+    /// The abbreviation, lowercased and without periods or spaces, e.g.
+    /// "hr" for "H.R.".
+    pub(crate) prefix: String,
+    /// Everything after the abbreviation, e.g. "3076" or "117-108".
+    pub(crate) rest: &'a str,
+    /// The congress given after the citation, if any.
+    pub(crate) congress: Option<u16>,
+}
+
+impl<'a> Citation<'a> {
+    /// Splits a citation at its first digit.
+    pub(crate) fn split(input: &'a str) -> Option<Self> {
+        let (body, congress) = strip_congress(input.trim());
+        let at = body.find(|c: char| c.is_ascii_digit())?;
+        let (prefix, rest) = body.split_at(at);
+
+        Some(Citation {
+            prefix: prefix
+                .chars()
+                .filter(char::is_ascii_alphanumeric)
+                .map(|c| c.to_ascii_lowercase())
+                .collect(),
+            rest: rest.trim(),
+            congress,
+        })
+    }
+
+    /// Splits the numbers of a citation at a hyphen, as in "117-108".
+    pub(crate) fn numbers(&self) -> (&'a str, Option<&'a str>) {
+        match self.rest.split_once('-') {
+            Some((first, second)) => (first.trim(), Some(second.trim())),
+            None => (self.rest, None),
+        }
+    }
+}
+
+/// Parses a string made only of ASCII digits.
+pub(crate) fn number<T: std::str::FromStr>(s: &str) -> Option<T> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Parses a congress such as "117", "117th" or "117th Congress".
+pub(crate) fn congress(s: &str) -> Option<u16> {
+    let s = s.trim();
+    let s = strip_suffix_ignore_case(s, "congress")
+        .unwrap_or(s)
+        .trim_end();
+    let s = ["st", "nd", "rd", "th"]
+        .iter()
+        .find_map(|suffix| strip_suffix_ignore_case(s, suffix))
+        .unwrap_or(s);
+
+    number(s).filter(|&congress| congress > 0)
+}
+
+/// Splits a trailing congress off a citation.
+fn strip_congress(s: &str) -> (&str, Option<u16>) {
+    if let Some(inner) = s.strip_suffix(')') {
+        if let Some(open) = inner.rfind('(') {
+            if let Some(congress) = congress(&inner[open + 1..]) {
+                return (inner[..open].trim_end(), Some(congress));
+            }
+        }
+    }
+
+    if let Some((body, tail)) = s.rsplit_once([',', '—', '–']) {
+        if let Some(congress) = congress(tail) {
+            return (body.trim_end(), Some(congress));
+        }
+    }
+
+    if let Some(body) = strip_suffix_ignore_case(s, "congress") {
+        if let Some((body, ordinal)) = body.trim_end().rsplit_once(char::is_whitespace) {
+            if let Some(congress) = congress(ordinal) {
+                return (body.trim_end(), Some(congress));
+            }
+        }
+    }
+
+    (s, None)
+}
+
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let at = s.len().checked_sub(suffix.len())?;
+    (s.is_char_boundary(at) && s[at..].eq_ignore_ascii_case(suffix)).then(|| &s[..at])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordinals() {
+        let ordinals: Vec<_> = [1, 2, 3, 4, 11, 12, 13, 21, 102, 111, 117]
+            .into_iter()
+            .map(|n| Ordinal(n).to_string())
+            .collect();
+
+        assert_eq!(
+            ordinals,
+            [
+                "1st", "2nd", "3rd", "4th", "11th", "12th", "13th", "21st", "102nd", "111th",
+                "117th"
+            ]
+        );
+    }
+
+    #[test]
+    fn splits_citations() {
+        let citation = Citation::split("H.Con.Res. 12 (117th Congress)").unwrap();
+        assert_eq!(citation.prefix, "hconres");
+        assert_eq!(citation.rest, "12");
+        assert_eq!(citation.congress, Some(117));
+
+        let citation = Citation::split("Pub. L. 117-108").unwrap();
+        assert_eq!(citation.prefix, "publ");
+        assert_eq!(citation.numbers(), ("117", Some("108")));
+        assert_eq!(citation.congress, None);
+
+        let citation = Citation::split("S. 5, 116th Congress").unwrap();
+        assert_eq!(citation.congress, Some(116));
+
+        let citation = Citation::split("PN2467-1 — 117th Congress").unwrap();
+        assert_eq!(citation.rest, "2467-1");
+        assert_eq!(citation.congress, Some(117));
+
+        let citation = Citation::split("hr3076 117th congress").unwrap();
+        assert_eq!(citation.prefix, "hr");
+        assert_eq!(citation.congress, Some(117));
+
+        assert!(Citation::split("H.R.").is_none());
+    }
+
+    #[test]
+    fn parses_congresses() {
+        assert_eq!(congress("117"), Some(117));
+        assert_eq!(congress("117th"), Some(117));
+        assert_eq!(congress("1st Congress"), Some(1));
+        assert_eq!(congress("0"), None);
+        assert_eq!(congress("-1"), None);
+        assert_eq!(congress("Congress"), None);
+    }
+}
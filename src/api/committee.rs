@@ -2,6 +2,9 @@
 
 //! Committee API endpoints and types.
 
+use crate::api::common::impl_api_enum;
+
+mod activity;
 mod bills;
 mod chamber;
 mod chamber_by_congress;
@@ -12,7 +15,11 @@ mod house_communication;
 mod nominations;
 mod reports;
 mod senate_communication;
+mod system_code;
+
+pub mod models;
 
+pub use self::activity::{ActivityEvent, ActivityKind, CommitteeActivityReport};
 pub use self::bills::{Bills, BillsBuilder, BillsBuilderError};
 pub use self::chamber::{Chamber, ChamberBuilder, ChamberBuilderError};
 pub use self::chamber_by_congress::{
@@ -29,11 +36,12 @@ pub use self::reports::{Reports, ReportsBuilder, ReportsBuilderError};
 pub use self::senate_communication::{
     SenateCommunication, SenateCommunicationBuilder, SenateCommunicationBuilderError,
 };
+pub use self::system_code::CommitteeSystemCode;
 
 /// Chamber options for the Committee resource.
 ///
 /// This differs from CommitteeChamber enum with its Joint variant.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CommitteeChamber {
     House,
     Senate,
@@ -41,7 +49,7 @@ pub enum CommitteeChamber {
 }
 
 impl CommitteeChamber {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             CommitteeChamber::House => "house",
             CommitteeChamber::Senate => "senate",
@@ -49,3 +57,14 @@ impl CommitteeChamber {
         }
     }
 }
+
+impl_api_enum!(
+    CommitteeChamber,
+    "chamber",
+    [House, Senate, Joint],
+    json {
+        House => "House",
+        Senate => "Senate",
+        Joint => "Joint",
+    }
+);
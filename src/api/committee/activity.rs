@@ -0,0 +1,557 @@
+//! A rollup of everything a committee did during a congress.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use chrono::{DateTime, Datelike, NaiveDate, Utc};
+use futures_util::{join, stream, StreamExt, TryStreamExt};
+
+use crate::api::{
+    bill::BillId,
+    calendar::Congress,
+    client::Client,
+    committee::{
+        models::{
+            CommitteeBill, CommitteeBillsResponse, CommitteeHouseCommunicationsResponse,
+            CommitteeNominationsResponse, CommitteeReportsResponse,
+            CommitteeSenateCommunicationsResponse,
+        },
+        BillsBuilder, CommitteeChamber, CommitteeSystemCode, HouseCommunicationBuilder,
+        NominationsBuilder, ReportsBuilder, SenateCommunicationBuilder,
+    },
+    committee_meeting::{
+        self,
+        models::{CommitteeMeeting, CommitteeMeetingResponse, CommitteeMeetingsResponse},
+        EventId,
+    },
+    committee_report::models::CommitteeReport,
+    common,
+    error::ApiError,
+    hearing::{
+        self,
+        models::{Hearing, HearingResponse, HearingsResponse},
+        JacketNumber,
+    },
+    house_communication::models::HouseCommunication,
+    nomination::models::Nomination,
+    paged::all_pages,
+    query::Query,
+    senate_communication::models::SenateCommunication,
+};
+
+/// The number of hearing and meeting details requested at once.
+const DETAIL_CONCURRENCY: usize = 8;
+
+/// A kind of committee activity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ActivityKind {
+    Bill,
+    Report,
+    Nomination,
+    HouseCommunication,
+    SenateCommunication,
+    Hearing,
+    Meeting,
+}
+
+impl ActivityKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ActivityKind::Bill => "bill",
+            ActivityKind::Report => "report",
+            ActivityKind::Nomination => "nomination",
+            ActivityKind::HouseCommunication => "house communication",
+            ActivityKind::SenateCommunication => "senate communication",
+            ActivityKind::Hearing => "hearing",
+            ActivityKind::Meeting => "meeting",
+        }
+    }
+}
+
+impl fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A dated entry of a committee's timeline.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub struct ActivityEvent {
+    pub date: NaiveDate,
+    pub kind: ActivityKind,
+    /// What the activity was about, e.g. "H.R. 4414: Referred to" or a
+    /// hearing title.
+    pub label: String,
+}
+
+/// The bills, reports, nominations, communications, hearings and meetings
+/// of a committee during a congress.
+///
+/// The committee endpoints return items from every congress, so all of
+/// their pages are fetched and filtered down to the requested congress.
+/// Bills and reports are only requested if updated since the congress
+/// began, which the API can filter on. Hearings and meetings are listed per
+/// chamber without their committees, so each one is fetched, a few at a
+/// time, to find those held by the committee. A full committee's hearings
+/// and meetings include those of its subcommittees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CommitteeActivityReport {
+    pub committee: CommitteeSystemCode,
+    pub congress: u16,
+    pub bills: Vec<CommitteeBill>,
+    pub reports: Vec<CommitteeReport>,
+    pub nominations: Vec<Nomination>,
+    pub house_communications: Vec<HouseCommunication>,
+    pub senate_communications: Vec<SenateCommunication>,
+    pub hearings: Vec<Hearing>,
+    pub meetings: Vec<CommitteeMeeting>,
+    /// Every dated activity, oldest first. Items without a date are counted
+    /// but left out of the timeline.
+    pub timeline: Vec<ActivityEvent>,
+}
+
+impl CommitteeActivityReport {
+    /// Fetches the activity of a committee during a congress.
+    pub async fn fetch<C: Client>(
+        client: &C,
+        committee: CommitteeSystemCode,
+        congress: u16,
+    ) -> Result<Self, ApiError<C::Error>> {
+        let chamber = match committee.chamber() {
+            CommitteeChamber::House => common::CommitteeChamber::House,
+            CommitteeChamber::Senate => common::CommitteeChamber::Senate,
+            CommitteeChamber::Joint => common::CommitteeChamber::NoChamber,
+        };
+
+        // Nothing of a congress was updated before it began.
+        let since: Option<DateTime<Utc>> = Congress::new(congress)
+            .ok()
+            .and_then(|congress| congress.start_date().and_hms_opt(0, 0, 0))
+            .map(|start| start.and_utc());
+
+        let (bills, reports, nominations, house, senate, hearings, meetings) = join!(
+            all_pages::<CommitteeBillsResponse, _, _, _>(client, |offset, limit| {
+                let mut builder = BillsBuilder::from(committee);
+                if let Some(since) = since {
+                    builder.from_date_time(since);
+                }
+                builder.offset(offset).limit(limit).build().unwrap()
+            }),
+            all_pages::<CommitteeReportsResponse, _, _, _>(client, |offset, limit| {
+                let mut builder = ReportsBuilder::from(committee);
+                if let Some(since) = since {
+                    builder.from_date_time(since);
+                }
+                builder.offset(offset).limit(limit).build().unwrap()
+            }),
+            all_pages::<CommitteeNominationsResponse, _, _, _>(client, |offset, limit| {
+                NominationsBuilder::from(committee)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<CommitteeHouseCommunicationsResponse, _, _, _>(client, |offset, limit| {
+                HouseCommunicationBuilder::from(committee)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<CommitteeSenateCommunicationsResponse, _, _, _>(client, |offset, limit| {
+                SenateCommunicationBuilder::from(committee)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<HearingsResponse, _, _, _>(client, |offset, limit| {
+                hearing::Chamber::builder()
+                    .congress(congress)
+                    .chamber(chamber)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<CommitteeMeetingsResponse, _, _, _>(client, |offset, limit| {
+                committee_meeting::Chamber::builder()
+                    .congress(congress)
+                    .chamber(chamber)
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+        );
+
+        let mut report = Self {
+            committee,
+            congress,
+            bills: in_congress(bills, congress, |bill| bill.congress)?,
+            reports: in_congress(reports, congress, |report| report.congress)?,
+            nominations: in_congress(nominations, congress, |nomination| nomination.congress)?,
+            house_communications: in_congress(house, congress, |comm| comm.congress)?,
+            senate_communications: in_congress(senate, congress, |comm| comm.congress)?,
+            hearings: Vec::new(),
+            meetings: Vec::new(),
+            timeline: Vec::new(),
+        };
+
+        report.hearings = stream::iter(in_congress(hearings, congress, |h| h.congress)?)
+            .map(|listed| async move {
+                let endpoint = JacketNumber::builder()
+                    .congress(congress)
+                    .chamber(chamber)
+                    .jacket_number(listed.jacket_number)
+                    .build()
+                    .unwrap();
+                endpoint
+                    .query(client)
+                    .await
+                    .map(|HearingResponse { hearing }| hearing)
+            })
+            .buffered(DETAIL_CONCURRENCY)
+            .try_filter(|hearing| {
+                let held = hearing
+                    .committees
+                    .iter()
+                    .any(|c| held_by(committee, &c.system_code));
+                async move { held }
+            })
+            .try_collect()
+            .await?;
+
+        report.meetings = stream::iter(in_congress(meetings, congress, |m| m.congress)?)
+            .map(|listed| async move {
+                let endpoint = EventId::builder()
+                    .congress(congress)
+                    .chamber(chamber)
+                    .event_id(listed.event_id)
+                    .build()
+                    .unwrap();
+                endpoint
+                    .query(client)
+                    .await
+                    .map(|CommitteeMeetingResponse { committee_meeting }| committee_meeting)
+            })
+            .buffered(DETAIL_CONCURRENCY)
+            .try_filter(|meeting| {
+                let held = meeting
+                    .committees
+                    .iter()
+                    .any(|c| held_by(committee, &c.system_code));
+                async move { held }
+            })
+            .try_collect()
+            .await?;
+
+        report.timeline = report.events();
+        Ok(report)
+    }
+
+    fn events(&self) -> Vec<ActivityEvent> {
+        let mut events = Vec::new();
+        let mut push = |date: Option<NaiveDate>, kind, label: String| {
+            if let Some(date) = date {
+                events.push(ActivityEvent { date, kind, label });
+            }
+        };
+
+        for bill in &self.bills {
+            let id = BillId::new(bill.congress, bill.bill_type, bill.number);
+            let label = match &bill.relationship_type {
+                Some(relationship) => format!("{}: {relationship}", id.citation()),
+                None => id.citation(),
+            };
+            push(
+                bill.action_date.map(|date| date.date_naive()),
+                ActivityKind::Bill,
+                label,
+            );
+        }
+        for report in &self.reports {
+            push(
+                report.issue_date,
+                ActivityKind::Report,
+                report.citation.clone(),
+            );
+        }
+        for nomination in &self.nominations {
+            push(
+                nomination.received_date,
+                ActivityKind::Nomination,
+                nomination.citation.clone(),
+            );
+        }
+        for comm in &self.house_communications {
+            push(
+                comm.referral_date,
+                ActivityKind::HouseCommunication,
+                format!("{} {}", comm.communication_type.code, comm.number),
+            );
+        }
+        for comm in &self.senate_communications {
+            push(
+                comm.referral_date,
+                ActivityKind::SenateCommunication,
+                format!("{} {}", comm.communication_type.code, comm.number),
+            );
+        }
+        for hearing in &self.hearings {
+            push(
+                hearing.dates.iter().filter_map(|d| d.date).min(),
+                ActivityKind::Hearing,
+                hearing.title.clone().unwrap_or_default(),
+            );
+        }
+        for meeting in &self.meetings {
+            push(
+                meeting.date.map(|date| date.date_naive()),
+                ActivityKind::Meeting,
+                meeting.title.clone().unwrap_or_default(),
+            );
+        }
+
+        events.sort();
+        events
+    }
+
+    /// The number of items of a kind, whether dated or not.
+    pub fn count(&self, kind: ActivityKind) -> usize {
+        match kind {
+            ActivityKind::Bill => self.bills.len(),
+            ActivityKind::Report => self.reports.len(),
+            ActivityKind::Nomination => self.nominations.len(),
+            ActivityKind::HouseCommunication => self.house_communications.len(),
+            ActivityKind::SenateCommunication => self.senate_communications.len(),
+            ActivityKind::Hearing => self.hearings.len(),
+            ActivityKind::Meeting => self.meetings.len(),
+        }
+    }
+
+    /// The number of items of every kind.
+    pub fn counts(&self) -> BTreeMap<ActivityKind, usize> {
+        [
+            ActivityKind::Bill,
+            ActivityKind::Report,
+            ActivityKind::Nomination,
+            ActivityKind::HouseCommunication,
+            ActivityKind::SenateCommunication,
+            ActivityKind::Hearing,
+            ActivityKind::Meeting,
+        ]
+        .into_iter()
+        .map(|kind| (kind, self.count(kind)))
+        .collect()
+    }
+
+    /// The number of timeline events in each (year, month), optionally only
+    /// of one kind.
+    pub fn monthly(&self, kind: Option<ActivityKind>) -> BTreeMap<(i32, u32), u32> {
+        let mut months = BTreeMap::new();
+        for event in &self.timeline {
+            if kind.is_none_or(|kind| kind == event.kind) {
+                *months
+                    .entry((event.date.year(), event.date.month()))
+                    .or_default() += 1;
+            }
+        }
+        months
+    }
+}
+
+/// Keeps the items of a congress, or returns the error which interrupted
+/// fetching them.
+fn in_congress<T, E>(
+    (items, err): (Vec<T>, Option<E>),
+    congress: u16,
+    congress_of: impl Fn(&T) -> u16,
+) -> Result<Vec<T>, E> {
+    match err {
+        Some(err) => Err(err),
+        None => Ok(items
+            .into_iter()
+            .filter(|item| congress_of(item) == congress)
+            .collect()),
+    }
+}
+
+/// Whether an item of the committee with the given system code belongs to
+/// `committee`, being either the same committee or one of its subcommittees.
+fn held_by(committee: CommitteeSystemCode, system_code: &str) -> bool {
+    system_code.eq_ignore_ascii_case(committee.as_str())
+        || (committee.subcommittee().is_none()
+            && system_code
+                .get(..4)
+                .is_some_and(|code| code.eq_ignore_ascii_case(committee.committee())))
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::test::MockClient;
+
+    use super::*;
+
+    fn client() -> MockClient {
+        MockClient::new()
+            .respond(
+                "committee/house/hspw00/bills?offset=0&limit=250&fromDateTime=2023-01-03T00%3A00%3A00Z",
+                r#"{
+                    "committee-bills": {
+                        "bills": [
+                            {"actionDate": "2023-04-19T13:01:00Z", "billType": "HR", "congress": 118, "number": "2812", "relationshipType": "Referred to"},
+                            {"actionDate": "2023-03-02T13:01:00Z", "billType": "HR", "congress": 118, "number": "1", "relationshipType": "Reported by"},
+                            {"actionDate": "2012-04-19T13:01:00Z", "billType": "HR", "congress": 112, "number": "4414", "relationshipType": "Referred to"}
+                        ],
+                        "count": 3
+                    },
+                    "pagination": {"count": 3}
+                }"#,
+            )
+            .respond(
+                "committee/house/hspw00/reports?offset=0&limit=250&fromDateTime=2023-01-03T00%3A00%3A00Z",
+                r#"{"reports": [{"citation": "H. Rept. 118-10", "congress": 118, "number": 10, "issueDate": "2023-03-10"}], "pagination": {"count": 1}}"#,
+            )
+            .respond(
+                "committee/house/hspw00/nominations?offset=0&limit=250",
+                r#"{"nominations": [], "pagination": {"count": 0}}"#,
+            )
+            .respond(
+                "committee/house/hspw00/house-communication?offset=0&limit=250",
+                r#"{"houseCommunications": [{"chamber": "House", "communicationType": {"code": "EC", "name": "Executive Communication"}, "congress": 118, "number": 3262, "referralDate": "2023-03-27"}], "pagination": {"count": 1}}"#,
+            )
+            .respond(
+                "committee/house/hspw00/senate-communication?offset=0&limit=250",
+                r#"{"senateCommunications": [], "pagination": {"count": 0}}"#,
+            )
+            .respond(
+                "hearing/118/house?offset=0&limit=250",
+                r#"{"hearings": [{"jacketNumber": 51001, "congress": 118, "chamber": "House"}, {"jacketNumber": 51002, "congress": 118, "chamber": "House"}], "pagination": {"count": 2}}"#,
+            )
+            .respond(
+                "hearing/118/house/51001",
+                r#"{"hearing": {"jacketNumber": 51001, "congress": 118, "title": "Highway Funding", "committees": [{"name": "Highways and Transit Subcommittee", "systemCode": "hspw12"}], "dates": [{"date": "2023-03-02"}]}}"#,
+            )
+            .respond(
+                "hearing/118/house/51002",
+                r#"{"hearing": {"jacketNumber": 51002, "congress": 118, "title": "Farm Bill", "committees": [{"name": "Agriculture Committee", "systemCode": "hsag00"}], "dates": [{"date": "2023-03-05"}]}}"#,
+            )
+            .respond(
+                "committee-meeting/118/house?offset=0&limit=250",
+                r#"{"committeeMeetings": [{"eventId": "115538", "congress": 118, "chamber": "House"}], "pagination": {"count": 1}}"#,
+            )
+            .respond(
+                "committee-meeting/118/house/115538",
+                r#"{"committeeMeeting": {"eventId": "115538", "congress": 118, "title": "Markup of H.R. 1", "date": "2023-03-02T14:00:00Z", "committees": [{"name": "Transportation and Infrastructure Committee", "systemCode": "hspw00"}]}}"#,
+            )
+    }
+
+    #[tokio::test]
+    async fn rolls_up_activity() {
+        let code = "hspw00".parse().unwrap();
+        let report = CommitteeActivityReport::fetch(&client(), code, 118)
+            .await
+            .unwrap();
+
+        assert_eq!(report.count(ActivityKind::Bill), 2);
+        assert_eq!(report.counts()[&ActivityKind::Report], 1);
+        assert_eq!(report.counts()[&ActivityKind::Nomination], 0);
+        assert_eq!(report.hearings.len(), 1);
+        assert_eq!(report.hearings[0].jacket_number, 51001);
+        assert_eq!(report.meetings.len(), 1);
+
+        let timeline: Vec<_> = report
+            .timeline
+            .iter()
+            .map(|event| (event.date.to_string(), event.kind, event.label.as_str()))
+            .collect();
+        assert_eq!(
+            timeline,
+            [
+                (
+                    "2023-03-02".into(),
+                    ActivityKind::Bill,
+                    "H.R. 1: Reported by"
+                ),
+                (
+                    "2023-03-02".into(),
+                    ActivityKind::Hearing,
+                    "Highway Funding"
+                ),
+                (
+                    "2023-03-02".into(),
+                    ActivityKind::Meeting,
+                    "Markup of H.R. 1"
+                ),
+                ("2023-03-10".into(), ActivityKind::Report, "H. Rept. 118-10"),
+                (
+                    "2023-03-27".into(),
+                    ActivityKind::HouseCommunication,
+                    "EC 3262"
+                ),
+                (
+                    "2023-04-19".into(),
+                    ActivityKind::Bill,
+                    "H.R. 2812: Referred to"
+                ),
+            ]
+        );
+        assert_eq!(
+            report.monthly(None),
+            BTreeMap::from([((2023, 3), 5), ((2023, 4), 1)])
+        );
+        assert_eq!(
+            report.monthly(Some(ActivityKind::Bill)),
+            BTreeMap::from([((2023, 3), 1), ((2023, 4), 1)])
+        );
+    }
+
+    #[tokio::test]
+    async fn subcommittees_only_see_their_own() {
+        let since = "&fromDateTime=2023-01-03T00%3A00%3A00Z";
+        let client = [
+            ("bills", since),
+            ("reports", since),
+            ("nominations", ""),
+            ("house-communication", ""),
+            ("senate-communication", ""),
+        ]
+        .into_iter()
+        .fold(client(), |client, (resource, since)| {
+            client.respond(
+                &format!("committee/house/hspw12/{resource}?offset=0&limit=250{since}"),
+                r#"{"committee-bills": {"bills": []}, "pagination": {"count": 0}}"#,
+            )
+        });
+
+        let code = "hspw12".parse().unwrap();
+        let report = CommitteeActivityReport::fetch(&client, code, 118)
+            .await
+            .unwrap();
+
+        assert_eq!(report.hearings.len(), 1);
+        assert!(report.meetings.is_empty());
+        assert_eq!(report.timeline.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn fails_on_error() {
+        let client = client().respond_with(
+            "hearing/118/house/51002",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"{"error": "internal error"}"#,
+        );
+
+        let code = "hspw00".parse().unwrap();
+        let err = CommitteeActivityReport::fetch(&client, code, 118)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, ApiError::Http { .. }), "{err:?}");
+    }
+}
@@ -0,0 +1,485 @@
+//! Typed response models for the Committee endpoints.
+//!
+//! Each endpoint may be queried into the matching `*Response` type instead of
+//! a `serde_json::Value`:
+//!
+//! ```rust,no_run
+//! use congressdotgov_rs::api::committee::{self, models::CommitteeResponse, CommitteeChamber};
+//! use congressdotgov_rs::api::Query;
+//! use congressdotgov_rs::{Auth, Cdg};
+//!
+//! # tokio_test::block_on(async {
+//! let client = Cdg::new(Auth::Token("API_KEY".into())).unwrap();
+//! let endpoint = committee::CommitteeCode::builder()
+//!     .chamber(CommitteeChamber::House)
+//!     .committee_code("hspw00")
+//!     .build()
+//!     .unwrap();
+//!
+//! let res: CommitteeResponse = endpoint.query(&client).await.unwrap();
+//! for subcommittee in res.committee.subcommittees() {
+//!     println!("{}: {}", subcommittee.system_code, subcommittee.name);
+//! }
+//! # })
+//! ```
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::CommitteeChamber,
+    committee_report::models::CommitteeReport,
+    common::{BillType, Pagination, ResourceCount},
+    de,
+    house_communication::models::HouseCommunication,
+    nomination::models::Nomination,
+    paged::{impl_page, Page},
+    senate_communication::models::SenateCommunication,
+};
+
+/// The type of a committee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CommitteeTypeCode {
+    Standing,
+    Select,
+    Special,
+    Joint,
+    #[serde(rename = "Task Force")]
+    TaskForce,
+    Subcommittee,
+    #[serde(rename = "Commission or Caucus")]
+    CommissionOrCaucus,
+    #[serde(other)]
+    Other,
+}
+
+/// A link to a parent committee or subcommittee.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeRef {
+    pub name: String,
+    pub system_code: String,
+    pub url: Option<String>,
+}
+
+/// A committee to which a House or Senate communication was referred.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommunicationReferral {
+    pub name: String,
+    pub system_code: String,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub referral_date: Option<NaiveDate>,
+    pub url: Option<String>,
+}
+
+/// A single entry of a committee's name history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeHistory {
+    pub official_name: Option<String>,
+    pub library_of_congress_name: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub start_date: Option<DateTime<Utc>>,
+    /// Absent while the name is still in use.
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub end_date: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+impl CommitteeHistory {
+    /// Whether this name was in use on the given date.
+    pub fn was_current_on(&self, date: NaiveDate) -> bool {
+        self.start_date
+            .is_none_or(|start| start.date_naive() <= date)
+            && self.end_date.is_none_or(|end| date < end.date_naive())
+    }
+}
+
+/// A committee or subcommittee.
+///
+/// List endpoints return a summary containing the name and chamber, while
+/// the /committee/:chamber/:committeeCode endpoint returns the name history
+/// and related resource counts. Fields not present in a given response are
+/// left empty.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Committee {
+    pub system_code: String,
+    pub name: Option<String>,
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(alias = "type")]
+    pub committee_type_code: Option<CommitteeTypeCode>,
+    pub is_current: Option<bool>,
+    pub parent: Option<CommitteeRef>,
+    #[serde(default, deserialize_with = "de::one_or_many")]
+    pub subcommittees: Vec<CommitteeRef>,
+    #[serde(default)]
+    pub history: Vec<CommitteeHistory>,
+    pub bills: Option<ResourceCount>,
+    pub reports: Option<ResourceCount>,
+    pub communications: Option<ResourceCount>,
+    pub nominations: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl Committee {
+    /// The subcommittees of this committee.
+    pub fn subcommittees(&self) -> &[CommitteeRef] {
+        &self.subcommittees
+    }
+
+    /// Whether this is a subcommittee of another committee.
+    pub fn is_subcommittee(&self) -> bool {
+        self.parent.is_some()
+    }
+
+    /// The current name of the committee.
+    ///
+    /// This is the `name` returned by list endpoints, falling back to the
+    /// official name of the open-ended entry in the name history.
+    pub fn current_name(&self) -> Option<&str> {
+        self.name.as_deref().or_else(|| {
+            self.history
+                .iter()
+                .find(|h| h.end_date.is_none())
+                .and_then(|h| h.official_name.as_deref())
+        })
+    }
+
+    /// The name history entry in use on the given date.
+    pub fn name_on(&self, date: NaiveDate) -> Option<&CommitteeHistory> {
+        self.history.iter().find(|h| h.was_current_on(date))
+    }
+}
+
+/// An activity of a committee on an item referred to it, such as
+/// "Referred to" or "Reported by".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeActivity {
+    pub name: String,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub date: Option<DateTime<Utc>>,
+}
+
+/// A committee to which an item was referred, along with the activities
+/// of the committee on that item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeReferral {
+    pub name: String,
+    pub system_code: String,
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(rename = "type")]
+    pub committee_type_code: Option<CommitteeTypeCode>,
+    #[serde(default)]
+    pub activities: Vec<CommitteeActivity>,
+    #[serde(default)]
+    pub subcommittees: Vec<CommitteeReferral>,
+    pub url: Option<String>,
+}
+
+/// A bill referred to or otherwise acted on by a committee.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeBill {
+    pub congress: u16,
+    pub bill_type: BillType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub action_date: Option<DateTime<Utc>>,
+    pub relationship_type: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The bills associated with a committee.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeBills {
+    #[serde(default)]
+    pub bills: Vec<CommitteeBill>,
+    #[serde(default)]
+    pub count: u32,
+    pub url: Option<String>,
+}
+
+/// The response of the /committee, /committee/:congress,
+/// /committee/:chamber and /committee/:congress/:chamber endpoints.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteesResponse {
+    #[serde(default)]
+    pub committees: Vec<Committee>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee/:chamber/:committeeCode endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeResponse {
+    pub committee: Committee,
+}
+
+/// The response of the /committee/:chamber/:committeeCode/bills endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeBillsResponse {
+    #[serde(rename = "committee-bills")]
+    pub committee_bills: CommitteeBills,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee/:chamber/:committeeCode/reports endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeReportsResponse {
+    #[serde(default)]
+    pub reports: Vec<CommitteeReport>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee/:chamber/:committeeCode/nominations endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeNominationsResponse {
+    #[serde(default)]
+    pub nominations: Vec<Nomination>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee/:chamber/:committeeCode/house-communication
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeHouseCommunicationsResponse {
+    #[serde(default)]
+    pub house_communications: Vec<HouseCommunication>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee/:chamber/:committeeCode/senate-communication
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeSenateCommunicationsResponse {
+    #[serde(default)]
+    pub senate_communications: Vec<SenateCommunication>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl Page for CommitteeBillsResponse {
+    type Item = CommitteeBill;
+
+    fn into_page(self) -> (Vec<CommitteeBill>, Pagination) {
+        (self.committee_bills.bills, self.pagination)
+    }
+}
+
+impl_page!(
+    CommitteesResponse => committees: Committee,
+    CommitteeReportsResponse => reports: CommitteeReport,
+    CommitteeNominationsResponse => nominations: Nomination,
+    CommitteeHouseCommunicationsResponse => house_communications: HouseCommunication,
+    CommitteeSenateCommunicationsResponse => senate_communications: SenateCommunication,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::committee_report::CommitteeReportType;
+
+    use super::*;
+
+    #[test]
+    fn committee_detail() {
+        let json = r#"{
+            "committee": {
+                "bills": {"count": 25384, "url": "https://api.congress.gov/v3/committee/house/hspw00/bills?format=json"},
+                "communications": {"count": 6775, "url": "https://api.congress.gov/v3/committee/house/hspw00/house-communication?format=json"},
+                "history": [
+                    {
+                        "libraryOfCongressName": "Transportation and Infrastructure",
+                        "officialName": "Committee on Transportation and Infrastructure",
+                        "startDate": "1995-01-04T05:00:00Z",
+                        "updateDate": "2020-02-04T00:07:37Z"
+                    },
+                    {
+                        "endDate": "1995-01-03T05:00:00Z",
+                        "libraryOfCongressName": "Public Works and Transportation",
+                        "officialName": "Committee on Public Works and Transportation",
+                        "startDate": "1975-01-14T05:00:00Z",
+                        "updateDate": "2020-02-10T16:49:05Z"
+                    }
+                ],
+                "isCurrent": true,
+                "reports": {"count": 1319, "url": "https://api.congress.gov/v3/committee/house/hspw00/reports?format=json"},
+                "subcommittees": [
+                    {"name": "Highways and Transit Subcommittee", "systemCode": "hspw12", "url": "https://api.congress.gov/v3/committee/house/hspw12?format=json"},
+                    {"name": "Aviation Subcommittee", "systemCode": "hspw05", "url": "https://api.congress.gov/v3/committee/house/hspw05?format=json"}
+                ],
+                "systemCode": "hspw00",
+                "type": "Standing",
+                "updateDate": "2020-02-04T00:07:37Z"
+            },
+            "request": {"chamber": "house", "committeeCode": "hspw00", "contentType": "application/json", "format": "json"}
+        }"#;
+
+        let res: CommitteeResponse = serde_json::from_str(json).unwrap();
+        let committee = res.committee;
+
+        assert_eq!(committee.system_code, "hspw00");
+        assert_eq!(
+            committee.committee_type_code,
+            Some(CommitteeTypeCode::Standing)
+        );
+        assert_eq!(committee.is_current, Some(true));
+        assert!(!committee.is_subcommittee());
+        assert_eq!(committee.bills.as_ref().unwrap().count, 25384);
+
+        let codes: Vec<_> = committee
+            .subcommittees()
+            .iter()
+            .map(|s| s.system_code.as_str())
+            .collect();
+        assert_eq!(codes, ["hspw12", "hspw05"]);
+
+        assert_eq!(
+            committee.current_name(),
+            Some("Committee on Transportation and Infrastructure")
+        );
+        let old = committee
+            .name_on(NaiveDate::from_ymd_opt(1990, 6, 1).unwrap())
+            .unwrap();
+        assert_eq!(
+            old.library_of_congress_name.as_deref(),
+            Some("Public Works and Transportation")
+        );
+        assert!(old.end_date.is_some());
+    }
+
+    #[test]
+    fn committee_list() {
+        let json = r#"{
+            "committees": [
+                {
+                    "chamber": "House",
+                    "committeeTypeCode": "Subcommittee",
+                    "name": "Water Resources and Environment Subcommittee",
+                    "parent": {"name": "Transportation and Infrastructure Committee", "systemCode": "hspw00", "url": "https://api.congress.gov/v3/committee/house/hspw00?format=json"},
+                    "systemCode": "hspw02",
+                    "updateDate": "2021-01-20T16:19:46Z",
+                    "url": "https://api.congress.gov/v3/committee/house/hspw02?format=json"
+                },
+                {
+                    "chamber": "Joint",
+                    "committeeTypeCode": "Joint",
+                    "name": "Joint Economic Committee",
+                    "parent": null,
+                    "subcommittees": {"name": "Economic Goals and Intergovernmental Policy Subcommittee", "systemCode": "jsec01", "url": null},
+                    "systemCode": "jsec00",
+                    "updateDate": "2020-02-04T00:07:37Z",
+                    "url": "https://api.congress.gov/v3/committee/joint/jsec00?format=json"
+                }
+            ],
+            "pagination": {"count": 220, "next": "https://api.congress.gov/v3/committee/118/house?offset=20&limit=20&format=json"}
+        }"#;
+
+        let res: CommitteesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.pagination.count, 220);
+        assert!(res.pagination.next.is_some());
+
+        let sub = &res.committees[0];
+        assert!(sub.is_subcommittee());
+        assert_eq!(sub.parent.as_ref().unwrap().system_code, "hspw00");
+        assert_eq!(sub.chamber, Some(CommitteeChamber::House));
+        assert!(sub.subcommittees().is_empty());
+
+        let joint = &res.committees[1];
+        assert_eq!(joint.chamber, Some(CommitteeChamber::Joint));
+        assert_eq!(joint.subcommittees().len(), 1);
+        assert_eq!(joint.current_name(), Some("Joint Economic Committee"));
+    }
+
+    #[test]
+    fn committee_sub_resources() {
+        let bills: CommitteeBillsResponse = serde_json::from_str(
+            r#"{
+                "committee-bills": {
+                    "bills": [{"actionDate": "2012-04-19T13:01:00Z", "billType": "HR", "congress": 112, "number": "4414", "relationshipType": "Referred to", "updateDate": "2019-02-17T21:10:13Z", "url": "https://api.congress.gov/v3/bill/112/hr/4414?format=json"}],
+                    "count": 25384,
+                    "url": "https://api.congress.gov/v3/committee/house/hspw00/bills?format=json"
+                },
+                "pagination": {"count": 25384}
+            }"#,
+        )
+        .unwrap();
+        let bill = &bills.committee_bills.bills[0];
+        assert_eq!(bill.bill_type, BillType::Hr);
+        assert_eq!(bill.number, 4414);
+
+        let reports: CommitteeReportsResponse = serde_json::from_str(
+            r#"{"reports": [{"chamber": "House", "citation": "H. Rept. 109-570", "congress": 109, "number": 570, "part": 1, "type": "HRPT", "updateDate": "2015-03-20 00:42:12+00:00", "url": "https://api.congress.gov/v3/committee-report/109/HRPT/570?format=json"}], "pagination": {"count": 1319}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            reports.reports[0].report_type,
+            Some(CommitteeReportType::Hrpt)
+        );
+        assert!(reports.reports[0].update_date.is_some());
+
+        let nominations: CommitteeNominationsResponse = serde_json::from_str(
+            r#"{"nominations": [{"citation": "PN2477", "congress": 117, "description": "Julia Frifield, of the District of Columbia, to be an Assistant Secretary of State (Legislative Affairs), vice Naz Durakoglu.", "latestAction": {"actionDate": "2022-08-03", "text": "Received in the Senate and referred to the Committee on Foreign Relations."}, "nominationType": {"isCivilian": true}, "number": 2477, "partNumber": "00", "receivedDate": "2022-08-03", "updateDate": "2022-08-04T04:25:12Z", "url": "https://api.congress.gov/v3/nomination/117/2477?format=json"}]}"#,
+        )
+        .unwrap();
+        let nomination = &nominations.nominations[0];
+        assert!(!nomination.is_military());
+        assert_eq!(
+            nomination.latest_action.as_ref().unwrap().action_date,
+            NaiveDate::from_ymd_opt(2022, 8, 3)
+        );
+
+        let house: CommitteeHouseCommunicationsResponse = serde_json::from_str(
+            r#"{"houseCommunications": [{"chamber": "House", "communicationType": {"code": "EC", "name": "Executive Communication"}, "congress": 114, "number": 3262, "referralDate": "2015-10-27", "updateDate": "2018-02-02", "url": "https://api.congress.gov/v3/house-communication/114/ec/3262?format=json"}]}"#,
+        )
+        .unwrap();
+        let communication = &house.house_communications[0];
+        assert_eq!(communication.communication_type.code, "EC");
+        assert_eq!(
+            communication.referral_date,
+            NaiveDate::from_ymd_opt(2015, 10, 27)
+        );
+
+        let senate: CommitteeSenateCommunicationsResponse = serde_json::from_str(
+            r#"{"senateCommunications": [{"chamber": "Senate", "communicationType": {"code": "EC", "name": "Executive Communication"}, "congress": 114, "number": 7402, "referralDate": "2016-09-28", "updateDate": "2017-01-06T16:49:15Z", "url": "https://api.congress.gov/v3/senate-communication/114/ec/7402?format=json"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(senate.senate_communications[0].number, 7402);
+    }
+}
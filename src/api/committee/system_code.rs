@@ -0,0 +1,213 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::api::{
+    committee::{
+        BillsBuilder, CommitteeChamber, CommitteeCodeBuilder, HouseCommunicationBuilder,
+        NominationsBuilder, ReportsBuilder, SenateCommunicationBuilder,
+    },
+    common::ParseIdError,
+};
+
+/// The system code of a committee or subcommittee, such as "hspw00" for the
+/// House Committee on Transportation and Infrastructure or "ssfi13" for a
+/// subcommittee of the Senate Committee on Finance.
+///
+/// A system code is made of a chamber letter ('h', 's' or 'j'), three
+/// letters identifying the committee and two digits numbering the
+/// subcommittee, which are "00" for a full committee. It is parsed ignoring
+/// case, can be given to every builder that takes a committee code, and
+/// converts into the builder of every endpoint under
+/// /committee/:chamber/:committeeCode.
+///
+/// ```rust
+/// use congressdotgov_rs::api::committee::{self, CommitteeChamber, CommitteeSystemCode};
+///
+/// let code: CommitteeSystemCode = "ssfi13".parse().unwrap();
+/// assert_eq!(code.chamber(), CommitteeChamber::Senate);
+/// assert_eq!(code.committee(), "ssfi");
+/// assert_eq!(code.subcommittee(), Some(13));
+/// assert_eq!(code.parent().unwrap().as_str(), "ssfi00");
+///
+/// let bills = committee::BillsBuilder::from(code).build().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CommitteeSystemCode([u8; 6]);
+
+impl CommitteeSystemCode {
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("system codes are ASCII")
+    }
+
+    pub fn chamber(&self) -> CommitteeChamber {
+        match self.0[0] {
+            b'h' => CommitteeChamber::House,
+            b's' => CommitteeChamber::Senate,
+            _ => CommitteeChamber::Joint,
+        }
+    }
+
+    /// The code of the committee, including its chamber, e.g. "hspw".
+    pub fn committee(&self) -> &str {
+        &self.as_str()[..4]
+    }
+
+    /// The number of the subcommittee, or `None` for a full committee.
+    pub fn subcommittee(&self) -> Option<u8> {
+        let number = (self.0[4] - b'0') * 10 + (self.0[5] - b'0');
+        (number > 0).then_some(number)
+    }
+
+    pub fn is_subcommittee(&self) -> bool {
+        self.subcommittee().is_some()
+    }
+
+    /// The full committee of a subcommittee, or `None` for a full committee.
+    pub fn parent(&self) -> Option<Self> {
+        self.is_subcommittee().then(|| {
+            let mut code = self.0;
+            code[4..].copy_from_slice(b"00");
+            Self(code)
+        })
+    }
+}
+
+impl FromStr for CommitteeSystemCode {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseIdError {
+            kind: "committee system code",
+            input: s.into(),
+        };
+        let mut code: [u8; 6] = s.trim().as_bytes().try_into().map_err(|_| invalid())?;
+        code.make_ascii_lowercase();
+        if !matches!(code[0], b'h' | b's' | b'j')
+            || !code[1..4].iter().all(u8::is_ascii_lowercase)
+            || !code[4..].iter().all(u8::is_ascii_digit)
+        {
+            return Err(invalid());
+        }
+
+        Ok(Self(code))
+    }
+}
+
+impl fmt::Display for CommitteeSystemCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<CommitteeSystemCode> for Cow<'a, str> {
+    fn from(code: CommitteeSystemCode) -> Self {
+        Cow::Owned(code.as_str().into())
+    }
+}
+
+impl Serialize for CommitteeSystemCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for CommitteeSystemCode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+macro_rules! impl_builder_from_system_code {
+    ($($builder:ident),* $(,)?) => {
+        $(
+            impl From<CommitteeSystemCode> for $builder<'_> {
+                fn from(code: CommitteeSystemCode) -> Self {
+                    let mut builder = Self::default();
+                    builder.chamber(code.chamber()).committee_code(code);
+                    builder
+                }
+            }
+        )*
+    };
+}
+
+impl_builder_from_system_code!(
+    BillsBuilder,
+    CommitteeCodeBuilder,
+    HouseCommunicationBuilder,
+    NominationsBuilder,
+    ReportsBuilder,
+    SenateCommunicationBuilder,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::endpoint::Endpoint;
+
+    use super::*;
+
+    #[test]
+    fn parses_system_codes() {
+        let code: CommitteeSystemCode = "HSPW00".parse().unwrap();
+        assert_eq!(code.as_str(), "hspw00");
+        assert_eq!(code.chamber(), CommitteeChamber::House);
+        assert_eq!(code.committee(), "hspw");
+        assert_eq!(code.subcommittee(), None);
+        assert_eq!(code.parent(), None);
+
+        let code: CommitteeSystemCode = "jsec00".parse().unwrap();
+        assert_eq!(code.chamber(), CommitteeChamber::Joint);
+
+        for s in [
+            "", "hspw0", "hspw000", "xspw00", "hs1w00", "hspwaa", "hspw-0",
+        ] {
+            assert!(s.parse::<CommitteeSystemCode>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn subcommittees() {
+        let code: CommitteeSystemCode = "ssfi13".parse().unwrap();
+        assert!(code.is_subcommittee());
+        assert_eq!(code.subcommittee(), Some(13));
+        assert_eq!(code.parent(), Some("ssfi00".parse().unwrap()));
+        assert_eq!(code.parent().unwrap().parent(), None);
+    }
+
+    #[test]
+    fn system_code_serde() {
+        let code: CommitteeSystemCode = serde_json::from_str(r#""hsif16""#).unwrap();
+        assert_eq!(serde_json::to_string(&code).unwrap(), r#""hsif16""#);
+        assert!(serde_json::from_str::<CommitteeSystemCode>(r#""hsif""#).is_err());
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let code: CommitteeSystemCode = "ssas00".parse().unwrap();
+
+        assert_eq!(
+            NominationsBuilder::from(code).build().unwrap().endpoint(),
+            "committee/senate/ssas00/nominations"
+        );
+        assert_eq!(
+            CommitteeCodeBuilder::default()
+                .chamber(CommitteeChamber::Senate)
+                .committee_code(code)
+                .build()
+                .unwrap()
+                .endpoint(),
+            "committee/senate/ssas00"
+        );
+    }
+}
@@ -6,6 +6,9 @@ mod chamber;
 mod committee_meeting;
 mod congress;
 mod event_id;
+mod meeting_event_id;
+
+pub mod models;
 
 pub use self::chamber::{Chamber, ChamberBuilder, ChamberBuilderError};
 pub use self::committee_meeting::{
@@ -13,3 +16,4 @@ pub use self::committee_meeting::{
 };
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
 pub use self::event_id::{EventId, EventIdBuilder, EventIdBuilderError};
+pub use self::meeting_event_id::MeetingEventId;
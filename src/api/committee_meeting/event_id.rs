@@ -9,6 +9,8 @@ use crate::{
 };
 
 /// Represents the /committee-meeting/:congress/:chamber/:eventId endpoint.
+///
+/// The event ID can be given as a [`MeetingEventId`](super::MeetingEventId).
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct EventId {
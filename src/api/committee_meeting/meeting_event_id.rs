@@ -0,0 +1,150 @@
+use std::fmt;
+use std::num::NonZeroU32;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::api::{common::ParseIdError, de};
+
+/// The event ID of a committee meeting, a positive number such as "115538".
+///
+/// A `MeetingEventId` is parsed from its digits, deserialized from either
+/// the string or the number the API returns, and can be given to the
+/// [`EventId`](crate::api::committee_meeting::EventId) builder.
+///
+/// ```rust
+/// use congressdotgov_rs::api::{
+///     committee_meeting::{EventId, MeetingEventId},
+///     common::CommitteeChamber,
+/// };
+///
+/// let event: MeetingEventId = "115538".parse().unwrap();
+/// assert_eq!(event.get(), 115538);
+/// assert!("11553a".parse::<MeetingEventId>().is_err());
+/// assert!(MeetingEventId::try_from(0).is_err());
+///
+/// let endpoint = EventId::builder()
+///     .congress(118_u16)
+///     .chamber(CommitteeChamber::House)
+///     .event_id(event)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MeetingEventId(NonZeroU32);
+
+impl MeetingEventId {
+    pub fn get(self) -> u32 {
+        self.0.get()
+    }
+}
+
+fn invalid(input: impl Into<String>) -> ParseIdError {
+    ParseIdError {
+        kind: "meeting event ID",
+        input: input.into(),
+    }
+}
+
+impl FromStr for MeetingEventId {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let digits = s.trim();
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid(s));
+        }
+
+        digits
+            .parse::<NonZeroU32>()
+            .map(Self)
+            .map_err(|_| invalid(s))
+    }
+}
+
+impl TryFrom<u32> for MeetingEventId {
+    type Error = ParseIdError;
+
+    fn try_from(number: u32) -> Result<Self, Self::Error> {
+        NonZeroU32::new(number)
+            .map(Self)
+            .ok_or_else(|| invalid(number.to_string()))
+    }
+}
+
+impl From<MeetingEventId> for u32 {
+    fn from(id: MeetingEventId) -> Self {
+        id.get()
+    }
+}
+
+impl PartialEq<u32> for MeetingEventId {
+    fn eq(&self, other: &u32) -> bool {
+        self.get() == *other
+    }
+}
+
+impl fmt::Display for MeetingEventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Serialize for MeetingEventId {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for MeetingEventId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Self::try_from(de::number(deserializer)?).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{committee_meeting::EventId, common::CommitteeChamber, endpoint::Endpoint};
+
+    use super::*;
+
+    #[test]
+    fn parses_event_ids() {
+        assert_eq!("115538".parse::<MeetingEventId>().unwrap(), 115538);
+        assert_eq!(" 333542 ".parse::<MeetingEventId>().unwrap(), 333542);
+
+        for s in ["", "0", "-1", "+1", "11553a", "1e5", "99999999999"] {
+            assert!(s.parse::<MeetingEventId>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn event_id_serde() {
+        let id: MeetingEventId = serde_json::from_str(r#""115538""#).unwrap();
+        assert_eq!(
+            serde_json::from_str::<MeetingEventId>("115538").unwrap(),
+            id
+        );
+        assert_eq!(serde_json::to_string(&id).unwrap(), r#""115538""#);
+        assert!(serde_json::from_str::<MeetingEventId>(r#""0""#).is_err());
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let id: MeetingEventId = "115538".parse().unwrap();
+
+        let endpoint = EventId::builder()
+            .congress(118_u16)
+            .chamber(CommitteeChamber::House)
+            .event_id(id)
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.endpoint(), "committee-meeting/118/house/115538");
+    }
+}
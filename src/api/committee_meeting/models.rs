@@ -0,0 +1,227 @@
+//! Typed response models for the Committee Meeting endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::models::CommitteeRef,
+    committee_meeting::MeetingEventId,
+    common::{AssociatedBill, CommitteeChamber, Pagination},
+    de,
+    paged::impl_page,
+};
+
+/// The status of a committee meeting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MeetingStatus {
+    Scheduled,
+    Canceled,
+    Postponed,
+    Rescheduled,
+    #[serde(other)]
+    Other,
+}
+
+/// Where a committee meeting is held.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MeetingLocation {
+    pub building: Option<String>,
+    pub room: Option<String>,
+    pub address: Option<String>,
+}
+
+/// A witness testifying at a committee meeting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Witness {
+    pub name: String,
+    pub organization: Option<String>,
+    pub position: Option<String>,
+}
+
+/// A document submitted for a committee meeting, either by the committee or
+/// by a witness.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MeetingDocument {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub document_type: Option<String>,
+    pub format: Option<String>,
+    pub url: Option<String>,
+}
+
+/// A printed transcript of a hearing held at a committee meeting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HearingTranscript {
+    #[serde(deserialize_with = "de::number")]
+    pub jacket_number: u32,
+    pub url: Option<String>,
+}
+
+/// A video recording of a committee meeting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Video {
+    pub name: Option<String>,
+    pub url: String,
+}
+
+/// A nomination or treaty considered at a committee meeting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelatedItem {
+    pub congress: u16,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub part: Option<String>,
+    pub url: Option<String>,
+}
+
+/// The items considered at a committee meeting.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelatedItems {
+    #[serde(default)]
+    pub bills: Vec<AssociatedBill>,
+    #[serde(default)]
+    pub nominations: Vec<RelatedItem>,
+    #[serde(default)]
+    pub treaties: Vec<RelatedItem>,
+}
+
+/// A committee meeting, as returned by both the list and detail endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeMeeting {
+    pub event_id: MeetingEventId,
+    pub congress: u16,
+    pub chamber: Option<CommitteeChamber>,
+    pub title: Option<String>,
+    /// The kind of meeting, e.g. "Hearing" or "Markup".
+    #[serde(rename = "type")]
+    pub meeting_type: Option<String>,
+    pub meeting_status: Option<MeetingStatus>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub date: Option<DateTime<Utc>>,
+    pub location: Option<MeetingLocation>,
+    #[serde(default)]
+    pub committees: Vec<CommitteeRef>,
+    #[serde(default)]
+    pub witnesses: Vec<Witness>,
+    #[serde(default)]
+    pub witness_documents: Vec<MeetingDocument>,
+    #[serde(default)]
+    pub meeting_documents: Vec<MeetingDocument>,
+    #[serde(default)]
+    pub hearing_transcript: Vec<HearingTranscript>,
+    #[serde(default)]
+    pub videos: Vec<Video>,
+    #[serde(default)]
+    pub related_items: RelatedItems,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The response of the /committee-meeting, /committee-meeting/:congress and
+/// /committee-meeting/:congress/:chamber endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeMeetingsResponse {
+    #[serde(default)]
+    pub committee_meetings: Vec<CommitteeMeeting>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee-meeting/:congress/:chamber/:eventId
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeMeetingResponse {
+    pub committee_meeting: CommitteeMeeting,
+}
+
+impl_page!(CommitteeMeetingsResponse => committee_meetings: CommitteeMeeting);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::BillType;
+
+    use super::*;
+
+    #[test]
+    fn committee_meeting_detail() {
+        let json = r#"{
+            "committeeMeeting": {
+                "chamber": "House",
+                "committees": [{"name": "House Natural Resources Subcommittee on Federal Lands", "systemCode": "hsii10", "url": "https://api.congress.gov/v3/committee/house/hsii10?format=json"}],
+                "congress": 118,
+                "date": "2023-03-28T14:00:00Z",
+                "eventId": "115538",
+                "hearingTranscript": [],
+                "location": {"building": "Longworth House Office Building", "room": "1324"},
+                "meetingDocuments": [{"description": null, "documentType": "Support Document", "format": "PDF", "name": "Hearing Notice", "url": "https://www.congress.gov/118/meeting/house/115538/documents/HHRG-118-II10-20230328-SD001.pdf"}],
+                "meetingStatus": "Scheduled",
+                "relatedItems": {"bills": [{"congress": 118, "number": 1, "type": "HR", "url": "https://api.congress.gov/v3/bill/118/hr/1?format=json"}], "nominations": [], "treaties": []},
+                "title": "Legislative hearing on H.R. 1",
+                "type": "Hearing",
+                "updateDate": "2023-03-27T20:24:49Z",
+                "videos": [{"name": "Legislative hearing", "url": "https://www.youtube.com/watch?v=example"}],
+                "witnessDocuments": [{"documentType": "Witness Statement", "format": "PDF", "url": "https://www.congress.gov/118/meeting/house/115538/witnesses/HHRG-118-II10-Wstate-DoeJ-20230328.pdf"}],
+                "witnesses": [{"name": "The Honorable Jane Doe", "organization": "Department of the Interior", "position": "Director"}]
+            }
+        }"#;
+
+        let res: CommitteeMeetingResponse = serde_json::from_str(json).unwrap();
+        let meeting = res.committee_meeting;
+
+        assert_eq!(meeting.event_id, 115538);
+        assert_eq!(meeting.meeting_status, Some(MeetingStatus::Scheduled));
+        assert_eq!(meeting.meeting_type.as_deref(), Some("Hearing"));
+        assert_eq!(
+            meeting.location.unwrap().building.as_deref(),
+            Some("Longworth House Office Building")
+        );
+        assert_eq!(meeting.witnesses[0].position.as_deref(), Some("Director"));
+        assert_eq!(
+            meeting.witness_documents[0].document_type.as_deref(),
+            Some("Witness Statement")
+        );
+        assert_eq!(
+            meeting.meeting_documents[0].name.as_deref(),
+            Some("Hearing Notice")
+        );
+        assert_eq!(meeting.related_items.bills[0].bill_type, BillType::Hr);
+        assert!(meeting.hearing_transcript.is_empty());
+    }
+
+    #[test]
+    fn committee_meeting_list() {
+        let json = r#"{"committeeMeetings": [{"chamber": "NoChamber", "congress": 118, "eventId": "115540", "updateDate": "2023-03-27T20:24:49Z", "url": "https://api.congress.gov/v3/committee-meeting/118/nochamber/115540?format=json"}, {"chamber": "Senate", "congress": 118, "eventId": "333542", "meetingStatus": "Postponed"}], "pagination": {"count": 2}}"#;
+
+        let res: CommitteeMeetingsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            res.committee_meetings[0].chamber,
+            Some(CommitteeChamber::NoChamber)
+        );
+        assert_eq!(
+            res.committee_meetings[1].meeting_status,
+            Some(MeetingStatus::Postponed)
+        );
+    }
+}
@@ -8,6 +8,8 @@ mod congress;
 mod jacket_number;
 mod text;
 
+pub mod models;
+
 pub use self::chamber::{Chamber, ChamberBuilder, ChamberBuilderError};
 pub use self::committee_print::{
     CommitteePrint, CommitteePrintBuilder, CommitteePrintBuilderError,
@@ -0,0 +1,128 @@
+//! Typed response models for the Committee Print endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::models::CommitteeRef,
+    common::{AssociatedBill, CommitteeChamber, Pagination, ResourceCount, TextFormat},
+    de,
+};
+
+/// A committee print, as returned by both the list and detail endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteePrint {
+    #[serde(deserialize_with = "de::number")]
+    pub jacket_number: u32,
+    pub congress: u16,
+    pub chamber: Option<CommitteeChamber>,
+    pub citation: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub number: Option<u32>,
+    pub title: Option<String>,
+    #[serde(default)]
+    pub associated_bills: Vec<AssociatedBill>,
+    #[serde(default)]
+    pub committees: Vec<CommitteeRef>,
+    pub text: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The response of the /committee-print, /committee-print/:congress and
+/// /committee-print/:congress/:chamber endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteePrintsResponse {
+    #[serde(default)]
+    pub committee_prints: Vec<CommitteePrint>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee-print/:congress/:chamber/:jacketNumber
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteePrintResponse {
+    #[serde(default, deserialize_with = "de::one_or_many")]
+    pub committee_print: Vec<CommitteePrint>,
+}
+
+/// The response of the
+/// /committee-print/:congress/:chamber/:jacketNumber/text endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteePrintTextResponse {
+    #[serde(default)]
+    pub text: Vec<TextFormat>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::TextFormatKind;
+
+    use super::*;
+
+    #[test]
+    fn committee_print_detail() {
+        let json = r#"{
+            "committeePrint": [
+                {
+                    "associatedBills": [{"congress": 117, "number": "5768", "type": "HR", "url": "https://api.congress.gov/v3/bill/117/hr/5768?format=json"}],
+                    "chamber": "House",
+                    "citation": "117-62",
+                    "committees": [{"name": "Rules Committee", "systemCode": "hsru00", "url": "https://api.congress.gov/v3/committee/house/hsru00?format=json"}],
+                    "congress": 117,
+                    "jacketNumber": 48144,
+                    "number": "62",
+                    "text": {"count": 4, "url": "https://api.congress.gov/v3/committee-print/117/house/48144/text?format=json"},
+                    "title": "RULES COMMITTEE PRINT 117-62 TEXT OF H.R. 5768, VIOLENT INCIDENT CLEAR-ANCE AND TECHNOLOGICAL INVESTIGATIVE METHODS ACT OF 2022",
+                    "updateDate": "2022-08-01 21:19:33+00:00"
+                }
+            ]
+        }"#;
+
+        let res: CommitteePrintResponse = serde_json::from_str(json).unwrap();
+        let print = &res.committee_print[0];
+
+        assert_eq!(print.jacket_number, 48144);
+        assert_eq!(print.number, Some(62));
+        assert_eq!(print.committees[0].system_code, "hsru00");
+        assert_eq!(print.associated_bills[0].number, 5768);
+    }
+
+    #[test]
+    fn committee_print_list_and_text() {
+        let list: CommitteePrintsResponse = serde_json::from_str(
+            r#"{"committeePrints": [{"chamber": "House", "congress": 117, "jacketNumber": 48144, "updateDate": "2022-08-01 21:19:33+00:00", "url": "https://api.congress.gov/v3/committee-print/117/house/48144?format=json"}], "pagination": {"count": 1}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            list.committee_prints[0].chamber,
+            Some(CommitteeChamber::House)
+        );
+
+        let text: CommitteePrintTextResponse = serde_json::from_str(
+            r#"{"text": [{"type": "Formatted Text", "url": "https://www.congress.gov/117/cprt/HPRT48144/CPRT-117HPRT48144.htm"}, {"type": "Formatted XML", "url": "https://www.congress.gov/117/cprt/HPRT48144/CPRT-117HPRT48144.xml"}, {"type": "Generated HTML", "url": "https://www.congress.gov/117/cprt/HPRT48144/generated/CPRT-117HPRT48144.htm"}]}"#,
+        )
+        .unwrap();
+        let kinds: Vec<_> = text.text.iter().map(|t| t.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                TextFormatKind::FormattedText,
+                TextFormatKind::FormattedXml,
+                TextFormatKind::GeneratedHtml
+            ]
+        );
+    }
+}
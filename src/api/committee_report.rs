@@ -2,21 +2,27 @@
 
 //! Committee-report API endpoints and types.
 
+use crate::api::common::impl_api_enum;
+
 mod committee_report;
 mod congress;
+mod report_id;
 mod report_number;
 mod report_type;
 mod text;
 
+pub mod models;
+
 pub use self::committee_report::{
     CommitteeReport, CommitteeReportBuilder, CommitteeReportBuilderError,
 };
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
+pub use self::report_id::ReportId;
 pub use self::report_number::{ReportNumber, ReportNumberBuilder, ReportNumberBuilderError};
 pub use self::report_type::{ReportType, ReportTypeBuilder, ReportTypeBuilderError};
 pub use self::text::{Text, TextBuilder, TextBuilderError};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CommitteeReportType {
     Hrpt,
     Srpt,
@@ -24,7 +30,7 @@ pub enum CommitteeReportType {
 }
 
 impl CommitteeReportType {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             CommitteeReportType::Hrpt => "hrpt",
             CommitteeReportType::Srpt => "srpt",
@@ -32,3 +38,14 @@ impl CommitteeReportType {
         }
     }
 }
+
+impl_api_enum!(
+    CommitteeReportType,
+    "committee report type",
+    [Hrpt, Srpt, Erpt],
+    json {
+        Hrpt => "HRPT",
+        Srpt => "SRPT",
+        Erpt => "ERPT",
+    }
+);
@@ -0,0 +1,162 @@
+//! Typed response models for the Committee Report endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::CommitteeChamber,
+    committee_report::{CommitteeReportType, ReportId},
+    common::{AssociatedBill, Pagination, ResourceCount, TextFormat},
+    de,
+};
+
+/// A treaty associated with a committee report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AssociatedTreaty {
+    pub congress: u16,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub part: Option<String>,
+    pub url: Option<String>,
+}
+
+/// A committee report, as returned by both the list and detail endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeReport {
+    pub citation: String,
+    pub congress: u16,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub part: Option<u32>,
+    #[serde(rename = "type")]
+    pub report_type: Option<CommitteeReportType>,
+    pub chamber: Option<CommitteeChamber>,
+    pub title: Option<String>,
+    pub is_conference_report: Option<bool>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub issue_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session_number: Option<u32>,
+    #[serde(default, alias = "associatedBill")]
+    pub associated_bills: Vec<AssociatedBill>,
+    #[serde(default)]
+    pub associated_treaties: Vec<AssociatedTreaty>,
+    pub text: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl CommitteeReport {
+    /// The identifier of the report, used to query its other endpoints.
+    pub fn id(&self) -> Option<ReportId> {
+        match self.report_type {
+            Some(report_type) => Some(ReportId::new(self.congress, report_type, self.number)),
+            None => self.citation.parse().ok(),
+        }
+    }
+}
+
+/// The response of the /committee-report, /committee-report/:congress and
+/// /committee-report/:congress/:reportType endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeReportsResponse {
+    #[serde(default)]
+    pub reports: Vec<CommitteeReport>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /committee-report/:congress/:reportType/:reportNumber
+/// endpoint.
+///
+/// A report split into several parts returns one entry per part.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeReportResponse {
+    #[serde(default)]
+    pub committee_reports: Vec<CommitteeReport>,
+}
+
+/// The response of the
+/// /committee-report/:congress/:reportType/:reportNumber/text endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommitteeReportTextResponse {
+    #[serde(default)]
+    pub text: Vec<TextFormat>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::{BillType, TextFormatKind};
+
+    use super::*;
+
+    #[test]
+    fn committee_report_detail() {
+        let json = r#"{
+            "committeeReports": [
+                {
+                    "associatedBill": [{"congress": 116, "number": "6395", "type": "HR", "url": "https://api.congress.gov/v3/bill/116/hr/6395?format=json"}],
+                    "chamber": "House",
+                    "citation": "H. Rept. 116-617",
+                    "congress": 116,
+                    "isConferenceReport": true,
+                    "issueDate": "2020-12-03T05:00:00Z",
+                    "number": 617,
+                    "part": 1,
+                    "reportType": "H.Rept.",
+                    "sessionNumber": 2,
+                    "text": {"count": 2, "url": "https://api.congress.gov/v3/committee-report/116/hrpt/617/text?format=json"},
+                    "title": "WILLIAM M. (MAC) THORNBERRY NATIONAL DEFENSE AUTHORIZATION ACT FOR FISCAL YEAR 2021",
+                    "type": "HRPT",
+                    "updateDate": "2022-09-29 03:27:29+00:00"
+                }
+            ],
+            "request": {"congress": "116", "contentType": "application/json", "format": "json", "reportNumber": "617", "reportType": "hrpt"}
+        }"#;
+
+        let res: CommitteeReportResponse = serde_json::from_str(json).unwrap();
+        let report = &res.committee_reports[0];
+
+        assert_eq!(report.report_type, Some(CommitteeReportType::Hrpt));
+        assert_eq!(report.is_conference_report, Some(true));
+        assert_eq!(report.issue_date, NaiveDate::from_ymd_opt(2020, 12, 3));
+        assert_eq!(report.associated_bills[0].bill_type, BillType::Hr);
+        assert_eq!(report.associated_bills[0].number, 6395);
+        assert_eq!(report.text.as_ref().unwrap().count, 2);
+        assert_eq!(
+            report.id(),
+            Some(ReportId::new(116, CommitteeReportType::Hrpt, 617))
+        );
+    }
+
+    #[test]
+    fn committee_report_text() {
+        let json = r#"{
+            "pagination": {"count": 2},
+            "text": [
+                {"isErrata": "N", "type": "Formatted Text", "url": "https://www.congress.gov/116/crpt/hrpt617/generated/CRPT-116hrpt617.htm"},
+                {"isErrata": "N", "type": "PDF", "url": "https://www.congress.gov/116/crpt/hrpt617/CRPT-116hrpt617.pdf"}
+            ]
+        }"#;
+
+        let res: CommitteeReportTextResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.text[0].kind, TextFormatKind::FormattedText);
+        assert_eq!(res.text[1].kind, TextFormatKind::Pdf);
+        assert_eq!(res.text[1].is_errata, Some(false));
+    }
+}
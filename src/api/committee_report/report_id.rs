@@ -0,0 +1,243 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::api::{
+    calendar::Congress,
+    citation::{self, Citation, ParseCitationError},
+    committee_report::{CommitteeReportType, ReportNumberBuilder, TextBuilder},
+};
+
+/// Identifies a committee report by its type, congress and number.
+///
+/// A `ReportId` can be parsed from a citation such as "H. Rept. 117-1",
+/// "S. Rept. 116-25" or "Ex. Rept. 117-3", and is displayed the same way.
+///
+/// ```rust
+/// use congressdotgov_rs::api::committee_report::{CommitteeReportType, ReportId};
+///
+/// let id: ReportId = "S. Rept. 116-25".parse().unwrap();
+/// assert_eq!(id, ReportId::new(116, CommitteeReportType::Srpt, 25));
+/// assert_eq!(id.to_string(), "S. Rept. 116-25");
+///
+/// let found: Vec<_> = ReportId::find_all(
+///     "Reported by the Committee on Rules. H. Rept. 117-1.",
+/// )
+/// .collect();
+/// assert_eq!(found, [ReportId::new(117, CommitteeReportType::Hrpt, 1)]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ReportId {
+    pub congress: u16,
+    pub report_type: CommitteeReportType,
+    pub number: u32,
+}
+
+impl ReportId {
+    pub fn new(congress: u16, report_type: CommitteeReportType, number: u32) -> Self {
+        Self {
+            congress,
+            report_type,
+            number,
+        }
+    }
+
+    /// Finds every committee report cited in a piece of text, such as the
+    /// text of a bill action. Citations naming a congress outside the
+    /// [calendar](crate::api::calendar) are skipped.
+    pub fn find_all(text: &str) -> impl Iterator<Item = ReportId> + '_ {
+        text.match_indices("Rept.").filter_map(move |(at, _)| {
+            let before = text[..at].trim_end();
+            let start = ["Ex.", "H.", "S."]
+                .iter()
+                .find(|prefix| before.ends_with(*prefix))
+                .map(|prefix| before.len() - prefix.len())?;
+            let boundary = text[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|c| !c.is_alphanumeric() && c != '.');
+            if !boundary {
+                return None;
+            }
+
+            let after = &text[at + "Rept.".len()..];
+            let digits = after.trim_start();
+            let len = digits
+                .find(|c: char| !c.is_ascii_digit() && c != '-')
+                .unwrap_or(digits.len());
+            let end = text.len() - digits.len() + len;
+
+            text[start..end].trim_end_matches('-').parse().ok()
+        })
+    }
+}
+
+fn report_type(prefix: &str) -> Option<CommitteeReportType> {
+    match prefix {
+        "hrept" | "hrpt" | "hrep" => Some(CommitteeReportType::Hrpt),
+        "srept" | "srpt" | "srep" => Some(CommitteeReportType::Srpt),
+        "exrept" | "exrpt" | "execrept" | "erept" | "erpt" => Some(CommitteeReportType::Erpt),
+        _ => None,
+    }
+}
+
+impl FromStr for ReportId {
+    type Err = ParseCitationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const KIND: &str = "committee report";
+
+        let invalid = || ParseCitationError::unrecognized(KIND, s);
+        let citation = Citation::split(s).ok_or_else(invalid)?;
+        let report_type = report_type(&citation.prefix).ok_or_else(invalid)?;
+
+        let (congress, number) = match citation.numbers() {
+            (congress, Some(number)) => (congress, number),
+            (_, None) => return Err(invalid()),
+        };
+        let congress = citation::congress(congress).ok_or_else(invalid)?;
+        let number = citation::number(number).ok_or_else(invalid)?;
+        let congress = Congress::new(congress)
+            .map(u16::from)
+            .map_err(|_| ParseCitationError::invalid_congress(KIND, s, congress))?;
+
+        Ok(Self::new(congress, report_type, number))
+    }
+}
+
+impl fmt::Display for ReportId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abbreviation = match self.report_type {
+            CommitteeReportType::Hrpt => "H. Rept.",
+            CommitteeReportType::Srpt => "S. Rept.",
+            CommitteeReportType::Erpt => "Ex. Rept.",
+        };
+        write!(f, "{} {}-{}", abbreviation, self.congress, self.number)
+    }
+}
+
+impl From<ReportId> for ReportNumberBuilder {
+    fn from(id: ReportId) -> Self {
+        let mut builder = Self::default();
+        builder
+            .congress(id.congress)
+            .report_type(id.report_type)
+            .report_number(id.number);
+        builder
+    }
+}
+
+impl From<ReportId> for TextBuilder {
+    fn from(id: ReportId) -> Self {
+        let mut builder = Self::default();
+        builder
+            .congress(id.congress)
+            .report_type(id.report_type)
+            .report_number(id.number);
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{committee_report::ReportNumber, endpoint::Endpoint};
+
+    use super::*;
+
+    #[test]
+    fn parses_citations() {
+        for (citation, id) in [
+            (
+                "H. Rept. 117-1",
+                ReportId::new(117, CommitteeReportType::Hrpt, 1),
+            ),
+            (
+                "S. Rept. 116-25",
+                ReportId::new(116, CommitteeReportType::Srpt, 25),
+            ),
+            (
+                "Ex. Rept. 117-3",
+                ReportId::new(117, CommitteeReportType::Erpt, 3),
+            ),
+            (
+                "H.Rept.116-617",
+                ReportId::new(116, CommitteeReportType::Hrpt, 617),
+            ),
+            (
+                "srpt 116-25",
+                ReportId::new(116, CommitteeReportType::Srpt, 25),
+            ),
+        ] {
+            assert_eq!(citation.parse::<ReportId>(), Ok(id), "{citation}");
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for id in [
+            ReportId::new(117, CommitteeReportType::Hrpt, 1),
+            ReportId::new(116, CommitteeReportType::Srpt, 25),
+            ReportId::new(117, CommitteeReportType::Erpt, 3),
+        ] {
+            assert_eq!(id.to_string().parse::<ReportId>(), Ok(id));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_citations() {
+        for citation in [
+            "",
+            "H. Rept.",
+            "H. Rept. 117",
+            "H. Doc. 117-1",
+            "S. Rept. 116-x",
+        ] {
+            assert!(citation.parse::<ReportId>().is_err(), "{citation}");
+        }
+
+        assert!(matches!(
+            "H. Rept. 300-1".parse::<ReportId>(),
+            Err(ParseCitationError::InvalidCongress { congress: 300, .. })
+        ));
+    }
+
+    #[test]
+    fn finds_citations_in_text() {
+        let text = "Reported (Amended) by the Committee on Energy and Commerce. \
+                    H. Rept. 116-617, Part I. Supplemental report filed, \
+                    H. Rept. 116-617, Part II; see also S. Rept. 116-25 and Ex. Rept. 117-3.";
+
+        let found: Vec<_> = ReportId::find_all(text).collect();
+        assert_eq!(
+            found,
+            [
+                ReportId::new(116, CommitteeReportType::Hrpt, 617),
+                ReportId::new(116, CommitteeReportType::Hrpt, 617),
+                ReportId::new(116, CommitteeReportType::Srpt, 25),
+                ReportId::new(117, CommitteeReportType::Erpt, 3),
+            ]
+        );
+
+        assert_eq!(
+            ReportId::find_all("Rept. 117-1, U.S. Rept. 2-3 and H. Rept.").count(),
+            0
+        );
+        assert_eq!(
+            ReportId::find_all("H. Rept. 300-1 and S. Rept. 0-2").count(),
+            0
+        );
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let id: ReportId = "H. Rept. 117-1".parse().unwrap();
+
+        assert_eq!(
+            ReportNumber::from(id).endpoint(),
+            "committee-report/117/hrpt/1"
+        );
+        assert_eq!(
+            TextBuilder::from(id).build().unwrap().endpoint(),
+            "committee-report/117/hrpt/1/text"
+        );
+    }
+}
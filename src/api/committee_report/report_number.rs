@@ -4,7 +4,7 @@ use std::borrow::Cow;
 
 use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
 
-use super::CommitteeReportType;
+use super::{CommitteeReportType, ReportId};
 
 /// Represents the /committee-report/:congress/:reportType/:reportNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -26,6 +26,17 @@ impl ReportNumber {
     }
 }
 
+impl From<ReportId> for ReportNumber {
+    fn from(id: ReportId) -> Self {
+        Self {
+            congress: id.congress,
+            report_type: id.report_type,
+            report_number: id.number,
+            format: Format::default(),
+        }
+    }
+}
+
 impl Endpoint for ReportNumber {
     fn method(&self) -> Method {
         Method::GET
@@ -1,12 +1,181 @@
 //! API types common to many endpoints.
 
 use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::NaiveDate;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::api::de;
+
+/// Error returned when parsing an unrecognized variant of an [`ApiEnum`].
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("unrecognized {kind}: {input:?}")]
+pub struct ParseEnumError {
+    pub kind: &'static str,
+    pub input: String,
+}
+
+/// Error returned when parsing a malformed identifier, such as a
+/// [`Bioguide`](crate::api::member::Bioguide).
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("invalid {kind}: {input:?}")]
+pub struct ParseIdError {
+    pub kind: &'static str,
+    pub input: String,
+}
+
+/// An enum of the fixed values the API takes in paths and parameters, such
+/// as [`BillType`].
+///
+/// Every `ApiEnum` is displayed as the value the API takes in paths, e.g.
+/// "hr", and serialized as the value the API uses for it in JSON, e.g. "HR".
+/// It is parsed and deserialized from either of those or from the other
+/// labels the API uses for it in responses, ignoring case.
+///
+/// ```rust
+/// use congressdotgov_rs::api::common::{ApiEnum, BillType};
+///
+/// assert_eq!("HR".parse(), Ok(BillType::Hr));
+/// assert_eq!(BillType::Hjres.to_string(), "hjres");
+/// assert_eq!(BillType::Hjres.json_label(), "HJRES");
+/// assert_eq!(BillType::all().len(), 8);
+/// ```
+pub trait ApiEnum:
+    Copy + Eq + FromStr<Err = ParseEnumError> + fmt::Display + Serialize + DeserializeOwned + 'static
+{
+    /// What the enum describes, used in error messages, e.g. "bill type".
+    const KIND: &'static str;
+
+    /// Every variant, in declaration order.
+    fn all() -> &'static [Self];
+
+    /// The value the API takes for the variant.
+    fn as_str(self) -> &'static str;
+
+    /// The value the API uses for the variant in JSON, which is what it is
+    /// serialized as. Defaults to [`as_str`](ApiEnum::as_str).
+    fn json_label(self) -> &'static str {
+        self.as_str()
+    }
+
+    /// Other labels the API uses for the variant in responses.
+    fn labels(self) -> &'static [&'static str] {
+        &[]
+    }
+
+    /// Whether `s` names the variant, ignoring case.
+    fn matches(self, s: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(s)
+            || self.json_label().eq_ignore_ascii_case(s)
+            || self
+                .labels()
+                .iter()
+                .any(|label| label.eq_ignore_ascii_case(s))
+    }
+
+    /// Parses a variant from its value or one of its labels.
+    fn parse(s: &str) -> Result<Self, ParseEnumError> {
+        let s = s.trim();
+        Self::all()
+            .iter()
+            .copied()
+            .find(|variant| variant.matches(s))
+            .ok_or_else(|| ParseEnumError {
+                kind: Self::KIND,
+                input: s.into(),
+            })
+    }
+}
+
+/// Implements [`ApiEnum`] for an enum with an inherent `as_str`, along with
+/// `FromStr`, `Display` and serde in terms of it. The optional `json` block
+/// gives the value serialized for each variant, and `labels` the other
+/// labels it is parsed from.
+macro_rules! impl_api_enum {
+    (
+        $enum:ident, $kind:literal, [$($variant:ident),+ $(,)?]
+        $(, json { $($json_variant:ident => $json:literal),+ $(,)? })?
+        $(, labels { $($labeled:ident => [$($label:literal),+ $(,)?]),* $(,)? })?
+    ) => {
+        impl $crate::api::common::ApiEnum for $enum {
+            const KIND: &'static str = $kind;
+
+            fn all() -> &'static [Self] {
+                &[$($enum::$variant),+]
+            }
+
+            fn as_str(self) -> &'static str {
+                $enum::as_str(self)
+            }
+
+            $(
+                fn json_label(self) -> &'static str {
+                    match self {
+                        $($enum::$json_variant => $json,)+
+                    }
+                }
+            )?
+
+            $(
+                fn labels(self) -> &'static [&'static str] {
+                    #[allow(unreachable_patterns)]
+                    match self {
+                        $($enum::$labeled => &[$($label),+],)*
+                        _ => &[],
+                    }
+                }
+            )?
+        }
+
+        $crate::api::common::impl_api_enum!(@traits $enum);
+    };
+    (@traits $enum:ident) => {
+        impl ::std::str::FromStr for $enum {
+            type Err = $crate::api::common::ParseEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                <Self as $crate::api::common::ApiEnum>::parse(s)
+            }
+        }
+
+        impl ::std::fmt::Display for $enum {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                f.write_str($enum::as_str(*self))
+            }
+        }
+
+        impl ::serde::Serialize for $enum {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                serializer.serialize_str(<Self as $crate::api::common::ApiEnum>::json_label(*self))
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $enum {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                let s = <String as ::serde::Deserialize>::deserialize(deserializer)?;
+                <Self as $crate::api::common::ApiEnum>::parse(&s)
+                    .map_err(::serde::de::Error::custom)
+            }
+        }
+    };
+}
+
+pub(crate) use impl_api_enum;
 
 /// Chamber options for Committee endpoints.
 ///
 /// This differs from the Chamber enum for the Committee
 /// resource as this has the NoChamber variant.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CommitteeChamber {
     House,
     Senate,
@@ -23,9 +192,24 @@ impl CommitteeChamber {
     }
 }
 
+impl_api_enum!(
+    CommitteeChamber,
+    "chamber",
+    [House, Senate, NoChamber],
+    json {
+        House => "House",
+        Senate => "Senate",
+        NoChamber => "NoChamber",
+    },
+    labels {
+        House => ["House of Representatives"],
+        NoChamber => ["No Chamber"],
+    }
+);
+
 /// The possible Congressional bill types for both
 /// the House of Representatives and Senate.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum BillType {
     /// H.R. - House Bill
     Hr,
@@ -58,8 +242,38 @@ impl BillType {
             BillType::Sres => "sres",
         }
     }
+
+    /// The abbreviation used when citing a bill of this type, e.g. "H.R.".
+    pub fn citation(self) -> &'static str {
+        match self {
+            BillType::Hr => "H.R.",
+            BillType::S => "S.",
+            BillType::Hjres => "H.J.Res.",
+            BillType::Sjres => "S.J.Res.",
+            BillType::Hconres => "H.Con.Res.",
+            BillType::Sconres => "S.Con.Res.",
+            BillType::Hres => "H.Res.",
+            BillType::Sres => "S.Res.",
+        }
+    }
 }
 
+impl_api_enum!(
+    BillType,
+    "bill type",
+    [Hr, S, Hjres, Sjres, Hconres, Sconres, Hres, Sres],
+    json {
+        Hr => "HR",
+        S => "S",
+        Hjres => "HJRES",
+        Sjres => "SJRES",
+        Hconres => "HCONRES",
+        Sconres => "SCONRES",
+        Hres => "HRES",
+        Sres => "SRES",
+    }
+);
+
 /// The congress.gov API can return data in either Json or XML
 /// format. The default for this crate is Json.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +303,8 @@ impl Format {
     }
 }
 
+impl_api_enum!(Format, "format", [Json, Xml]);
+
 /// Certain endpoints allow the response to be sorted
 /// in either Ascending or Descending order.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,10 +322,400 @@ impl Default for Sort {
 }
 
 impl Sort {
+    /// The value the API takes, sorting by update date. Its space is sent
+    /// as `+` in the query string, e.g. `sort=updateDate+asc`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Sort::Asc => "updateDate asc",
+            Sort::Desc => "updateDate desc",
+        }
+    }
+}
+
+impl_api_enum!(
+    Sort,
+    "sort order",
+    [Asc, Desc],
+    labels {
+        Asc => ["asc"],
+        Desc => ["desc"],
+    }
+);
+
+/// Pagination information returned with list responses.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Pagination {
+    /// The total number of items across all pages.
+    #[serde(default)]
+    pub count: u32,
+    /// The URL of the next page, if any.
+    pub next: Option<String>,
+    /// The URL of the previous page, if any.
+    pub prev: Option<String>,
+}
+
+/// A reference to a related, paginated resource along with the number of
+/// items it contains.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ResourceCount {
+    #[serde(default)]
+    pub count: u32,
+    pub url: Option<String>,
+}
+
+/// The most recent action taken on an item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct LatestAction {
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub action_date: Option<NaiveDate>,
+    pub action_time: Option<String>,
+    pub text: Option<String>,
+}
+
+/// The code and name of a House or Senate communication type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CommunicationTypeCode {
+    pub code: String,
+    pub name: String,
+}
+
+/// The format of a Congressional Record document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RecordLinkKind {
+    /// A PDF document.
+    Pdf,
+    /// An HTML document, labeled "Formatted Text" by the API.
+    Html,
+    /// A plain text document.
+    Text,
+    /// Any other format.
+    Other,
+}
+
+impl RecordLinkKind {
+    fn from_label(label: &str) -> Self {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "pdf" => RecordLinkKind::Pdf,
+            "formatted text" | "html" | "htm" => RecordLinkKind::Html,
+            "txt" | "text" => RecordLinkKind::Text,
+            _ => RecordLinkKind::Other,
+        }
+    }
+
     pub fn as_str(self) -> &'static str {
         match self {
-            Sort::Asc => "asc",
-            Sort::Desc => "desc",
+            RecordLinkKind::Pdf => "PDF",
+            RecordLinkKind::Html => "Formatted Text",
+            RecordLinkKind::Text => "TXT",
+            RecordLinkKind::Other => "Other",
         }
     }
 }
+
+impl Serialize for RecordLinkKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordLinkKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|label| Self::from_label(&label))
+    }
+}
+
+/// A link to a Congressional Record document in a specific format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RecordLink {
+    #[serde(rename = "type")]
+    pub kind: RecordLinkKind,
+    pub url: String,
+    /// The part number of documents split into several files.
+    #[serde(
+        default,
+        deserialize_with = "de::opt_number",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub part: Option<u32>,
+}
+
+impl RecordLink {
+    pub(crate) fn new(kind: RecordLinkKind, url: String, part: Option<u32>) -> Self {
+        RecordLink { kind, url, part }
+    }
+}
+
+/// A page of the Congressional Record.
+///
+/// Daily edition pages are prefixed by the section they appear in: `S` for
+/// the Senate, `H` for the House, `E` for Extensions of Remarks and `D` for
+/// the Daily Digest. Bound edition pages are plain numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RecordPage {
+    pub prefix: Option<char>,
+    pub number: u32,
+}
+
+impl std::str::FromStr for RecordPage {
+    type Err = std::num::ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.chars().next() {
+            Some(c) if c.is_ascii_alphabetic() => Ok(RecordPage {
+                prefix: Some(c.to_ascii_uppercase()),
+                number: s[1..].parse()?,
+            }),
+            _ => Ok(RecordPage {
+                prefix: None,
+                number: s.parse()?,
+            }),
+        }
+    }
+}
+
+impl std::fmt::Display for RecordPage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(prefix) = self.prefix {
+            write!(f, "{prefix}")?;
+        }
+        write!(f, "{}", self.number)
+    }
+}
+
+impl Serialize for RecordPage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match self.prefix {
+            Some(_) => serializer.collect_str(self),
+            None => serializer.serialize_u32(self.number),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RecordPage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Number(u32),
+            String(String),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Number(number) => Ok(RecordPage {
+                prefix: None,
+                number,
+            }),
+            Raw::String(s) => s
+                .parse()
+                .map_err(|_| serde::de::Error::custom(format!("invalid page: {s}"))),
+        }
+    }
+}
+
+/// A section of a Congressional Record issue, such as the Senate or House
+/// section, along with its page range and documents.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RecordSection {
+    pub name: Option<String>,
+    pub start_page: Option<RecordPage>,
+    pub end_page: Option<RecordPage>,
+    #[serde(default)]
+    pub text: Vec<RecordLink>,
+}
+
+impl RecordSection {
+    /// The documents of the section in the given format.
+    pub fn links(&self, kind: RecordLinkKind) -> impl Iterator<Item = &RecordLink> + '_ {
+        self.text.iter().filter(move |link| link.kind == kind)
+    }
+}
+
+/// The format of a published text, such as a report, print or bill text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TextFormatKind {
+    /// HTML, labeled "Formatted Text" by the API.
+    FormattedText,
+    Pdf,
+    FormattedXml,
+    GeneratedHtml,
+    /// Any other format.
+    Other,
+}
+
+impl TextFormatKind {
+    fn from_label(label: &str) -> Self {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "formatted text" => TextFormatKind::FormattedText,
+            "pdf" => TextFormatKind::Pdf,
+            "formatted xml" => TextFormatKind::FormattedXml,
+            "generated html" => TextFormatKind::GeneratedHtml,
+            _ => TextFormatKind::Other,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TextFormatKind::FormattedText => "Formatted Text",
+            TextFormatKind::Pdf => "PDF",
+            TextFormatKind::FormattedXml => "Formatted XML",
+            TextFormatKind::GeneratedHtml => "Generated HTML",
+            TextFormatKind::Other => "Other",
+        }
+    }
+}
+
+impl Serialize for TextFormatKind {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for TextFormatKind {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(|label| Self::from_label(&label))
+    }
+}
+
+/// A link to a published text in a specific format.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TextFormat {
+    #[serde(rename = "type")]
+    pub kind: TextFormatKind,
+    pub url: String,
+    /// Whether the text is an errata correcting an earlier text.
+    #[serde(default, deserialize_with = "de::opt_flag")]
+    pub is_errata: Option<bool>,
+}
+
+/// A bill associated with another item, such as a report or meeting.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AssociatedBill {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub bill_type: BillType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub url: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use crate::api::{
+        amendments::CongressionalAmendmentType, committee, committee_report::CommitteeReportType,
+        house_communication::HouseCommunicationType, law::CongressionalLawType,
+        member::CongressionalStateCode, senate_communication::SenateCommunicationType,
+    };
+
+    use super::*;
+
+    fn round_trips<T: ApiEnum + Debug>() {
+        for variant in T::all() {
+            let s = variant.to_string();
+            assert_eq!(s, variant.as_str());
+            assert_eq!(s.parse::<T>(), Ok(*variant));
+            assert_eq!(s.to_uppercase().parse::<T>(), Ok(*variant));
+
+            let json = serde_json::to_string(variant).unwrap();
+            assert_eq!(json, format!("{:?}", variant.json_label()));
+            assert_eq!(serde_json::from_str::<T>(&json).unwrap(), *variant);
+        }
+        assert!(matches!(
+            "not a variant".parse::<T>(),
+            Err(ParseEnumError { kind, .. }) if kind == T::KIND
+        ));
+    }
+
+    #[test]
+    fn api_enums_round_trip() {
+        round_trips::<BillType>();
+        round_trips::<CommitteeChamber>();
+        round_trips::<Format>();
+        round_trips::<Sort>();
+        round_trips::<committee::CommitteeChamber>();
+        round_trips::<CongressionalAmendmentType>();
+        round_trips::<CongressionalLawType>();
+        round_trips::<CommitteeReportType>();
+        round_trips::<HouseCommunicationType>();
+        round_trips::<SenateCommunicationType>();
+        round_trips::<CongressionalStateCode>();
+    }
+
+    #[test]
+    fn api_enums_serialize_json_labels() {
+        assert_eq!(serde_json::to_string(&BillType::Hr).unwrap(), r#""HR""#);
+        assert_eq!(
+            serde_json::to_string(&CommitteeReportType::Hrpt).unwrap(),
+            r#""HRPT""#
+        );
+        assert_eq!(
+            serde_json::to_string(&CommitteeChamber::House).unwrap(),
+            r#""House""#
+        );
+        assert_eq!(
+            serde_json::to_string(&CongressionalLawType::Public).unwrap(),
+            r#""Public Law""#
+        );
+        assert_eq!(BillType::Hr.to_string(), "hr");
+        assert_eq!(CommitteeReportType::Hrpt.to_string(), "hrpt");
+    }
+
+    #[test]
+    fn api_enums_parse_response_labels() {
+        assert_eq!(
+            serde_json::from_str::<CommitteeChamber>(r#""House of Representatives""#).unwrap(),
+            CommitteeChamber::House
+        );
+        assert_eq!("No Chamber".parse(), Ok(CommitteeChamber::NoChamber));
+        assert_eq!("Public Law".parse(), Ok(CongressionalLawType::Public));
+        assert_eq!("asc".parse(), Ok(Sort::Asc));
+        assert_eq!(Sort::Desc.to_string(), "updateDate desc");
+        assert_eq!(
+            "Petition or Memorial".parse(),
+            Ok(SenateCommunicationType::Pom)
+        );
+        assert_eq!(
+            serde_json::from_str::<BillType>(r#""HJRES""#).unwrap(),
+            BillType::Hjres
+        );
+        assert_eq!(
+            "bill type".parse::<BillType>().unwrap_err().to_string(),
+            r#"unrecognized bill type: "bill type""#
+        );
+    }
+}
@@ -6,6 +6,8 @@ mod congress;
 mod current_congress;
 mod specific_congress;
 
+pub mod models;
+
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
 pub use self::current_congress::{
     CurrentCongress, CurrentCongressBuilder, CurrentCongressBuilderError,
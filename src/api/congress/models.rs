@@ -0,0 +1,142 @@
+//! Typed response models for the Congress endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    citation,
+    common::{CommitteeChamber, Pagination},
+    de,
+};
+
+/// Whether a session of Congress is a regular or a special session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SessionType {
+    #[serde(rename = "R")]
+    Regular,
+    #[serde(rename = "S")]
+    Special,
+}
+
+/// A session of one chamber of a congress.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CongressSession {
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub number: Option<u32>,
+    #[serde(rename = "type")]
+    pub session_type: Option<SessionType>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub start_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub end_date: Option<NaiveDate>,
+}
+
+/// A congress, as returned by the /congress, /congress/:congress and
+/// /congress/current endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CongressInfo {
+    pub name: String,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub start_year: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub end_year: Option<u32>,
+    #[serde(default)]
+    pub sessions: Vec<CongressSession>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl CongressInfo {
+    /// The number of the congress. The list endpoint omits it, in which case
+    /// it is taken from the name, e.g. "117th Congress".
+    pub fn congress_number(&self) -> Option<u16> {
+        match self.number {
+            Some(number) => u16::try_from(number).ok(),
+            None => citation::congress(&self.name),
+        }
+    }
+}
+
+/// The response of the /congress endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CongressesResponse {
+    #[serde(default)]
+    pub congresses: Vec<CongressInfo>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /congress/:congress and /congress/current endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CongressResponse {
+    pub congress: CongressInfo,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specific_congress() {
+        let json = r#"{
+            "congress": {
+                "endYear": "2022",
+                "name": "117th Congress",
+                "number": 117,
+                "sessions": [
+                    {"chamber": "House of Representatives", "endDate": "2022-01-03", "number": 1, "startDate": "2021-01-03", "type": "R"},
+                    {"chamber": "Senate", "endDate": "2022-01-03", "number": 1, "startDate": "2021-01-03", "type": "R"},
+                    {"chamber": "House of Representatives", "endDate": "2023-01-03", "number": 2, "startDate": "2022-01-03", "type": "R"},
+                    {"chamber": "Senate", "endDate": "2023-01-03", "number": 2, "startDate": "2022-01-03", "type": "R"}
+                ],
+                "startYear": "2021",
+                "updateDate": "2023-01-03T17:43:32Z",
+                "url": "https://api.congress.gov/v3/congress/117?format=json"
+            },
+            "request": {"contentType": "application/json", "format": "json"}
+        }"#;
+
+        let congress = serde_json::from_str::<CongressResponse>(json)
+            .unwrap()
+            .congress;
+        assert_eq!(congress.congress_number(), Some(117));
+        assert_eq!(congress.start_year, Some(2021));
+        assert_eq!(congress.sessions.len(), 4);
+        assert_eq!(congress.sessions[0].chamber, Some(CommitteeChamber::House));
+        assert_eq!(
+            congress.sessions[0].session_type,
+            Some(SessionType::Regular)
+        );
+        assert_eq!(
+            congress.sessions[3].end_date,
+            NaiveDate::from_ymd_opt(2023, 1, 3)
+        );
+    }
+
+    #[test]
+    fn congress_list() {
+        let json = r#"{
+            "congresses": [
+                {"endYear": "2024", "name": "118th Congress", "sessions": [{"chamber": "Senate", "number": 1, "startDate": "2023-01-03", "type": "R"}], "startYear": "2023"}
+            ],
+            "pagination": {"count": 118, "next": "https://api.congress.gov/v3/congress?offset=1&limit=1&format=json"}
+        }"#;
+
+        let res: CongressesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.congresses[0].congress_number(), Some(118));
+        assert_eq!(res.congresses[0].sessions[0].end_date, None);
+        assert_eq!(res.pagination.count, 118);
+    }
+}
@@ -9,7 +9,7 @@ use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryPara
 #[builder(setter(strip_option))]
 pub struct SpecificCongress {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(default)]
     format: Format,
 }
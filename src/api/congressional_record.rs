@@ -4,6 +4,8 @@
 
 mod congressional_record;
 
+pub mod models;
+
 pub use self::congressional_record::{
     CongressionalRecord, CongressionalRecordBuilder, CongressionalRecordBuilderError,
 };
@@ -0,0 +1,204 @@
+//! Typed response models for the Congressional Record endpoint.
+//!
+//! Unlike the other endpoints, /congressional-record uses PascalCase keys and
+//! groups each issue's documents by section and format.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    common::{RecordLink, RecordLinkKind},
+    de,
+};
+
+/// The documents of one section of an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "RawSectionLinks")]
+#[non_exhaustive]
+pub struct SectionLinks {
+    pub label: Option<String>,
+    /// The position of the section within the issue.
+    pub ordinal: Option<u32>,
+    pub links: Vec<RecordLink>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawSectionLinks {
+    label: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    ordinal: Option<u32>,
+    #[serde(default, rename = "PDF")]
+    pdf: Vec<RawLink>,
+    #[serde(default, rename = "HTML", alias = "Formatted Text")]
+    html: Vec<RawLink>,
+    #[serde(default, rename = "TXT")]
+    txt: Vec<RawLink>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RawLink {
+    url: String,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    part: Option<u32>,
+}
+
+impl From<RawSectionLinks> for SectionLinks {
+    fn from(raw: RawSectionLinks) -> Self {
+        let links = [
+            (RecordLinkKind::Pdf, raw.pdf),
+            (RecordLinkKind::Html, raw.html),
+            (RecordLinkKind::Text, raw.txt),
+        ]
+        .into_iter()
+        .flat_map(|(kind, links)| {
+            links
+                .into_iter()
+                .map(move |link| RecordLink::new(kind, link.url, link.part))
+        })
+        .collect();
+
+        SectionLinks {
+            label: raw.label,
+            ordinal: raw.ordinal,
+            links,
+        }
+    }
+}
+
+/// The documents of an issue, grouped by section.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct IssueLinks {
+    pub digest: Option<SectionLinks>,
+    pub senate: Option<SectionLinks>,
+    pub house: Option<SectionLinks>,
+    pub remarks: Option<SectionLinks>,
+    pub full_record: Option<SectionLinks>,
+}
+
+impl IssueLinks {
+    /// All sections present for the issue, in the order they appear.
+    pub fn sections(&self) -> Vec<&SectionLinks> {
+        let mut sections: Vec<_> = [
+            &self.digest,
+            &self.senate,
+            &self.house,
+            &self.remarks,
+            &self.full_record,
+        ]
+        .into_iter()
+        .flatten()
+        .collect();
+        sections.sort_by_key(|s| s.ordinal.unwrap_or(u32::MAX));
+        sections
+    }
+}
+
+/// An issue of the daily Congressional Record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct Issue {
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub id: Option<u32>,
+    #[serde(deserialize_with = "de::number")]
+    pub congress: u32,
+    #[serde(deserialize_with = "de::number")]
+    pub volume: u32,
+    #[serde(deserialize_with = "de::number")]
+    pub issue: u32,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub publish_date: Option<NaiveDate>,
+    #[serde(default)]
+    pub links: IssueLinks,
+}
+
+/// The issues matching a query, along with the total count.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct Results {
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub index_start: Option<u32>,
+    #[serde(default)]
+    pub total_count: u32,
+    #[serde(default)]
+    pub issues: Vec<Issue>,
+}
+
+/// The response of the /congressional-record endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+#[non_exhaustive]
+pub struct CongressionalRecordResponse {
+    pub results: Results,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn congressional_record() {
+        let json = r#"{
+            "Results": {
+                "IndexStart": 1,
+                "Issues": [
+                    {
+                        "Congress": "117",
+                        "Id": 26958,
+                        "Issue": "109",
+                        "Links": {
+                            "Digest": {"Label": "Daily Digest", "Ordinal": 1, "PDF": [{"Part": "1", "Url": "https://www.congress.gov/117/crec/2022/06/24/168/109/CREC-2022-06-24-dailydigest.pdf"}]},
+                            "FullRecord": {"Label": "Entire Issue", "Ordinal": 5, "PDF": [{"Part": "1", "Url": "https://www.congress.gov/117/crec/2022/06/24/168/109/CREC-2022-06-24.pdf"}]},
+                            "House": {"Label": "House Section", "Ordinal": 3, "PDF": [{"Part": "1", "Url": "https://www.congress.gov/117/crec/2022/06/24/168/109/CREC-2022-06-24-house.pdf"}]},
+                            "Remarks": {"Label": "Extensions of Remarks Section", "Ordinal": 4, "PDF": [{"Part": "1", "Url": "https://www.congress.gov/117/crec/2022/06/24/168/109/CREC-2022-06-24-extensions.pdf"}]},
+                            "Senate": {"Label": "Senate Section", "Ordinal": 2, "PDF": [{"Part": "1", "Url": "https://www.congress.gov/117/crec/2022/06/24/168/109/CREC-2022-06-24-senate.pdf"}]}
+                        },
+                        "PublishDate": "2022-06-24",
+                        "Session": "2",
+                        "Volume": "168"
+                    }
+                ],
+                "TotalCount": 13763
+            }
+        }"#;
+
+        let res: CongressionalRecordResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.results.total_count, 13763);
+
+        let issue = &res.results.issues[0];
+        assert_eq!(issue.congress, 117);
+        assert_eq!(issue.volume, 168);
+        assert_eq!(issue.issue, 109);
+        assert_eq!(issue.session, Some(2));
+        assert_eq!(issue.publish_date, NaiveDate::from_ymd_opt(2022, 6, 24));
+
+        let labels: Vec<_> = issue
+            .links
+            .sections()
+            .into_iter()
+            .filter_map(|s| s.label.as_deref())
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "Daily Digest",
+                "Senate Section",
+                "House Section",
+                "Extensions of Remarks Section",
+                "Entire Issue"
+            ]
+        );
+
+        let senate = issue.links.senate.as_ref().unwrap();
+        assert_eq!(senate.links[0].kind, RecordLinkKind::Pdf);
+        assert_eq!(senate.links[0].part, Some(1));
+        assert!(senate.links[0].url.ends_with("senate.pdf"));
+    }
+}
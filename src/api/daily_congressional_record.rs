@@ -7,6 +7,8 @@ mod daily_congressional_record;
 mod issue_number;
 mod volume_number;
 
+pub mod models;
+
 pub use self::articles::{Articles, ArticlesBuilder, ArticlesBuilderError};
 pub use self::daily_congressional_record::{
     DailyCongressionalRecord, DailyCongressionalRecordBuilder, DailyCongressionalRecordBuilderError,
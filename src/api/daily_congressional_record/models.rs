@@ -0,0 +1,206 @@
+//! Typed response models for the Daily Congressional Record endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    common::{Pagination, RecordLink, RecordLinkKind, RecordPage, RecordSection, ResourceCount},
+    de,
+};
+
+/// The contents of a full issue of the daily Congressional Record.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct FullIssue {
+    pub articles: Option<ResourceCount>,
+    #[serde(default)]
+    pub entire_issue: Vec<RecordLink>,
+    #[serde(default)]
+    pub sections: Vec<RecordSection>,
+}
+
+impl FullIssue {
+    /// The documents of the entire issue in the given format.
+    pub fn entire_issue(&self, kind: RecordLinkKind) -> impl Iterator<Item = &RecordLink> + '_ {
+        self.entire_issue
+            .iter()
+            .filter(move |link| link.kind == kind)
+    }
+
+    /// The section with the given name, e.g. "Senate" or "Daily Digest".
+    pub fn section(&self, name: &str) -> Option<&RecordSection> {
+        self.sections.iter().find(|s| {
+            s.name
+                .as_deref()
+                .is_some_and(|n| n.eq_ignore_ascii_case(name))
+        })
+    }
+}
+
+/// An issue of the daily Congressional Record.
+///
+/// The list endpoints only return the identifying fields; the
+/// /daily-congressional-record/:volumeNumber/:issueNumber endpoint also
+/// returns the full issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DailyIssue {
+    #[serde(deserialize_with = "de::number")]
+    pub volume_number: u32,
+    #[serde(deserialize_with = "de::number")]
+    pub issue_number: u32,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub congress: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session_number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub issue_date: Option<NaiveDate>,
+    pub full_issue: Option<FullIssue>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// An article of the daily Congressional Record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Article {
+    pub title: String,
+    pub start_page: Option<RecordPage>,
+    pub end_page: Option<RecordPage>,
+    #[serde(default)]
+    pub text: Vec<RecordLink>,
+}
+
+/// The articles of one section of an issue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ArticleSection {
+    pub name: String,
+    #[serde(default)]
+    pub section_articles: Vec<Article>,
+}
+
+/// The response of the /daily-congressional-record and
+/// /daily-congressional-record/:volumeNumber endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct DailyCongressionalRecordResponse {
+    #[serde(default)]
+    pub daily_congressional_record: Vec<DailyIssue>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /daily-congressional-record/:volumeNumber/:issueNumber
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct IssueResponse {
+    pub issue: DailyIssue,
+}
+
+/// The response of the
+/// /daily-congressional-record/:volumeNumber/:issueNumber/articles endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct ArticlesResponse {
+    #[serde(default)]
+    pub articles: Vec<ArticleSection>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn daily_congressional_record_list() {
+        let json = r#"{
+            "dailyCongressionalRecord": [
+                {"congress": 118, "issueDate": "2023-07-11T04:00:00Z", "issueNumber": "118", "sessionNumber": 1, "updateDate": "2023-07-12T11:30:30Z", "url": "https://api.congress.gov/v3/daily-congressional-record/169/118?format=json", "volumeNumber": 169}
+            ],
+            "pagination": {"count": 13763, "next": "https://api.congress.gov/v3/daily-congressional-record?offset=1&limit=1&format=json"}
+        }"#;
+
+        let res: DailyCongressionalRecordResponse = serde_json::from_str(json).unwrap();
+        let issue = &res.daily_congressional_record[0];
+        assert_eq!(issue.volume_number, 169);
+        assert_eq!(issue.issue_number, 118);
+        assert_eq!(issue.issue_date, NaiveDate::from_ymd_opt(2023, 7, 11));
+        assert!(issue.full_issue.is_none());
+    }
+
+    #[test]
+    fn daily_congressional_record_issue() {
+        let json = r#"{
+            "issue": {
+                "congress": 118,
+                "fullIssue": {
+                    "articles": {"count": 139, "url": "https://api.congress.gov/v3/daily-congressional-record/169/16/articles?format=json"},
+                    "entireIssue": [
+                        {"part": "1", "type": "Formatted Text", "url": "https://www.congress.gov/118/crec/2023/01/26/169/16/CREC-2023-01-26.htm"},
+                        {"part": "1", "type": "PDF", "url": "https://www.congress.gov/118/crec/2023/01/26/169/16/CREC-2023-01-26.pdf"}
+                    ],
+                    "sections": [
+                        {"endPage": "D82", "name": "Daily Digest", "startPage": "D75", "text": [{"type": "PDF", "url": "https://www.congress.gov/118/crec/2023/01/26/169/16/CREC-2023-01-26-dailydigest.pdf"}, {"type": "Formatted Text", "url": "https://www.congress.gov/118/crec/2023/01/26/169/16/CREC-2023-01-26-dailydigest.htm"}]},
+                        {"endPage": "S192", "name": "Senate", "startPage": "S161", "text": [{"type": "PDF", "url": "https://www.congress.gov/118/crec/2023/01/26/169/16/CREC-2023-01-26-senate.pdf"}]}
+                    ]
+                },
+                "issueDate": "2023-01-26T05:00:00Z",
+                "issueNumber": "16",
+                "sessionNumber": 1,
+                "updateDate": "2023-01-27T12:30:24Z",
+                "url": "https://api.congress.gov/v3/daily-congressional-record/169/16?format=json",
+                "volumeNumber": 169
+            },
+            "request": {"contentType": "application/json", "format": "json", "issueNumber": "16", "volumeNumber": "169"}
+        }"#;
+
+        let res: IssueResponse = serde_json::from_str(json).unwrap();
+        let full = res.issue.full_issue.unwrap();
+        assert_eq!(full.articles.as_ref().unwrap().count, 139);
+        assert_eq!(full.entire_issue(RecordLinkKind::Pdf).count(), 1);
+        assert_eq!(full.entire_issue[0].kind, RecordLinkKind::Html);
+
+        let digest = full.section("daily digest").unwrap();
+        assert_eq!(
+            digest.start_page,
+            Some(RecordPage {
+                prefix: Some('D'),
+                number: 75
+            })
+        );
+        assert_eq!(digest.end_page.unwrap().to_string(), "D82");
+        assert_eq!(digest.links(RecordLinkKind::Html).count(), 1);
+    }
+
+    #[test]
+    fn daily_congressional_record_articles() {
+        let json = r#"{
+            "articles": [
+                {
+                    "name": "Senate",
+                    "sectionArticles": [
+                        {"endPage": "S2148", "startPage": "S2147", "text": [{"type": "Formatted Text", "url": "https://www.congress.gov/118/crec/2023/06/22/169/108/modified/CREC-2023-06-22-pt1-PgS2147.htm"}, {"type": "PDF", "url": "https://www.congress.gov/118/crec/2023/06/22/169/108/CREC-2023-06-22-pt1-PgS2147.pdf"}], "title": "ADJOURNMENT UNTIL MONDAY, JUNE 26, 2023, AT 3 P.M."}
+                    ]
+                }
+            ],
+            "pagination": {"count": 139}
+        }"#;
+
+        let res: ArticlesResponse = serde_json::from_str(json).unwrap();
+        let article = &res.articles[0].section_articles[0];
+        assert_eq!(article.start_page.unwrap().number, 2147);
+        assert_eq!(article.end_page.unwrap().prefix, Some('S'));
+        assert_eq!(article.text.len(), 2);
+    }
+}
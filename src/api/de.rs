@@ -0,0 +1,204 @@
+//! Deserialization helpers for typed response models.
+//!
+//! The congress.gov API is not consistent in how it represents dates and
+//! numbers. Dates may be plain `YYYY-MM-DD` strings, RFC 3339 timestamps, or
+//! timestamps with a space separator, and identifiers such as bill numbers
+//! may be returned as either JSON numbers or strings. These helpers accept
+//! every form seen in API responses.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{de, Deserialize, Deserializer};
+
+/// Parse a date from any of the date or timestamp forms used by the API.
+pub(crate) fn parse_date(s: &str) -> Option<NaiveDate> {
+    let s = s.trim();
+    NaiveDate::parse_from_str(s.get(..10)?, "%Y-%m-%d").ok()
+}
+
+/// Parse a timestamp from any of the date or timestamp forms used by the
+/// API. Plain dates are interpreted as midnight UTC.
+pub(crate) fn parse_date_time(s: &str) -> Option<DateTime<Utc>> {
+    let s = s.trim();
+    if let Ok(dt) = DateTime::parse_from_rfc3339(s) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = DateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%:z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S") {
+        return Some(dt.and_utc());
+    }
+    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S") {
+        return Some(dt.and_utc());
+    }
+    NaiveDate::parse_from_str(s, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc())
+}
+
+/// Deserialize an optional date. Empty strings are treated as missing.
+pub(crate) fn opt_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.trim().is_empty() => parse_date(&s)
+            .map(Some)
+            .ok_or_else(|| de::Error::custom(format!("invalid date: {s}"))),
+        _ => Ok(None),
+    }
+}
+
+/// Deserialize an optional timestamp. Empty strings are treated as missing.
+pub(crate) fn opt_date_time<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<String>::deserialize(deserializer)? {
+        Some(s) if !s.trim().is_empty() => parse_date_time(&s)
+            .map(Some)
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp: {s}"))),
+        _ => Ok(None),
+    }
+}
+
+/// Deserialize an optional string. Empty strings are treated as missing.
+pub(crate) fn opt_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<String>::deserialize(deserializer)?.filter(|s| !s.trim().is_empty()))
+}
+
+/// Deserialize an optional flag which may be represented as a JSON boolean or
+/// as a "Y"/"N" string.
+pub(crate) fn opt_flag<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flag {
+        Bool(bool),
+        String(String),
+    }
+
+    match Option::<Flag>::deserialize(deserializer)? {
+        Some(Flag::Bool(b)) => Ok(Some(b)),
+        Some(Flag::String(s)) => match s.trim().to_ascii_lowercase().as_str() {
+            "" => Ok(None),
+            "y" | "yes" | "true" => Ok(Some(true)),
+            "n" | "no" | "false" => Ok(Some(false)),
+            _ => Err(de::Error::custom(format!("invalid flag: {s}"))),
+        },
+        None => Ok(None),
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(u64),
+    String(String),
+}
+
+impl NumberOrString {
+    fn into_u32<E: de::Error>(self) -> Result<Option<u32>, E> {
+        match self {
+            NumberOrString::Number(n) => u32::try_from(n).map(Some).map_err(E::custom),
+            NumberOrString::String(s) if s.trim().is_empty() => Ok(None),
+            NumberOrString::String(s) => s.trim().parse().map(Some).map_err(E::custom),
+        }
+    }
+}
+
+/// Deserialize a number which may be represented as a JSON number or string.
+pub(crate) fn number<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    NumberOrString::deserialize(deserializer)?
+        .into_u32()?
+        .ok_or_else(|| de::Error::custom("expected a number"))
+}
+
+/// Deserialize an optional number which may be represented as a JSON number
+/// or string.
+pub(crate) fn opt_number<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<NumberOrString>::deserialize(deserializer)? {
+        Some(n) => n.into_u32(),
+        None => Ok(None),
+    }
+}
+
+/// Deserialize a sequence that the API may return as either a single object
+/// or a list of objects, or omit entirely.
+pub(crate) fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+
+    Ok(match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::Many(items)) => items,
+        Some(OneOrMany::One(item)) => vec![item],
+        None => Vec::new(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, TimeZone, Utc};
+
+    use super::*;
+
+    #[test]
+    fn parses_every_date_form() {
+        let expected = NaiveDate::from_ymd_opt(2022, 8, 3).unwrap();
+        assert_eq!(parse_date("2022-08-03"), Some(expected));
+        assert_eq!(parse_date("2022-08-03T04:00:00Z"), Some(expected));
+        assert_eq!(parse_date("2022-08-03 04:00:00+00:00"), Some(expected));
+        assert_eq!(parse_date("08/03/2022"), None);
+    }
+
+    #[test]
+    fn parses_every_date_time_form() {
+        let expected = Utc.with_ymd_and_hms(2022, 8, 3, 4, 0, 0).unwrap();
+        assert_eq!(parse_date_time("2022-08-03T04:00:00Z"), Some(expected));
+        assert_eq!(parse_date_time("2022-08-03T00:00:00-04:00"), Some(expected));
+        assert_eq!(parse_date_time("2022-08-03 04:00:00+00:00"), Some(expected));
+        assert_eq!(parse_date_time("2022-08-03T04:00:00"), Some(expected));
+        assert_eq!(
+            parse_date_time("2022-08-03"),
+            Some(Utc.with_ymd_and_hms(2022, 8, 3, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn numbers_may_be_strings() {
+        #[derive(Deserialize)]
+        struct Value {
+            #[serde(deserialize_with = "number")]
+            a: u32,
+            #[serde(default, deserialize_with = "opt_number")]
+            b: Option<u32>,
+        }
+
+        let v: Value = serde_json::from_str(r#"{"a": "3076", "b": 12}"#).unwrap();
+        assert_eq!(v.a, 3076);
+        assert_eq!(v.b, Some(12));
+
+        let v: Value = serde_json::from_str(r#"{"a": 1}"#).unwrap();
+        assert_eq!(v.b, None);
+    }
+}
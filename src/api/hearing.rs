@@ -7,6 +7,8 @@ mod congress;
 mod hearing;
 mod jacket_number;
 
+pub mod models;
+
 pub use self::chamber::{Chamber, ChamberBuilder, ChamberBuilderError};
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
 pub use self::hearing::{Hearing, HearingBuilder, HearingBuilderError};
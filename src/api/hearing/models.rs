@@ -0,0 +1,139 @@
+//! Typed response models for the Hearing endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::models::CommitteeRef,
+    committee_meeting::MeetingEventId,
+    common::{CommitteeChamber, Pagination, TextFormat},
+    de,
+    paged::impl_page,
+};
+
+/// A date on which a hearing was held.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HearingDate {
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub date: Option<NaiveDate>,
+}
+
+/// The committee meeting at which a hearing was held.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct AssociatedMeeting {
+    pub event_id: MeetingEventId,
+    pub url: Option<String>,
+}
+
+/// A printed hearing, as returned by both the list and detail endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Hearing {
+    #[serde(deserialize_with = "de::number")]
+    pub jacket_number: u32,
+    pub congress: u16,
+    pub chamber: Option<CommitteeChamber>,
+    pub citation: Option<String>,
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub part: Option<u32>,
+    pub library_of_congress_identifier: Option<String>,
+    #[serde(default)]
+    pub committees: Vec<CommitteeRef>,
+    #[serde(default)]
+    pub dates: Vec<HearingDate>,
+    #[serde(default)]
+    pub formats: Vec<TextFormat>,
+    pub associated_meeting: Option<AssociatedMeeting>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl Hearing {
+    /// The dates on which the hearing was held.
+    pub fn held_on(&self) -> impl Iterator<Item = NaiveDate> + '_ {
+        self.dates.iter().filter_map(|d| d.date)
+    }
+}
+
+/// The response of the /hearing, /hearing/:congress and
+/// /hearing/:congress/:chamber endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HearingsResponse {
+    #[serde(default)]
+    pub hearings: Vec<Hearing>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /hearing/:congress/:chamber/:jacketNumber endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HearingResponse {
+    pub hearing: Hearing,
+}
+
+impl_page!(HearingsResponse => hearings: Hearing);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::common::TextFormatKind;
+
+    use super::*;
+
+    #[test]
+    fn hearing_detail() {
+        let json = r#"{
+            "hearing": {
+                "associatedMeeting": {"eventId": "110484", "url": "https://api.congress.gov/v3/committee-meeting/116/house/110484?format=json"},
+                "chamber": "House",
+                "citation": "H.Hrg.116",
+                "committees": [{"name": "House Agriculture Committee", "systemCode": "hsag00", "url": "https://api.congress.gov/v3/committee/house/hsag00?format=json"}],
+                "congress": 116,
+                "dates": [{"date": "2020-02-11"}],
+                "formats": [
+                    {"type": "Formatted Text", "url": "https://congress.gov/116/chrg/CHRG-116hhrg41365/generated/CHRG-116hhrg41365.htm"},
+                    {"type": "PDF", "url": "https://congress.gov/116/chrg/CHRG-116hhrg41365/CHRG-116hhrg41365.pdf"}
+                ],
+                "jacketNumber": 41365,
+                "libraryOfCongressIdentifier": "LC65343",
+                "title": "ECONOMIC OPPORTUNITIES FROM LOCAL AGRICULTURAL MARKETS",
+                "updateDate": "2022-06-30 03:50:43+00:00"
+            }
+        }"#;
+
+        let res: HearingResponse = serde_json::from_str(json).unwrap();
+        let hearing = res.hearing;
+
+        assert_eq!(hearing.jacket_number, 41365);
+        assert_eq!(
+            hearing.associated_meeting.as_ref().unwrap().event_id,
+            110484
+        );
+        assert_eq!(
+            hearing.held_on().collect::<Vec<_>>(),
+            [NaiveDate::from_ymd_opt(2020, 2, 11).unwrap()]
+        );
+        assert_eq!(hearing.formats[1].kind, TextFormatKind::Pdf);
+    }
+
+    #[test]
+    fn hearing_list() {
+        let json = r#"{"hearings": [{"chamber": "House", "congress": 116, "jacketNumber": 41365, "updateDate": "2022-06-30 03:50:43+00:00", "url": "https://api.congress.gov/v3/hearing/116/house/41365?format=json"}], "pagination": {"count": 1}}"#;
+
+        let res: HearingsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.hearings[0].chamber, Some(CommitteeChamber::House));
+        assert!(res.hearings[0].formats.is_empty());
+    }
+}
@@ -2,15 +2,19 @@
 
 //! House-communication API endpoints and types.
 
+use crate::api::common::impl_api_enum;
+
 mod communication_number;
 mod communication_type;
 mod congress;
 mod house_communication;
 
+pub mod models;
+
 /// The possible communication types in the House of Representatives
 /// available via the congress.gov API. R – Requirements also exists,
 /// but it is not an option with the API.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum HouseCommunicationType {
     /// Executive Communications
     Ec,
@@ -23,7 +27,7 @@ pub enum HouseCommunicationType {
 }
 
 impl HouseCommunicationType {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             HouseCommunicationType::Ec => "ec",
             HouseCommunicationType::Ml => "ml",
@@ -33,6 +37,24 @@ impl HouseCommunicationType {
     }
 }
 
+impl_api_enum!(
+    HouseCommunicationType,
+    "House communication type",
+    [Ec, Ml, Pm, Pt],
+    json {
+        Ec => "EC",
+        Ml => "ML",
+        Pm => "PM",
+        Pt => "PT",
+    },
+    labels {
+        Ec => ["Executive Communication"],
+        Ml => ["Memorial"],
+        Pm => ["Presidential Message"],
+        Pt => ["Petition"],
+    }
+);
+
 pub use self::communication_number::{
     CommunicationNumber, CommunicationNumberBuilder, CommunicationNumberBuilderError,
 };
@@ -0,0 +1,162 @@
+//! Typed response models for the House Communication endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::models::CommunicationReferral,
+    common::{CommitteeChamber, CommunicationTypeCode, Pagination},
+    de,
+};
+
+/// A House requirement that a communication was submitted to satisfy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MatchingRequirement {
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub url: Option<String>,
+}
+
+/// A House document printed from a communication.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseDocument {
+    pub citation: String,
+    pub title: Option<String>,
+}
+
+/// A House communication, as returned by both the list and detail endpoints.
+///
+/// The list endpoints only return the identifying fields and a few
+/// descriptive ones; the remaining fields are filled by the
+/// /house-communication/:congress/:communicationType/:communicationNumber
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseCommunication {
+    /// The list endpoints name this field "congressNumber".
+    #[serde(alias = "congressNumber")]
+    pub congress: u16,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub communication_type: CommunicationTypeCode,
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(rename = "abstract", default, deserialize_with = "de::opt_string")]
+    pub abstract_text: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub congressional_record_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session_number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_flag")]
+    pub is_rulemaking: Option<bool>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub legal_authority: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub report_nature: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub submitting_agency: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub submitting_official: Option<String>,
+    #[serde(default)]
+    pub matching_requirements: Vec<MatchingRequirement>,
+    #[serde(default, alias = "houseDocument")]
+    pub house_documents: Vec<HouseDocument>,
+    #[serde(default)]
+    pub committees: Vec<CommunicationReferral>,
+    /// Only set when listed through a committee's communications.
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub referral_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The response of the /house-communication, /house-communication/:congress
+/// and /house-communication/:congress/:communicationType endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseCommunicationsResponse {
+    #[serde(default)]
+    pub house_communications: Vec<HouseCommunication>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the
+/// /house-communication/:congress/:communicationType/:communicationNumber
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseCommunicationResponse {
+    #[serde(rename = "house-communication", alias = "houseCommunication")]
+    pub house_communication: HouseCommunication,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn house_communication_detail() {
+        let json = r#"{
+            "house-communication": {
+                "abstract": "A letter from the Chairman, Council of the District of Columbia, transmitting D.C. Act 24-267, \"Protecting Businesses and Workers from Wage Theft Temporary Amendment Act of 2022\", pursuant to Public Law 93-198, section 602(c)(1); (87 Stat. 814); to the Committee on Oversight and Reform.",
+                "chamber": "House",
+                "committees": [{"name": "Oversight and Reform Committee", "referralDate": "2022-02-01", "systemCode": "hsgo00", "url": "https://api.congress.gov/v3/committee/house/hsgo00?format=json"}],
+                "communicationType": {"code": "EC", "name": "Executive Communication"},
+                "congress": 117,
+                "congressionalRecordDate": "2022-02-01",
+                "isRulemaking": "False",
+                "legalAuthority": "Public Law 93-198, section 602(c)(1); (87 Stat. 814)",
+                "matchingRequirements": [{"number": "2120", "url": "http://api.congress.gov/v3/house-requirement/2120?format=json"}],
+                "number": "3324",
+                "reportNature": "D.C. Act 24-267, \"Protecting Businesses and Workers from Wage Theft Temporary Amendment Act of 2022\".",
+                "sessionNumber": 2,
+                "submittingAgency": "Council of the District of Columbia",
+                "submittingOfficial": "Chairman",
+                "updateDate": "2022-02-18"
+            },
+            "request": {"communicationType": "ec", "congress": "117", "contentType": "application/json", "format": "json", "number": "3324"}
+        }"#;
+
+        let res: HouseCommunicationResponse = serde_json::from_str(json).unwrap();
+        let communication = res.house_communication;
+
+        assert_eq!(communication.number, 3324);
+        assert_eq!(communication.chamber, Some(CommitteeChamber::House));
+        assert_eq!(communication.is_rulemaking, Some(false));
+        assert!(communication
+            .abstract_text
+            .unwrap()
+            .starts_with("A letter from the Chairman"));
+        assert_eq!(
+            communication.submitting_agency.as_deref(),
+            Some("Council of the District of Columbia")
+        );
+        assert_eq!(communication.matching_requirements[0].number, 2120);
+        assert_eq!(
+            communication.committees[0].referral_date,
+            NaiveDate::from_ymd_opt(2022, 2, 1)
+        );
+    }
+
+    #[test]
+    fn house_communication_list() {
+        let json = r#"{"houseCommunications": [{"chamber": "House", "communicationType": {"code": "EC", "name": "Executive Communication"}, "congressNumber": 117, "number": "2057", "reportNature": "Clarifying Food and Drug Administration's Regulatory Authority Over Hemp Products.", "submittingAgency": "Department of Health and Human Services", "submittingOfficial": "Secretary", "updateDate": "2021-09-01", "url": "https://api.congress.gov/v3/house-communication/117/ec/2057?format=json"}], "pagination": {"count": 1}}"#;
+
+        let res: HouseCommunicationsResponse = serde_json::from_str(json).unwrap();
+        let communication = &res.house_communications[0];
+        assert_eq!(communication.congress, 117);
+        assert_eq!(
+            communication.submitting_official.as_deref(),
+            Some("Secretary")
+        );
+        assert!(communication.committees.is_empty());
+    }
+}
@@ -6,6 +6,8 @@ mod house_requirement;
 mod matching_communications;
 mod requirement_number;
 
+pub mod models;
+
 pub use self::house_requirement::{
     HouseRequirement, HouseRequirementBuilder, HouseRequirementBuilderError,
 };
@@ -0,0 +1,125 @@
+//! Typed response models for the House Requirement endpoints.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    common::{Pagination, ResourceCount},
+    de,
+    house_communication::models::HouseCommunication,
+};
+
+/// A House requirement, as returned by both the list and detail endpoints.
+///
+/// The list endpoint only returns the number and links; the remaining
+/// fields are filled by the /house-requirement/:requirementNumber endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseRequirement {
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    #[serde(default, deserialize_with = "de::opt_flag")]
+    pub active_record: Option<bool>,
+    /// How often the report is due, e.g. "Annual.".
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub frequency: Option<String>,
+    /// What the required report covers.
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub nature: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub legal_authority: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub parent_agency: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub submitting_agency: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub submitting_official: Option<String>,
+    pub matching_communications: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The response of the /house-requirement endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseRequirementsResponse {
+    #[serde(default)]
+    pub house_requirements: Vec<HouseRequirement>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /house-requirement/:requirementNumber endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct HouseRequirementResponse {
+    pub house_requirement: HouseRequirement,
+}
+
+/// The response of the
+/// /house-requirement/:requirementNumber/matching-communications endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MatchingCommunicationsResponse {
+    #[serde(default)]
+    pub matching_communications: Vec<HouseCommunication>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn house_requirement_detail() {
+        let json = r#"{
+            "houseRequirement": {
+                "activeRecord": true,
+                "frequency": "Annual.",
+                "legalAuthority": "Public Law 116–260, div. N, title II, sec. 223(a); (134 Stat. 1927)",
+                "matchingCommunications": {"count": 2, "url": "https://api.congress.gov/v3/house-requirement/8070/matching-communications?format=json"},
+                "nature": "Report on the Pandemic Relief Programs.",
+                "number": 8070,
+                "parentAgency": "Department of Health and Human Services",
+                "submittingAgency": "Centers for Disease Control and Prevention",
+                "submittingOfficial": "Director",
+                "updateDate": "2021-08-13"
+            },
+            "request": {"contentType": "application/json", "format": "json", "requirementNumber": "8070"}
+        }"#;
+
+        let res: HouseRequirementResponse = serde_json::from_str(json).unwrap();
+        let requirement = res.house_requirement;
+
+        assert_eq!(requirement.number, 8070);
+        assert_eq!(requirement.active_record, Some(true));
+        assert_eq!(requirement.frequency.as_deref(), Some("Annual."));
+        assert_eq!(
+            requirement.parent_agency.as_deref(),
+            Some("Department of Health and Human Services")
+        );
+        assert_eq!(requirement.matching_communications.unwrap().count, 2);
+    }
+
+    #[test]
+    fn house_requirement_list_and_matching_communications() {
+        let list: HouseRequirementsResponse = serde_json::from_str(
+            r#"{"houseRequirements": [{"number": 8070, "updateDate": "2021-08-13", "url": "https://api.congress.gov/v3/house-requirement/8070?format=json"}], "pagination": {"count": 1}}"#,
+        )
+        .unwrap();
+        assert!(list.house_requirements[0].frequency.is_none());
+
+        let matching: MatchingCommunicationsResponse = serde_json::from_str(
+            r#"{"matchingCommunications": [{"chamber": "House", "communicationType": {"code": "EC", "name": "Executive Communication"}, "congress": 117, "number": 2642, "url": "https://api.congress.gov/v3/house-communication/117/ec/2642?format=json"}], "pagination": {"count": 2}}"#,
+        )
+        .unwrap();
+        assert_eq!(matching.matching_communications[0].number, 2642);
+        assert_eq!(matching.pagination.count, 2);
+    }
+}
@@ -2,10 +2,14 @@
 //!
 //! Note there is no general /law endpoint.
 
+use crate::api::common::impl_api_enum;
+
 pub use congress::Congress;
 pub use congress::CongressBuilder;
 pub use congress::CongressBuilderError;
 
+pub use law_id::LawId;
+
 pub use law_number::LawNumber;
 pub use law_number::LawNumberBuilder;
 pub use law_number::LawNumberBuilderError;
@@ -15,11 +19,12 @@ pub use law_type::LawTypeBuilder;
 pub use law_type::LawTypeBuilderError;
 
 mod congress;
+mod law_id;
 mod law_number;
 mod law_type;
 
 /// The possible law types in Congress. Also known as 'slip laws.'
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum CongressionalLawType {
     /// Public laws affect society as a whole
     Public,
@@ -28,7 +33,7 @@ pub enum CongressionalLawType {
 }
 
 impl CongressionalLawType {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             CongressionalLawType::Public => "pub",
             CongressionalLawType::Private => "priv",
@@ -36,6 +41,16 @@ impl CongressionalLawType {
     }
 }
 
+impl_api_enum!(
+    CongressionalLawType,
+    "law type",
+    [Public, Private],
+    json {
+        Public => "Public Law",
+        Private => "Private Law",
+    }
+);
+
 impl Default for CongressionalLawType {
     fn default() -> Self {
         Self::Public
@@ -0,0 +1,171 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::api::{
+    calendar::Congress,
+    citation::{self, Citation, ParseCitationError},
+    law::{CongressionalLawType, LawNumberBuilder},
+};
+
+/// Identifies a public or private law by its congress and number.
+///
+/// A `LawId` can be parsed from the common ways of citing a law, such as
+/// "Pub. L. 117-108", "P.L. 117-58", "PL117-108", "Private Law 116-1" or the
+/// Bluebook form "Pub. L. No. 117-108", and is displayed in the Bluebook form.
+///
+/// ```rust
+/// use congressdotgov_rs::api::law::{CongressionalLawType, LawId, LawNumber};
+///
+/// let id: LawId = "P.L. 117-58".parse().unwrap();
+/// assert_eq!(id, LawId::new(117, CongressionalLawType::Public, 58));
+/// assert_eq!(id.to_string(), "Pub. L. No. 117-58");
+///
+/// let endpoint = LawNumber::from(id);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LawId {
+    pub congress: u16,
+    pub law_type: CongressionalLawType,
+    pub number: u32,
+}
+
+impl LawId {
+    pub fn new(congress: u16, law_type: CongressionalLawType, number: u32) -> Self {
+        Self {
+            congress,
+            law_type,
+            number,
+        }
+    }
+}
+
+fn law_type(prefix: &str) -> Option<CongressionalLawType> {
+    let prefix = prefix.strip_suffix("no").unwrap_or(prefix);
+    match prefix {
+        "pl" | "publ" | "publaw" | "publiclaw" => Some(CongressionalLawType::Public),
+        "pvtl" | "privl" | "privlaw" | "privatelaw" => Some(CongressionalLawType::Private),
+        _ => None,
+    }
+}
+
+impl FromStr for LawId {
+    type Err = ParseCitationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const KIND: &str = "law";
+
+        let invalid = || ParseCitationError::unrecognized(KIND, s);
+        let citation = Citation::split(s).ok_or_else(invalid)?;
+        let law_type = law_type(&citation.prefix).ok_or_else(invalid)?;
+
+        let (congress, number) = match citation.numbers() {
+            (congress, Some(number)) => (congress, number),
+            (_, None) => return Err(invalid()),
+        };
+        let congress = citation::congress(congress).ok_or_else(invalid)?;
+        let number = citation::number(number).ok_or_else(invalid)?;
+        let congress = Congress::new(congress)
+            .map(u16::from)
+            .map_err(|_| ParseCitationError::invalid_congress(KIND, s, congress))?;
+
+        Ok(Self::new(congress, law_type, number))
+    }
+}
+
+impl fmt::Display for LawId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let abbreviation = match self.law_type {
+            CongressionalLawType::Public => "Pub. L.",
+            CongressionalLawType::Private => "Priv. L.",
+        };
+        write!(f, "{} No. {}-{}", abbreviation, self.congress, self.number)
+    }
+}
+
+impl From<LawId> for LawNumberBuilder {
+    fn from(id: LawId) -> Self {
+        let mut builder = Self::default();
+        builder
+            .congress(id.congress)
+            .law_type(id.law_type)
+            .law_number(id.number);
+        builder
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{endpoint::Endpoint, law::LawNumber};
+
+    use super::*;
+
+    #[test]
+    fn parses_citations() {
+        let public = LawId::new(117, CongressionalLawType::Public, 108);
+        let private = LawId::new(116, CongressionalLawType::Private, 1);
+
+        for (citation, id) in [
+            ("Pub. L. 117-108", public),
+            ("Pub. L. No. 117-108", public),
+            ("P.L. 117-108", public),
+            ("PL117-108", public),
+            ("Public Law 117-108", public),
+            ("Private Law 116-1", private),
+            ("Priv. L. No. 116-1", private),
+            ("Pvt. L. 116-1", private),
+        ] {
+            assert_eq!(citation.parse::<LawId>(), Ok(id), "{citation}");
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for id in [
+            LawId::new(117, CongressionalLawType::Public, 58),
+            LawId::new(116, CongressionalLawType::Private, 1),
+            LawId::new(93, CongressionalLawType::Public, 198),
+        ] {
+            let citation = id.to_string();
+            assert_eq!(citation.parse::<LawId>(), Ok(id), "{citation}");
+        }
+
+        assert_eq!(
+            LawId::new(116, CongressionalLawType::Private, 1).to_string(),
+            "Priv. L. No. 116-1"
+        );
+    }
+
+    #[test]
+    fn rejects_invalid_citations() {
+        for citation in [
+            "",
+            "P.L.",
+            "P.L. 117",
+            "P.L. 117-",
+            "Pub. Res. 117-1",
+            "H.R. 117-5",
+        ] {
+            assert!(citation.parse::<LawId>().is_err(), "{citation}");
+        }
+
+        assert!(matches!(
+            "Pub. L. 300-1".parse::<LawId>(),
+            Err(ParseCitationError::InvalidCongress { congress: 300, .. })
+        ));
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let id: LawId = "Private Law 116-1".parse().unwrap();
+
+        assert_eq!(LawNumber::from(id).endpoint(), "law/116/priv/1");
+        assert_eq!(
+            LawNumberBuilder::from(id)
+                .limit(5)
+                .build()
+                .unwrap()
+                .endpoint(),
+            "law/116/priv/1"
+        );
+    }
+}
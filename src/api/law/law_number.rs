@@ -4,7 +4,7 @@ use std::borrow::Cow;
 
 use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
 
-use super::CongressionalLawType;
+use super::{CongressionalLawType, LawId};
 
 /// Represents the /law/:congress/:lawType/:lawNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -30,6 +30,19 @@ impl LawNumber {
     }
 }
 
+impl From<LawId> for LawNumber {
+    fn from(id: LawId) -> Self {
+        Self {
+            congress: id.congress,
+            law_type: id.law_type,
+            law_number: id.number,
+            format: Format::default(),
+            offset: None,
+            limit: None,
+        }
+    }
+}
+
 impl Endpoint for LawNumber {
     fn method(&self) -> Method {
         Method::GET
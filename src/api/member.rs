@@ -2,15 +2,25 @@
 
 //! Member API endpoints and types.
 
+use std::fmt;
+use std::num::NonZeroU16;
+
+use crate::api::common::{impl_api_enum, ApiEnum};
+
+mod bioguide;
 mod bioguide_id;
 mod congress;
 mod congress_state_code_district;
 mod cosponsored_legislation;
 mod member;
+mod profile;
 mod sponsored_legislation;
 mod state_code;
 mod state_code_district;
 
+pub mod models;
+
+pub use self::bioguide::Bioguide;
 pub use self::bioguide_id::{BioguideId, BioguideIdBuilder, BioguideIdBuilderError};
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
 pub use self::congress_state_code_district::{
@@ -21,6 +31,7 @@ pub use self::cosponsored_legislation::{
     CosponsoredLegislation, CosponsoredLegislationBuilder, CosponsoredLegislationBuilderError,
 };
 pub use self::member::{Member, MemberBuilder, MemberBuilderError};
+pub use self::profile::{MemberProfile, MemberStats};
 pub use self::sponsored_legislation::{
     SponsoredLegislation, SponsoredLegislationBuilder, SponsoredLegislationBuilderError,
 };
@@ -31,8 +42,13 @@ pub use self::state_code_district::{
 
 /// The different possible state codes that can be used when querying
 /// and filtering Congressional member data. These match the two-digit
-/// postal codes for the 50 U.S. states and the District of Columbia.
-#[derive(Debug, Clone, Copy)]
+/// postal codes for the 50 U.S. states, the District of Columbia and the
+/// five territories that send a delegate or resident commissioner to the
+/// House.
+///
+/// A code is parsed from either its postal code or its name, ignoring case,
+/// and is serialized as its postal code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum CongressionalStateCode {
     AL,
     AK,
@@ -85,10 +101,81 @@ pub enum CongressionalStateCode {
     WV,
     WI,
     WY,
+    // Territories
+    AS,
+    GU,
+    MP,
+    PR,
+    VI,
 }
 
 impl CongressionalStateCode {
-    fn as_str(self) -> &'static str {
+    /// Every state, the District of Columbia and the territories.
+    const ALL: [CongressionalStateCode; 56] = [
+        CongressionalStateCode::AL,
+        CongressionalStateCode::AK,
+        CongressionalStateCode::AZ,
+        CongressionalStateCode::AR,
+        CongressionalStateCode::CA,
+        CongressionalStateCode::CO,
+        CongressionalStateCode::CT,
+        CongressionalStateCode::DE,
+        CongressionalStateCode::DC,
+        CongressionalStateCode::FL,
+        CongressionalStateCode::GA,
+        CongressionalStateCode::HI,
+        CongressionalStateCode::ID,
+        CongressionalStateCode::IL,
+        CongressionalStateCode::IN,
+        CongressionalStateCode::IA,
+        CongressionalStateCode::KS,
+        CongressionalStateCode::KY,
+        CongressionalStateCode::LA,
+        CongressionalStateCode::ME,
+        CongressionalStateCode::MD,
+        CongressionalStateCode::MA,
+        CongressionalStateCode::MI,
+        CongressionalStateCode::MN,
+        CongressionalStateCode::MS,
+        CongressionalStateCode::MO,
+        CongressionalStateCode::MT,
+        CongressionalStateCode::NE,
+        CongressionalStateCode::NV,
+        CongressionalStateCode::NH,
+        CongressionalStateCode::NJ,
+        CongressionalStateCode::NM,
+        CongressionalStateCode::NY,
+        CongressionalStateCode::NC,
+        CongressionalStateCode::ND,
+        CongressionalStateCode::OH,
+        CongressionalStateCode::OK,
+        CongressionalStateCode::OR,
+        CongressionalStateCode::PA,
+        CongressionalStateCode::RI,
+        CongressionalStateCode::SC,
+        CongressionalStateCode::SD,
+        CongressionalStateCode::TN,
+        CongressionalStateCode::TX,
+        CongressionalStateCode::UT,
+        CongressionalStateCode::VT,
+        CongressionalStateCode::VA,
+        CongressionalStateCode::WA,
+        CongressionalStateCode::WV,
+        CongressionalStateCode::WI,
+        CongressionalStateCode::WY,
+        CongressionalStateCode::AS,
+        CongressionalStateCode::GU,
+        CongressionalStateCode::MP,
+        CongressionalStateCode::PR,
+        CongressionalStateCode::VI,
+    ];
+
+    /// The state or territory with the given FIPS code.
+    pub fn from_fips(fips: u8) -> Option<Self> {
+        Self::ALL.iter().copied().find(|code| code.fips() == fips)
+    }
+
+    pub fn as_str(self) -> &'static str {
         use CongressionalStateCode::*;
         match self {
             AL => "AL",
@@ -142,6 +229,266 @@ impl CongressionalStateCode {
             WV => "WV",
             WI => "WI",
             WY => "WY",
+            AS => "AS",
+            GU => "GU",
+            MP => "MP",
+            PR => "PR",
+            VI => "VI",
+        }
+    }
+
+    /// The full name of the state or territory.
+    pub fn name(self) -> &'static str {
+        use CongressionalStateCode::*;
+        match self {
+            AL => "Alabama",
+            AK => "Alaska",
+            AZ => "Arizona",
+            AR => "Arkansas",
+            CA => "California",
+            CO => "Colorado",
+            CT => "Connecticut",
+            DE => "Delaware",
+            DC => "District of Columbia",
+            FL => "Florida",
+            GA => "Georgia",
+            HI => "Hawaii",
+            ID => "Idaho",
+            IL => "Illinois",
+            IN => "Indiana",
+            IA => "Iowa",
+            KS => "Kansas",
+            KY => "Kentucky",
+            LA => "Louisiana",
+            ME => "Maine",
+            MD => "Maryland",
+            MA => "Massachusetts",
+            MI => "Michigan",
+            MN => "Minnesota",
+            MS => "Mississippi",
+            MO => "Missouri",
+            MT => "Montana",
+            NE => "Nebraska",
+            NV => "Nevada",
+            NH => "New Hampshire",
+            NJ => "New Jersey",
+            NM => "New Mexico",
+            NY => "New York",
+            NC => "North Carolina",
+            ND => "North Dakota",
+            OH => "Ohio",
+            OK => "Oklahoma",
+            OR => "Oregon",
+            PA => "Pennsylvania",
+            RI => "Rhode Island",
+            SC => "South Carolina",
+            SD => "South Dakota",
+            TN => "Tennessee",
+            TX => "Texas",
+            UT => "Utah",
+            VT => "Vermont",
+            VA => "Virginia",
+            WA => "Washington",
+            WV => "West Virginia",
+            WI => "Wisconsin",
+            WY => "Wyoming",
+            AS => "American Samoa",
+            GU => "Guam",
+            MP => "Northern Mariana Islands",
+            PR => "Puerto Rico",
+            VI => "Virgin Islands",
+        }
+    }
+
+    /// The numeric FIPS code of the state or territory, usually written with
+    /// two digits, e.g. `01` for Alabama.
+    pub fn fips(self) -> u8 {
+        use CongressionalStateCode::*;
+        match self {
+            AL => 1,
+            AK => 2,
+            AZ => 4,
+            AR => 5,
+            CA => 6,
+            CO => 8,
+            CT => 9,
+            DE => 10,
+            DC => 11,
+            FL => 12,
+            GA => 13,
+            HI => 15,
+            ID => 16,
+            IL => 17,
+            IN => 18,
+            IA => 19,
+            KS => 20,
+            KY => 21,
+            LA => 22,
+            ME => 23,
+            MD => 24,
+            MA => 25,
+            MI => 26,
+            MN => 27,
+            MS => 28,
+            MO => 29,
+            MT => 30,
+            NE => 31,
+            NV => 32,
+            NH => 33,
+            NJ => 34,
+            NM => 35,
+            NY => 36,
+            NC => 37,
+            ND => 38,
+            OH => 39,
+            OK => 40,
+            OR => 41,
+            PA => 42,
+            RI => 44,
+            SC => 45,
+            SD => 46,
+            TN => 47,
+            TX => 48,
+            UT => 49,
+            VT => 50,
+            VA => 51,
+            WA => 53,
+            WV => 54,
+            WI => 55,
+            WY => 56,
+            AS => 60,
+            GU => 66,
+            MP => 69,
+            PR => 72,
+            VI => 78,
         }
     }
+
+    /// Whether the state or territory is represented in the House by a
+    /// non-voting delegate or resident commissioner, always elected at
+    /// large.
+    pub fn has_delegate(self) -> bool {
+        use CongressionalStateCode::*;
+        matches!(self, DC | AS | GU | MP | PR | VI)
+    }
+}
+
+impl ApiEnum for CongressionalStateCode {
+    const KIND: &'static str = "state or territory";
+
+    fn all() -> &'static [Self] {
+        &Self::ALL
+    }
+
+    fn as_str(self) -> &'static str {
+        CongressionalStateCode::as_str(self)
+    }
+
+    /// Accepts the full name of the state or territory, which the API uses
+    /// in responses.
+    fn matches(self, s: &str) -> bool {
+        self.as_str().eq_ignore_ascii_case(s) || self.name().eq_ignore_ascii_case(s)
+    }
+}
+
+impl_api_enum!(@traits CongressionalStateCode);
+
+/// A congressional district of a state or territory.
+///
+/// The API numbers the single district of a state with one representative,
+/// and the seat of a delegate or resident commissioner, as district 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum District {
+    /// The district of a state with one representative, or of a territory
+    /// represented by a delegate or resident commissioner.
+    AtLarge,
+    /// A numbered district. District 0 is always [`District::AtLarge`].
+    Numbered(NonZeroU16),
+}
+
+impl District {
+    /// The district of a delegate or resident commissioner.
+    pub const DELEGATE: District = District::AtLarge;
+
+    /// The number the API uses for the district.
+    pub fn number(self) -> u16 {
+        match self {
+            District::AtLarge => 0,
+            District::Numbered(number) => number.get(),
+        }
+    }
+}
+
+impl From<u16> for District {
+    fn from(number: u16) -> Self {
+        NonZeroU16::new(number).map_or(District::AtLarge, District::Numbered)
+    }
+}
+
+impl fmt::Display for District {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            District::AtLarge => f.write_str("At-Large"),
+            District::Numbered(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_state_codes() {
+        for (s, code) in [
+            ("MI", CongressionalStateCode::MI),
+            ("mi", CongressionalStateCode::MI),
+            ("Michigan", CongressionalStateCode::MI),
+            ("puerto rico", CongressionalStateCode::PR),
+            (" GU ", CongressionalStateCode::GU),
+        ] {
+            assert_eq!(s.parse(), Ok(code), "{s}");
+        }
+        assert!("XX".parse::<CongressionalStateCode>().is_err());
+
+        for code in CongressionalStateCode::all() {
+            assert_eq!(code.to_string().parse(), Ok(*code));
+            assert_eq!(code.name().parse(), Ok(*code));
+            assert_eq!(CongressionalStateCode::from_fips(code.fips()), Some(*code));
+        }
+        assert_eq!(CongressionalStateCode::all().len(), 56);
+    }
+
+    #[test]
+    fn state_code_serde() {
+        let json = serde_json::to_string(&CongressionalStateCode::VI).unwrap();
+        assert_eq!(json, r#""VI""#);
+        assert_eq!(
+            serde_json::from_str::<CongressionalStateCode>(r#""Northern Mariana Islands""#)
+                .unwrap(),
+            CongressionalStateCode::MP
+        );
+    }
+
+    #[test]
+    fn fips_codes() {
+        assert_eq!(CongressionalStateCode::AL.fips(), 1);
+        assert_eq!(CongressionalStateCode::DC.fips(), 11);
+        assert_eq!(CongressionalStateCode::PR.fips(), 72);
+        assert_eq!(CongressionalStateCode::from_fips(3), None);
+    }
+
+    #[test]
+    fn districts() {
+        assert_eq!(District::from(0), District::AtLarge);
+        assert_eq!(
+            District::from(10),
+            District::Numbered(NonZeroU16::new(10).unwrap())
+        );
+        assert_eq!(District::from(10).number(), 10);
+        assert_eq!(District::DELEGATE.number(), 0);
+        assert_eq!(District::AtLarge.to_string(), "At-Large");
+        assert!(CongressionalStateCode::PR.has_delegate());
+        assert!(!CongressionalStateCode::AK.has_delegate());
+    }
 }
@@ -0,0 +1,119 @@
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::api::common::ParseIdError;
+
+/// A Biographical Directory identifier of a member of Congress, made of the
+/// first letter of the member's surname and six digits, e.g. "L000174".
+///
+/// A `Bioguide` is parsed ignoring case, and can be given to every builder
+/// that takes a bioguide ID.
+///
+/// ```rust
+/// use congressdotgov_rs::api::member::{Bioguide, BioguideId};
+///
+/// let bioguide: Bioguide = "l000174".parse().unwrap();
+/// assert_eq!(bioguide.as_str(), "L000174");
+/// assert!("L00174".parse::<Bioguide>().is_err());
+///
+/// let endpoint = BioguideId::builder().bioguide_id(bioguide).build().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bioguide([u8; 7]);
+
+impl Bioguide {
+    pub fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.0).expect("bioguide IDs are ASCII")
+    }
+}
+
+impl FromStr for Bioguide {
+    type Err = ParseIdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseIdError {
+            kind: "bioguide ID",
+            input: s.into(),
+        };
+        let bytes: [u8; 7] = s.trim().as_bytes().try_into().map_err(|_| invalid())?;
+        if !bytes[0].is_ascii_alphabetic() || !bytes[1..].iter().all(u8::is_ascii_digit) {
+            return Err(invalid());
+        }
+
+        let mut id = bytes;
+        id[0] = id[0].to_ascii_uppercase();
+        Ok(Self(id))
+    }
+}
+
+impl fmt::Display for Bioguide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<'a> From<Bioguide> for Cow<'a, str> {
+    fn from(bioguide: Bioguide) -> Self {
+        Cow::Owned(bioguide.as_str().into())
+    }
+}
+
+impl Serialize for Bioguide {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for Bioguide {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{endpoint::Endpoint, member::SponsoredLegislation};
+
+    use super::*;
+
+    #[test]
+    fn parses_bioguide_ids() {
+        assert_eq!("L000174".parse::<Bioguide>().unwrap().as_str(), "L000174");
+        assert_eq!(" s001191 ".parse::<Bioguide>().unwrap().as_str(), "S001191");
+
+        for s in [
+            "", "L00017", "L0001745", "0000174", "LL00174", "L00017a", "É00017",
+        ] {
+            assert!(s.parse::<Bioguide>().is_err(), "{s}");
+        }
+    }
+
+    #[test]
+    fn bioguide_serde() {
+        let bioguide: Bioguide = serde_json::from_str(r#""L000174""#).unwrap();
+        assert_eq!(serde_json::to_string(&bioguide).unwrap(), r#""L000174""#);
+        assert!(serde_json::from_str::<Bioguide>(r#""L174""#).is_err());
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let bioguide: Bioguide = "L000174".parse().unwrap();
+
+        let endpoint = SponsoredLegislation::builder()
+            .bioguide_id(bioguide)
+            .build()
+            .unwrap();
+        assert_eq!(endpoint.endpoint(), "member/L000174/sponsored-legislation");
+    }
+}
@@ -43,7 +43,7 @@ impl Endpoint for Congress {
         params.push("format", self.format);
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
@@ -5,7 +5,10 @@ use std::borrow::Cow;
 use crate::{
     api::endpoint::Endpoint,
     api::params::QueryParams,
-    api::{common::Format, member::CongressionalStateCode},
+    api::{
+        common::Format,
+        member::{CongressionalStateCode, District},
+    },
 };
 
 /// Represents the /member/congress/:congress/:stateCode/:district endpoint.
@@ -19,7 +22,7 @@ pub struct CongressStateCodeDistrict {
     #[builder(setter(into))]
     state_code: CongressionalStateCode,
     #[builder(setter(into))]
-    district: u16,
+    district: District,
     #[builder(default)]
     format: Format,
     #[builder(default)]
@@ -42,7 +45,7 @@ impl Endpoint for CongressStateCodeDistrict {
             "member/congress/{}/{}/{}",
             self.congress,
             self.state_code.as_str(),
-            self.district
+            self.district.number()
         )
         .into()
     }
@@ -51,7 +54,7 @@ impl Endpoint for CongressStateCodeDistrict {
         let mut params = QueryParams::default();
 
         params.push("format", self.format);
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
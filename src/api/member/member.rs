@@ -44,9 +44,9 @@ impl Endpoint for Member {
         params.push("format", self.format);
         params.push_opt("offset", self.offset);
         params.push_opt("limit", self.limit);
-        params.push_opt("from_date_time", self.from_date_time);
-        params.push_opt("to_date_time", self.to_date_time);
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("fromDateTime", self.from_date_time);
+        params.push_opt("toDateTime", self.to_date_time);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
@@ -0,0 +1,263 @@
+//! Typed response models for the Member endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    bill::{models::PolicyArea, BillId},
+    common::{BillType, CommitteeChamber, LatestAction, Pagination, ResourceCount},
+    de,
+    paged::impl_page,
+};
+
+/// The official portrait of a member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Depiction {
+    pub image_url: Option<String>,
+    pub attribution: Option<String>,
+}
+
+/// A term a member served in a chamber.
+///
+/// The list endpoints only return the chamber and years of each term.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MemberTerm {
+    pub chamber: Option<CommitteeChamber>,
+    pub congress: Option<u16>,
+    /// "Representative", "Senator", "Delegate" or "Resident Commissioner".
+    pub member_type: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub start_year: Option<u32>,
+    /// The last year of the term, missing while it is being served.
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub end_year: Option<u32>,
+    pub state_code: Option<String>,
+    pub state_name: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub district: Option<u32>,
+}
+
+/// The terms of a member, as nested by the list endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MemberTerms {
+    #[serde(default, deserialize_with = "de::one_or_many")]
+    pub item: Vec<MemberTerm>,
+}
+
+/// A member of Congress, as returned by the list endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Member {
+    pub bioguide_id: String,
+    /// The member's name, last name first.
+    pub name: Option<String>,
+    pub party_name: Option<String>,
+    /// The full name of the member's state.
+    pub state: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub district: Option<u32>,
+    pub depiction: Option<Depiction>,
+    #[serde(default)]
+    pub terms: MemberTerms,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// A party a member belonged to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct PartyAffiliation {
+    pub party_name: String,
+    pub party_abbreviation: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub start_year: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub end_year: Option<u32>,
+}
+
+/// A leadership position held by a member.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Leadership {
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub congress: Option<u32>,
+    #[serde(rename = "type")]
+    pub leadership_type: String,
+    #[serde(default, deserialize_with = "de::opt_flag")]
+    pub current: Option<bool>,
+}
+
+/// A member of Congress, as returned by the /member/:bioguideId endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MemberDetail {
+    pub bioguide_id: String,
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    pub suffix_name: Option<String>,
+    pub honorific_name: Option<String>,
+    /// The member's name, first name first.
+    pub direct_order_name: Option<String>,
+    /// The member's name, last name first.
+    pub inverted_order_name: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub birth_year: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub death_year: Option<u32>,
+    pub current_member: Option<bool>,
+    pub state: Option<String>,
+    #[serde(default)]
+    pub party_history: Vec<PartyAffiliation>,
+    #[serde(default)]
+    pub leadership: Vec<Leadership>,
+    #[serde(default, deserialize_with = "de::one_or_many")]
+    pub terms: Vec<MemberTerm>,
+    pub depiction: Option<Depiction>,
+    pub official_website_url: Option<String>,
+    pub sponsored_legislation: Option<ResourceCount>,
+    pub cosponsored_legislation: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+impl MemberDetail {
+    /// The member's current party, the last in their party history.
+    pub fn party(&self) -> Option<&PartyAffiliation> {
+        self.party_history
+            .iter()
+            .max_by_key(|party| party.start_year)
+    }
+}
+
+/// A bill or amendment sponsored or cosponsored by a member.
+///
+/// Amendments have an `amendment_number` and no bill type or number.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Legislation {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub bill_type: Option<BillType>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub amendment_number: Option<u32>,
+    pub title: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub introduced_date: Option<NaiveDate>,
+    pub latest_action: Option<LatestAction>,
+    pub policy_area: Option<PolicyArea>,
+    pub url: Option<String>,
+}
+
+impl Legislation {
+    /// The identifier of the bill, if this is a bill rather than an
+    /// amendment.
+    pub fn bill_id(&self) -> Option<BillId> {
+        Some(BillId::new(self.congress, self.bill_type?, self.number?))
+    }
+
+    /// Whether this is an amendment rather than a bill.
+    pub fn is_amendment(&self) -> bool {
+        self.amendment_number.is_some()
+    }
+
+    /// Whether the latest action on the bill is its becoming law.
+    pub fn became_law(&self) -> bool {
+        self.latest_action
+            .as_ref()
+            .and_then(|action| action.text.as_deref())
+            .is_some_and(|text| {
+                text.starts_with("Became Public Law") || text.starts_with("Became Private Law")
+            })
+    }
+}
+
+/// The response of the /member endpoints listing members.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MembersResponse {
+    #[serde(default)]
+    pub members: Vec<Member>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /member/:bioguideId endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct MemberResponse {
+    pub member: MemberDetail,
+}
+
+/// The response of the /member/:bioguideId/sponsored-legislation endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SponsoredLegislationResponse {
+    #[serde(default)]
+    pub sponsored_legislation: Vec<Legislation>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /member/:bioguideId/cosponsored-legislation endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CosponsoredLegislationResponse {
+    #[serde(default)]
+    pub cosponsored_legislation: Vec<Legislation>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl_page!(
+    MembersResponse => members: Member,
+    SponsoredLegislationResponse => sponsored_legislation: Legislation,
+    CosponsoredLegislationResponse => cosponsored_legislation: Legislation,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn members() {
+        let json = r#"{
+            "members": [{
+                "bioguideId": "L000174",
+                "depiction": {"attribution": "<a href=\"http://www.senate.gov/artandhistory/history/common/generic/Photo_Collection_Main.htm\">U.S. Senate Historical Office</a>", "imageUrl": "https://www.congress.gov/img/member/l000174_200.jpg"},
+                "district": null,
+                "name": "Leahy, Patrick J.",
+                "partyName": "Democratic",
+                "state": "Vermont",
+                "terms": {"item": [{"chamber": "Senate", "endYear": null, "startYear": 1975}]},
+                "updateDate": "2022-11-07T13:42:19Z",
+                "url": "https://api.congress.gov/v3/member/L000174?format=json"
+            }],
+            "pagination": {"count": 2552}
+        }"#;
+
+        let res: MembersResponse = serde_json::from_str(json).unwrap();
+        let member = &res.members[0];
+        assert_eq!(member.district, None);
+        assert_eq!(member.terms.item[0].chamber, Some(CommitteeChamber::Senate));
+        assert_eq!(member.terms.item[0].end_year, None);
+    }
+}
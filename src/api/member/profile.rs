@@ -0,0 +1,350 @@
+//! A member's legislative record assembled from the member endpoints.
+
+use std::collections::BTreeMap;
+
+use futures_util::{join, stream, StreamExt};
+
+use crate::api::{
+    bill::{models::BillCosponsorsResponse, CosponsorsBuilder},
+    client::Client,
+    error::ApiError,
+    member::{
+        models::{
+            CosponsoredLegislationResponse, Legislation, MemberDetail, MemberResponse,
+            SponsoredLegislationResponse,
+        },
+        Bioguide, BioguideId, CosponsoredLegislation, SponsoredLegislation,
+    },
+    paged::all_pages,
+    query::Query,
+};
+
+/// The number of bills whose cosponsors are fetched at once.
+const FETCH_CONCURRENCY: usize = 8;
+
+/// Statistics derived from a member's sponsored and cosponsored legislation.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MemberStats {
+    /// The number of bills sponsored in each congress. Amendments are not
+    /// counted.
+    pub bills_sponsored_by_congress: BTreeMap<u16, u32>,
+    /// The number of amendments sponsored.
+    pub amendments_sponsored: u32,
+    /// The number of sponsored bills whose latest action is becoming law.
+    pub laws_enacted: u32,
+    /// The policy areas of sponsored bills, most common first and ties broken
+    /// by name.
+    pub top_policy_areas: Vec<(String, u32)>,
+    /// The number of cosponsors of the member's sponsored bills in each
+    /// party.
+    ///
+    /// The legislation lists do not say who cosponsored a bill, so this is
+    /// only filled in by [`MemberProfile::fetch_cosponsor_parties`].
+    pub cosponsors_by_party: Option<BTreeMap<String, u32>>,
+}
+
+impl MemberStats {
+    /// Computes the statistics of a member's sponsored legislation.
+    pub fn from_sponsored<'a, I>(sponsored: I) -> Self
+    where
+        I: IntoIterator<Item = &'a Legislation>,
+    {
+        let mut stats = Self::default();
+        let mut policy_areas = BTreeMap::<&str, u32>::new();
+
+        for legislation in sponsored {
+            if legislation.is_amendment() {
+                stats.amendments_sponsored += 1;
+                continue;
+            }
+
+            *stats
+                .bills_sponsored_by_congress
+                .entry(legislation.congress)
+                .or_default() += 1;
+            if legislation.became_law() {
+                stats.laws_enacted += 1;
+            }
+            if let Some(name) = legislation
+                .policy_area
+                .as_ref()
+                .map(|area| area.name.as_str())
+            {
+                *policy_areas.entry(name).or_default() += 1;
+            }
+        }
+
+        stats.top_policy_areas = policy_areas
+            .into_iter()
+            .map(|(name, count)| (name.to_string(), count))
+            .collect();
+        // The sort is stable, so ties keep the map's alphabetical order.
+        stats.top_policy_areas.sort_by(|(_, a), (_, b)| b.cmp(a));
+
+        stats
+    }
+
+    /// The total number of bills sponsored across every congress.
+    pub fn bills_sponsored(&self) -> u32 {
+        self.bills_sponsored_by_congress.values().sum()
+    }
+}
+
+/// A member together with every bill and amendment they have sponsored or
+/// cosponsored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MemberProfile {
+    pub member: MemberDetail,
+    pub sponsored: Vec<Legislation>,
+    pub cosponsored: Vec<Legislation>,
+    pub stats: MemberStats,
+}
+
+impl MemberProfile {
+    /// Fetches a member and all pages of their sponsored and cosponsored
+    /// legislation concurrently.
+    pub async fn fetch<C: Client>(
+        client: &C,
+        bioguide: &Bioguide,
+    ) -> Result<Self, ApiError<C::Error>> {
+        let member = BioguideId::builder()
+            .bioguide_id(bioguide.as_str())
+            .build()
+            .unwrap();
+
+        let (member, sponsored, cosponsored) = join!(
+            member.query(client),
+            all_pages::<SponsoredLegislationResponse, _, _, _>(client, |offset, limit| {
+                SponsoredLegislation::builder()
+                    .bioguide_id(bioguide.as_str())
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+            all_pages::<CosponsoredLegislationResponse, _, _, _>(client, |offset, limit| {
+                CosponsoredLegislation::builder()
+                    .bioguide_id(bioguide.as_str())
+                    .offset(offset)
+                    .limit(limit)
+                    .build()
+                    .unwrap()
+            }),
+        );
+
+        let MemberResponse { member } = member?;
+        let sponsored = match sponsored {
+            (_, Some(err)) => return Err(err),
+            (items, None) => items,
+        };
+        let cosponsored = match cosponsored {
+            (_, Some(err)) => return Err(err),
+            (items, None) => items,
+        };
+
+        let stats = MemberStats::from_sponsored(&sponsored);
+        Ok(Self {
+            member,
+            sponsored,
+            cosponsored,
+            stats,
+        })
+    }
+
+    /// Fills in [`MemberStats::cosponsors_by_party`] by fetching the
+    /// cosponsors of every sponsored bill.
+    ///
+    /// This makes at least one request per sponsored bill, fetching several
+    /// bills at a time. Withdrawn cosponsors are not counted.
+    pub async fn fetch_cosponsor_parties<C: Client>(
+        &mut self,
+        client: &C,
+    ) -> Result<&BTreeMap<String, u32>, ApiError<C::Error>> {
+        let mut fetches = stream::iter(self.sponsored.iter().filter_map(Legislation::bill_id))
+            .map(|id| async move {
+                let cosponsors =
+                    all_pages::<BillCosponsorsResponse, _, _, _>(client, |offset, limit| {
+                        CosponsorsBuilder::from(id)
+                            .offset(offset)
+                            .limit(limit)
+                            .build()
+                            .unwrap()
+                    })
+                    .await;
+
+                match cosponsors {
+                    (_, Some(err)) => Err(err),
+                    (cosponsors, None) => Ok(cosponsors),
+                }
+            })
+            .buffered(FETCH_CONCURRENCY);
+
+        let mut parties = BTreeMap::new();
+        while let Some(fetched) = fetches.next().await {
+            for cosponsor in fetched?.iter().filter(|c| !c.is_withdrawn()) {
+                let party = cosponsor.party.as_deref().unwrap_or("Unknown");
+                *parties.entry(party.to_string()).or_default() += 1;
+            }
+        }
+
+        Ok(self.stats.cosponsors_by_party.insert(parties))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::{
+        api::{bill::BillId, common::BillType},
+        test::MockClient,
+    };
+
+    use super::*;
+
+    const MEMBER: &str = r#"{
+        "member": {
+            "bioguideId": "L000174",
+            "directOrderName": "Patrick J. Leahy",
+            "invertedOrderName": "Leahy, Patrick J.",
+            "birthYear": "1940",
+            "currentMember": false,
+            "partyHistory": [
+                {"partyName": "Democratic", "partyAbbreviation": "D", "startYear": 1975}
+            ],
+            "terms": [
+                {"chamber": "Senate", "congress": 117, "memberType": "Senator", "startYear": 2021, "endYear": 2023, "stateCode": "VT"}
+            ],
+            "sponsoredLegislation": {"count": 4, "url": "https://api.congress.gov/v3/member/L000174/sponsored-legislation"}
+        }
+    }"#;
+
+    fn bill(congress: u16, number: u32, policy_area: &str, action: &str) -> String {
+        format!(
+            r#"{{"congress": {congress}, "type": "S", "number": "{number}", "policyArea": {{"name": "{policy_area}"}}, "latestAction": {{"actionDate": "2022-01-01", "text": "{action}"}}}}"#
+        )
+    }
+
+    fn client() -> MockClient {
+        MockClient::new()
+            .respond("member/L000174", MEMBER)
+            .respond(
+                "member/L000174/sponsored-legislation?offset=0&limit=250",
+                &format!(
+                    r#"{{"sponsoredLegislation": [{}, {}, {}], "pagination": {{"count": 4, "next": "https://api.congress.gov/v3/member/L000174/sponsored-legislation?offset=3&limit=250"}}}}"#,
+                    bill(117, 3451, "Crime and Law Enforcement", "Became Public Law No: 117-350."),
+                    bill(117, 4653, "Government Operations and Politics", "Read twice and referred to the Committee on the Judiciary."),
+                    bill(116, 1790, "Crime and Law Enforcement", "Referred to the Committee on the Judiciary."),
+                ),
+            )
+            .respond(
+                "member/L000174/sponsored-legislation?offset=3&limit=250",
+                r#"{"sponsoredLegislation": [{"congress": 117, "amendmentNumber": "5499", "introducedDate": "2022-09-29"}], "pagination": {"count": 4}}"#,
+            )
+            .respond(
+                "member/L000174/cosponsored-legislation?offset=0&limit=250",
+                &format!(
+                    r#"{{"cosponsoredLegislation": [{}], "pagination": {{"count": 1}}}}"#,
+                    bill(117, 1, "Government Operations and Politics", "Placed on calendar."),
+                ),
+            )
+    }
+
+    #[tokio::test]
+    async fn derives_stats() {
+        let bioguide = "L000174".parse().unwrap();
+        let profile = MemberProfile::fetch(&client(), &bioguide).await.unwrap();
+
+        assert_eq!(
+            profile.member.direct_order_name.as_deref(),
+            Some("Patrick J. Leahy")
+        );
+        assert_eq!(profile.member.party().unwrap().party_name, "Democratic");
+        assert_eq!(profile.sponsored.len(), 4);
+        assert_eq!(profile.cosponsored.len(), 1);
+
+        let stats = &profile.stats;
+        assert_eq!(
+            stats.bills_sponsored_by_congress,
+            BTreeMap::from([(116, 1), (117, 2)])
+        );
+        assert_eq!(stats.bills_sponsored(), 3);
+        assert_eq!(stats.amendments_sponsored, 1);
+        assert_eq!(stats.laws_enacted, 1);
+        assert_eq!(
+            stats.top_policy_areas,
+            vec![
+                ("Crime and Law Enforcement".to_string(), 2),
+                ("Government Operations and Politics".to_string(), 1),
+            ]
+        );
+        assert_eq!(stats.cosponsors_by_party, None);
+    }
+
+    #[tokio::test]
+    async fn counts_cosponsors_by_party() {
+        let cosponsor = |bioguide: &str, party: &str, withdrawn: bool| {
+            let withdrawn = if withdrawn {
+                r#", "sponsorshipWithdrawnDate": "2022-02-01""#
+            } else {
+                ""
+            };
+            format!(r#"{{"bioguideId": "{bioguide}", "party": "{party}"{withdrawn}}}"#)
+        };
+        let client = client()
+            .respond(
+                "bill/117/s/3451/cosponsors?offset=0&limit=250",
+                &format!(
+                    r#"{{"cosponsors": [{}, {}, {}], "pagination": {{"count": 3}}}}"#,
+                    cosponsor("G000359", "R", false),
+                    cosponsor("D000563", "D", false),
+                    cosponsor("C000127", "D", true),
+                ),
+            )
+            .respond(
+                "bill/117/s/4653/cosponsors?offset=0&limit=250",
+                &format!(
+                    r#"{{"cosponsors": [{}], "pagination": {{"count": 1}}}}"#,
+                    cosponsor("S000033", "I", false),
+                ),
+            )
+            .respond(
+                "bill/116/s/1790/cosponsors?offset=0&limit=250",
+                r#"{"cosponsors": [], "pagination": {"count": 0}}"#,
+            );
+
+        let bioguide = "L000174".parse().unwrap();
+        let mut profile = MemberProfile::fetch(&client, &bioguide).await.unwrap();
+        let parties = profile.fetch_cosponsor_parties(&client).await.unwrap();
+
+        assert_eq!(
+            parties,
+            &BTreeMap::from([
+                ("D".to_string(), 1),
+                ("I".to_string(), 1),
+                ("R".to_string(), 1),
+            ])
+        );
+        assert!(profile.stats.cosponsors_by_party.is_some());
+        assert_eq!(
+            profile.sponsored[0].bill_id(),
+            Some(BillId::new(117, BillType::S, 3451))
+        );
+    }
+
+    #[tokio::test]
+    async fn fails_when_a_list_fails() {
+        let client = client().respond_with(
+            "member/L000174/cosponsored-legislation?offset=0&limit=250",
+            StatusCode::INTERNAL_SERVER_ERROR,
+            r#"{"error": "internal error"}"#,
+        );
+
+        let bioguide = "L000174".parse().unwrap();
+        let err = MemberProfile::fetch(&client, &bioguide).await.unwrap_err();
+
+        assert!(matches!(err, ApiError::Http { .. }), "{err:?}");
+    }
+}
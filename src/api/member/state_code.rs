@@ -35,7 +35,7 @@ impl Endpoint for StateCode {
         let mut params = QueryParams::default();
 
         params.push("format", self.format);
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
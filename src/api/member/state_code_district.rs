@@ -4,7 +4,7 @@ use std::borrow::Cow;
 
 use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
 
-use super::CongressionalStateCode;
+use super::{CongressionalStateCode, District};
 
 /// Represents the /member/:stateCode/:district endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
@@ -13,7 +13,7 @@ pub struct StateCodeDistrict {
     #[builder(setter(into))]
     state_code: CongressionalStateCode,
     #[builder(setter(into))]
-    district: u16,
+    district: District,
     #[builder(default)]
     format: Format,
     #[builder(default)]
@@ -32,14 +32,19 @@ impl Endpoint for StateCodeDistrict {
     }
 
     fn endpoint(&self) -> Cow<'static, str> {
-        format!("member/{}/{}", self.state_code.as_str(), self.district).into()
+        format!(
+            "member/{}/{}",
+            self.state_code.as_str(),
+            self.district.number()
+        )
+        .into()
     }
 
     fn parameters(&self) -> QueryParams {
         let mut params = QueryParams::default();
 
         params.push("format", self.format);
-        params.push_opt("current_member", self.current_member);
+        params.push_opt("currentMember", self.current_member);
 
         params
     }
@@ -0,0 +1,353 @@
+//! Typed response models for the Nomination endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::{
+        models::{CommitteeRef, CommitteeReferral},
+        CommitteeChamber,
+    },
+    common::{LatestAction, Pagination, ResourceCount},
+    de,
+    nomination::NominationId,
+    paged::impl_page,
+};
+
+/// Whether a nomination is for a civilian or military position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationType {
+    pub is_civilian: Option<bool>,
+    pub is_military: Option<bool>,
+}
+
+impl NominationType {
+    /// Whether the nomination is for a military position.
+    ///
+    /// The API usually only sets the flag that applies, so a nomination is
+    /// considered military if `isMilitary` is true or `isCivilian` is false.
+    pub fn military(&self) -> bool {
+        self.is_military
+            .unwrap_or_else(|| self.is_civilian.is_some_and(|civilian| !civilian))
+    }
+
+    /// Whether the nomination is for a civilian position.
+    pub fn civilian(&self) -> bool {
+        !self.military()
+    }
+}
+
+/// A position to which nominees have been nominated.
+///
+/// A nomination may cover several positions, each identified by its ordinal.
+/// The individual nominees are available from the
+/// /nomination/:congress/:nominationNumber/:ordinal endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationPosition {
+    #[serde(deserialize_with = "de::number")]
+    pub ordinal: u32,
+    pub organization: Option<String>,
+    pub position_title: Option<String>,
+    pub intro_text: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub nominee_count: Option<u32>,
+    pub url: Option<String>,
+}
+
+/// A nomination, as returned by both the list and detail endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Nomination {
+    pub citation: String,
+    pub congress: u16,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    /// The partition of a nomination that has been split, "00" if unsplit.
+    pub part_number: Option<String>,
+    pub description: Option<String>,
+    pub organization: Option<String>,
+    pub is_list: Option<bool>,
+    pub is_privileged: Option<bool>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub executive_calendar_number: Option<u32>,
+    #[serde(default)]
+    pub nominees: Vec<NominationPosition>,
+    pub nomination_type: Option<NominationType>,
+    pub latest_action: Option<LatestAction>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub received_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub authority_date: Option<NaiveDate>,
+    pub actions: Option<ResourceCount>,
+    pub committees: Option<ResourceCount>,
+    pub hearings: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl Nomination {
+    /// Whether the nomination is for a military position.
+    pub fn is_military(&self) -> bool {
+        self.nomination_type.is_some_and(|t| t.military())
+    }
+
+    /// The identifier of the nomination, used to query its other endpoints.
+    pub fn id(&self) -> Option<NominationId> {
+        NominationId::parse_with_congress(&self.citation, self.congress).ok()
+    }
+
+    /// The ordinals of the positions covered by this nomination.
+    pub fn ordinals(&self) -> impl Iterator<Item = u32> + '_ {
+        self.nominees.iter().map(|n| n.ordinal)
+    }
+}
+
+/// An individual nominee for a position.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Nominee {
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub ordinal: Option<u32>,
+    pub first_name: Option<String>,
+    pub middle_name: Option<String>,
+    pub last_name: Option<String>,
+    pub suffix: Option<String>,
+    pub prefix: Option<String>,
+    pub state: Option<String>,
+    pub predecessor_name: Option<String>,
+    pub corps_code: Option<String>,
+}
+
+/// An action taken on a nomination.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationAction {
+    pub action_code: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub action_date: Option<NaiveDate>,
+    #[serde(rename = "type")]
+    pub action_type: Option<String>,
+    pub text: Option<String>,
+    #[serde(default)]
+    pub committees: Vec<CommitteeRef>,
+}
+
+/// A printed hearing associated with a nomination.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationHearing {
+    pub citation: Option<String>,
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub jacket_number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub part_number: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub errata_number: Option<u32>,
+}
+
+/// The response of the /nomination and /nomination/:congress endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationsResponse {
+    #[serde(default)]
+    pub nominations: Vec<Nomination>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /nomination/:congress/:nominationNumber endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationResponse {
+    pub nomination: Nomination,
+}
+
+/// The response of the /nomination/:congress/:nominationNumber/:ordinal
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NomineesResponse {
+    #[serde(default)]
+    pub nominees: Vec<Nominee>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /nomination/:congress/:nominationNumber/actions
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationActionsResponse {
+    #[serde(default)]
+    pub actions: Vec<NominationAction>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /nomination/:congress/:nominationNumber/committees
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationCommitteesResponse {
+    #[serde(default)]
+    pub committees: Vec<CommitteeReferral>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /nomination/:congress/:nominationNumber/hearings
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct NominationHearingsResponse {
+    #[serde(default)]
+    pub hearings: Vec<NominationHearing>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl_page!(NominationsResponse => nominations: Nomination);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nomination_detail() {
+        let json = r#"{
+            "nomination": {
+                "actions": {"count": 1, "url": "https://api.congress.gov/v3/nomination/117/2467/actions?format=json"},
+                "citation": "PN2467",
+                "committees": {"count": 1, "url": "https://api.congress.gov/v3/nomination/117/2467/committees?format=json"},
+                "congress": 117,
+                "description": " ",
+                "isList": true,
+                "latestAction": {"actionDate": "2022-08-03", "text": "Received in the Senate and referred to the Committee on Armed Services."},
+                "nominationType": {"isCivilian": false, "isMilitary": true},
+                "nominees": [
+                    {
+                        "introText": "THE FOLLOWING NAMED OFFICERS FOR APPOINTMENT TO THE GRADE INDICATED IN THE REGULAR AIR FORCE UNDER TITLE 10, U.S.C., SECTIONS 531 AND 716:",
+                        "nomineeCount": 12,
+                        "ordinal": 1,
+                        "organization": "Air Force",
+                        "positionTitle": "Colonel",
+                        "url": "https://api.congress.gov/v3/nomination/117/2467/1?format=json"
+                    }
+                ],
+                "number": 2467,
+                "partNumber": "00",
+                "receivedDate": "2022-08-03",
+                "updateDate": "2022-08-04 04:25:12+00:00"
+            },
+            "request": {"congress": "117", "contentType": "application/json", "format": "json"}
+        }"#;
+
+        let res: NominationResponse = serde_json::from_str(json).unwrap();
+        let nomination = res.nomination;
+
+        assert_eq!(nomination.citation, "PN2467");
+        assert_eq!(nomination.id(), Some(NominationId::new(117, 2467)));
+        assert!(nomination.is_military());
+        assert_eq!(nomination.ordinals().collect::<Vec<_>>(), [1]);
+
+        let position = &nomination.nominees[0];
+        assert_eq!(position.organization.as_deref(), Some("Air Force"));
+        assert_eq!(position.position_title.as_deref(), Some("Colonel"));
+        assert_eq!(position.nominee_count, Some(12));
+        assert_eq!(
+            nomination.latest_action.unwrap().action_date,
+            NaiveDate::from_ymd_opt(2022, 8, 3)
+        );
+    }
+
+    #[test]
+    fn nomination_list() {
+        let json = r#"{
+            "nominations": [
+                {
+                    "citation": "PN2804",
+                    "congress": 117,
+                    "description": "Julia Frifield, of the District of Columbia, to be an Assistant Secretary of State (Legislative Affairs).",
+                    "latestAction": {"actionDate": "2022-11-17", "text": "Received in the Senate and referred to the Committee on Foreign Relations."},
+                    "nominationType": {"isCivilian": true},
+                    "number": 2804,
+                    "organization": "Department of State",
+                    "partNumber": "00",
+                    "receivedDate": "2022-11-17",
+                    "updateDate": "2022-11-18T05:20:27Z",
+                    "url": "https://api.congress.gov/v3/nomination/117/2804?format=json"
+                }
+            ],
+            "pagination": {"count": 2, "next": null}
+        }"#;
+
+        let res: NominationsResponse = serde_json::from_str(json).unwrap();
+        let nomination = &res.nominations[0];
+        assert!(!nomination.is_military());
+        assert!(nomination.nomination_type.unwrap().civilian());
+        assert!(nomination.nominees.is_empty());
+    }
+
+    #[test]
+    fn nominees() {
+        let json = r#"{
+            "nominees": [
+                {"firstName": "JOHN", "lastName": "SMITH", "middleName": "D.", "ordinal": 1},
+                {"firstName": "Jane", "lastName": "Doe", "ordinal": 2, "predecessorName": "Richard Roe", "state": "NY"}
+            ],
+            "pagination": {"count": 12}
+        }"#;
+
+        let res: NomineesResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.nominees.len(), 2);
+        assert_eq!(
+            res.nominees[1].predecessor_name.as_deref(),
+            Some("Richard Roe")
+        );
+    }
+
+    #[test]
+    fn nomination_sub_resources() {
+        let actions: NominationActionsResponse = serde_json::from_str(
+            r#"{"actions": [{"actionCode": "S05120", "actionDate": "2022-08-03", "committees": [{"name": "Armed Services Committee", "systemCode": "ssas00", "url": "https://api.congress.gov/v3/committee/senate/ssas00?format=json"}], "text": "Received in the Senate and referred to the Committee on Armed Services.", "type": "IntroReferral"}], "pagination": {"count": 1}}"#,
+        )
+        .unwrap();
+        let action = &actions.actions[0];
+        assert_eq!(action.action_type.as_deref(), Some("IntroReferral"));
+        assert_eq!(action.committees[0].system_code, "ssas00");
+
+        let committees: NominationCommitteesResponse = serde_json::from_str(
+            r#"{"committees": [{"activities": [{"date": "2022-08-03T21:52:26Z", "name": "Referred to"}], "chamber": "Senate", "name": "Armed Services Committee", "systemCode": "ssas00", "type": "Standing", "url": "https://api.congress.gov/v3/committee/senate/ssas00?format=json"}], "pagination": {"count": 1}}"#,
+        )
+        .unwrap();
+        let committee = &committees.committees[0];
+        assert_eq!(committee.chamber, Some(CommitteeChamber::Senate));
+        assert_eq!(committee.activities[0].name, "Referred to");
+
+        let hearings: NominationHearingsResponse = serde_json::from_str(
+            r#"{"hearings": [{"chamber": "Senate", "citation": "S.Hrg. 116-68", "date": "2019-05-23", "errataNumber": null, "jacketNumber": 37106, "partNumber": 1}], "pagination": {"count": 1}}"#,
+        )
+        .unwrap();
+        let hearing = &hearings.hearings[0];
+        assert_eq!(hearing.jacket_number, Some(37106));
+        assert_eq!(hearing.errata_number, None);
+    }
+}
@@ -0,0 +1,248 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::api::{
+    calendar::Congress,
+    citation::{self, Citation, Ordinal, ParseCitationError},
+    nomination::{
+        ActionsBuilder, CommitteesBuilder, HearingsBuilder, NominationNumberBuilder, OrdinalBuilder,
+    },
+};
+
+/// Identifies a nomination by its congress, number and, for a nomination
+/// that has been split, its partition.
+///
+/// A `NominationId` can be parsed from a citation such as "PN2467",
+/// "PN2467-1" or "PN 78-2" followed by its congress, and is displayed in the
+/// congress.gov style, e.g. "PN2467-1 — 117th Congress". It converts into the
+/// builder of every endpoint under /nomination/:congress/:nominationNumber.
+/// The API has no path for a partition, so the conversions drop it and the
+/// endpoints describe the whole nomination, every partition included.
+/// The nominee ordinal of the [`Ordinal`](crate::api::nomination::Ordinal)
+/// endpoint is not part of a citation and still has to be set.
+///
+/// ```rust
+/// use congressdotgov_rs::api::nomination::{self, NominationId};
+///
+/// let id: NominationId = "PN 78-2 (117th Congress)".parse().unwrap();
+/// assert_eq!(id, NominationId::new(117, 78).with_partition(2));
+/// assert_eq!(id.to_string(), "PN78-2 — 117th Congress");
+///
+/// let nominees = nomination::OrdinalBuilder::from(id).ordinal(1_u32).build().unwrap();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NominationId {
+    pub congress: u16,
+    pub number: u32,
+    pub partition: Option<u32>,
+}
+
+impl NominationId {
+    pub fn new(congress: u16, number: u32) -> Self {
+        Self {
+            congress,
+            number,
+            partition: None,
+        }
+    }
+
+    /// The same nomination with the given partition.
+    pub fn with_partition(self, partition: u32) -> Self {
+        Self {
+            partition: Some(partition),
+            ..self
+        }
+    }
+
+    /// Parses a citation, using `congress` when the citation does not name
+    /// one, e.g. "PN2467".
+    pub fn parse_with_congress(s: &str, congress: u16) -> Result<Self, ParseCitationError> {
+        Self::parse(s, Some(congress))
+    }
+
+    /// The citation without its congress, e.g. "PN2467-1".
+    pub fn citation(&self) -> String {
+        match self.partition {
+            Some(partition) => format!("PN{}-{}", self.number, partition),
+            None => format!("PN{}", self.number),
+        }
+    }
+
+    fn parse(s: &str, default_congress: Option<u16>) -> Result<Self, ParseCitationError> {
+        const KIND: &str = "nomination";
+
+        let invalid = || ParseCitationError::unrecognized(KIND, s);
+        let citation = Citation::split(s).ok_or_else(invalid)?;
+        if citation.prefix != "pn" {
+            return Err(invalid());
+        }
+
+        let (number, partition) = citation.numbers();
+        let number = citation::number(number).ok_or_else(invalid)?;
+        let partition = match partition {
+            Some(partition) => Some(
+                citation::number(partition)
+                    .filter(|&partition| partition > 0)
+                    .ok_or_else(invalid)?,
+            ),
+            None => None,
+        };
+        let congress = match citation.congress {
+            Some(congress) => Congress::new(congress)
+                .map(u16::from)
+                .map_err(|_| ParseCitationError::invalid_congress(KIND, s, congress))?,
+            None => {
+                default_congress.ok_or_else(|| ParseCitationError::missing_congress(KIND, s))?
+            }
+        };
+
+        Ok(Self {
+            congress,
+            number,
+            partition,
+        })
+    }
+}
+
+impl FromStr for NominationId {
+    type Err = ParseCitationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+impl fmt::Display for NominationId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} — {} Congress",
+            self.citation(),
+            Ordinal(self.congress.into())
+        )
+    }
+}
+
+macro_rules! impl_builder_from_nomination_id {
+    ($($builder:ident),* $(,)?) => {
+        $(
+            /// Drops the partition, which the endpoint cannot address.
+            impl From<NominationId> for $builder {
+                fn from(id: NominationId) -> Self {
+                    let mut builder = Self::default();
+                    builder
+                        .congress(id.congress)
+                        .nomination_number(id.number);
+                    builder
+                }
+            }
+        )*
+    };
+}
+
+impl_builder_from_nomination_id!(
+    ActionsBuilder,
+    CommitteesBuilder,
+    HearingsBuilder,
+    NominationNumberBuilder,
+    OrdinalBuilder,
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::{endpoint::Endpoint, nomination::NominationNumber};
+
+    use super::*;
+
+    #[test]
+    fn parses_citations() {
+        for (citation, id) in [
+            ("PN2467 (117th Congress)", NominationId::new(117, 2467)),
+            (
+                "PN2467-1, 117th Congress",
+                NominationId::new(117, 2467).with_partition(1),
+            ),
+            (
+                "PN 78-2 (117th Congress)",
+                NominationId::new(117, 78).with_partition(2),
+            ),
+            ("pn12 116th Congress", NominationId::new(116, 12)),
+        ] {
+            assert_eq!(citation.parse::<NominationId>(), Ok(id), "{citation}");
+        }
+
+        assert_eq!(
+            NominationId::parse_with_congress("PN2467", 117),
+            Ok(NominationId::new(117, 2467))
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        for id in [
+            NominationId::new(117, 2467),
+            NominationId::new(116, 1059).with_partition(3),
+        ] {
+            assert_eq!(id.to_string().parse::<NominationId>(), Ok(id));
+            assert_eq!(
+                NominationId::parse_with_congress(&id.citation(), id.congress),
+                Ok(id)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_citations() {
+        assert!(matches!(
+            "PN2467".parse::<NominationId>(),
+            Err(ParseCitationError::MissingCongress { .. })
+        ));
+        for citation in [
+            "",
+            "PN",
+            "PN2467-",
+            "PN2467-0",
+            "PT2467",
+            "PN24x (117th Congress)",
+        ] {
+            assert!(
+                NominationId::parse_with_congress(citation, 117).is_err(),
+                "{citation}"
+            );
+        }
+    }
+
+    #[test]
+    fn builds_endpoints() {
+        let id: NominationId = "PN2467-1 (117th Congress)".parse().unwrap();
+
+        assert_eq!(NominationNumber::from(id).endpoint(), "nomination/117/2467");
+        assert_eq!(
+            HearingsBuilder::from(id).build().unwrap().endpoint(),
+            "nomination/117/2467/hearings"
+        );
+        assert_eq!(
+            OrdinalBuilder::from(id)
+                .ordinal(1_u32)
+                .build()
+                .unwrap()
+                .endpoint(),
+            "nomination/117/2467/1"
+        );
+    }
+
+    #[test]
+    fn drops_partition_in_endpoints() {
+        let whole = NominationId::new(117, 2467);
+        let partition = whole.with_partition(1);
+
+        assert_eq!(
+            NominationNumber::from(partition).endpoint(),
+            NominationNumber::from(whole).endpoint()
+        );
+        assert_eq!(
+            ActionsBuilder::from(partition).build().unwrap().endpoint(),
+            ActionsBuilder::from(whole).build().unwrap().endpoint()
+        );
+    }
+}
@@ -2,14 +2,17 @@ use derive_builder::Builder;
 use http::Method;
 use std::borrow::Cow;
 
-use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
+use crate::{
+    api::common::Format, api::endpoint::Endpoint, api::nomination::NominationId,
+    api::params::QueryParams,
+};
 
 /// Represents the /nomination/:congress/:nominationNumber endpoint.
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct NominationNumber {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     nomination_number: u32,
     #[builder(default)]
@@ -22,6 +25,18 @@ impl NominationNumber {
     }
 }
 
+/// Drops the partition of `id`: the detail of a partitioned nomination lists
+/// all of its partitions.
+impl From<NominationId> for NominationNumber {
+    fn from(id: NominationId) -> Self {
+        Self {
+            congress: id.congress,
+            nomination_number: id.number,
+            format: Format::default(),
+        }
+    }
+}
+
 impl Endpoint for NominationNumber {
     fn method(&self) -> Method {
         Method::GET
@@ -9,7 +9,7 @@ use crate::{api::endpoint::Endpoint, api::params::QueryParams, api::common::Form
 #[builder(setter(strip_option))]
 pub struct Ordinal {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     nomination_number: u32,
     #[builder(setter(into))]
@@ -0,0 +1,68 @@
+//! Helpers for exhausting paginated endpoints.
+
+use serde::de::DeserializeOwned;
+
+use crate::api::{
+    client::Client, common::Pagination, endpoint::Endpoint, error::ApiError, query::Query,
+};
+
+/// The largest page size the API accepts.
+pub(crate) const MAX_LIMIT: u8 = 250;
+
+/// A paginated response which can be split into its items and pagination.
+pub trait Page: DeserializeOwned {
+    type Item;
+
+    fn into_page(self) -> (Vec<Self::Item>, Pagination);
+}
+
+/// Implements `Page` for responses holding their items in a single field.
+macro_rules! impl_page {
+    ($($response:ty => $field:ident: $item:ty),* $(,)?) => {
+        $(
+            impl $crate::api::paged::Page for $response {
+                type Item = $item;
+
+                fn into_page(self) -> (Vec<$item>, $crate::api::common::Pagination) {
+                    (self.$field, self.pagination)
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use impl_page;
+
+/// Fetches every page of an endpoint, building the endpoint for each page from
+/// its offset and limit.
+///
+/// Pages are requested until one comes back empty or the last page is
+/// reached. On error the items fetched so far are returned along with it.
+pub(crate) async fn all_pages<P, E, C, F>(
+    client: &C,
+    endpoint: F,
+) -> (Vec<P::Item>, Option<ApiError<C::Error>>)
+where
+    P: Page,
+    E: Endpoint,
+    C: Client,
+    F: Fn(u32, u8) -> E,
+{
+    let mut items = Vec::new();
+    loop {
+        let offset = u32::try_from(items.len()).unwrap_or(u32::MAX);
+        let page: P = match endpoint(offset, MAX_LIMIT).query(client).await {
+            Ok(page) => page,
+            Err(err) => return (items, Some(err)),
+        };
+
+        let (page_items, pagination) = page.into_page();
+        let fetched = page_items.len();
+        items.extend(page_items);
+
+        let exhausted = pagination.next.is_none() && offset + fetched as u32 >= pagination.count;
+        if fetched == 0 || exhausted {
+            return (items, None);
+        }
+    }
+}
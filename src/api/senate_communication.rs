@@ -2,11 +2,15 @@
 
 //! Senate-communication API endpoints and types.
 
+use crate::api::common::impl_api_enum;
+
 mod communication_number;
 mod communication_type;
 mod congress;
 mod senate_communication;
 
+pub mod models;
+
 pub use self::communication_number::{
     CommunicationNumber, CommunicationNumberBuilder, CommunicationNumberBuilderError,
 };
@@ -19,7 +23,7 @@ pub use self::senate_communication::{
 };
 
 /// The different Senate Communication Types.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SenateCommunicationType {
     /// Executive Communications
     Ec,
@@ -30,7 +34,7 @@ pub enum SenateCommunicationType {
 }
 
 impl SenateCommunicationType {
-    fn as_str(self) -> &'static str {
+    pub fn as_str(self) -> &'static str {
         match self {
             SenateCommunicationType::Ec => "ec",
             SenateCommunicationType::Pm => "pm",
@@ -38,3 +42,19 @@ impl SenateCommunicationType {
         }
     }
 }
+
+impl_api_enum!(
+    SenateCommunicationType,
+    "Senate communication type",
+    [Ec, Pm, Pom],
+    json {
+        Ec => "EC",
+        Pm => "PM",
+        Pom => "POM",
+    },
+    labels {
+        Ec => ["Executive Communication"],
+        Pm => ["Presidential Message"],
+        Pom => ["Petition or Memorial"],
+    }
+);
@@ -10,8 +10,8 @@ use super::SenateCommunicationType;
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct CommunicationNumber {
-    #[builder(default)]
-    congress: u8,
+    #[builder(default, setter(into))]
+    congress: u16,
     #[builder(setter(into))]
     communication_type: SenateCommunicationType,
     #[builder(setter(into))]
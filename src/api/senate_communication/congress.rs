@@ -8,8 +8,8 @@ use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryPara
 #[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
 pub struct Congress {
-    #[builder(default)]
-    congress: u8,
+    #[builder(default, setter(into))]
+    congress: u16,
     #[builder(default)]
     format: Format,
     #[builder(default)]
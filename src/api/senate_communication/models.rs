@@ -0,0 +1,110 @@
+//! Typed response models for the Senate Communication endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::models::CommunicationReferral,
+    common::{CommitteeChamber, CommunicationTypeCode, Pagination},
+    de,
+};
+
+/// A Senate communication, as returned by both the list and detail endpoints.
+///
+/// The list endpoints only return the identifying fields; the remaining
+/// fields are filled by the
+/// /senate-communication/:congress/:communicationType/:communicationNumber
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SenateCommunication {
+    #[serde(alias = "congressNumber")]
+    pub congress: u16,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub communication_type: CommunicationTypeCode,
+    pub chamber: Option<CommitteeChamber>,
+    #[serde(rename = "abstract", default, deserialize_with = "de::opt_string")]
+    pub abstract_text: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub congressional_record_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub session_number: Option<u32>,
+    #[serde(default)]
+    pub committees: Vec<CommunicationReferral>,
+    /// Only set when listed through a committee's communications.
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub referral_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+/// The response of the /senate-communication,
+/// /senate-communication/:congress and
+/// /senate-communication/:congress/:communicationType endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SenateCommunicationsResponse {
+    #[serde(default)]
+    pub senate_communications: Vec<SenateCommunication>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the
+/// /senate-communication/:congress/:communicationType/:communicationNumber
+/// endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SenateCommunicationResponse {
+    #[serde(alias = "senate-communication")]
+    pub senate_communication: SenateCommunication,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn senate_communication_detail() {
+        let json = r#"{
+            "request": {"communicationType": "ec", "congress": "117", "contentType": "application/json", "format": "json", "number": "2561"},
+            "senateCommunication": {
+                "abstract": "A communication from the Board Chairman and Chief Executive Officer, Farm Credit Administration, transmitting, pursuant to law, the Administration's annual report for calendar year 2021; to the Committee on Banking, Housing, and Urban Affairs.",
+                "chamber": "Senate",
+                "committees": [{"name": "Banking, Housing, and Urban Affairs Committee", "referralDate": "2021-11-03", "systemCode": "ssbk00", "url": "https://api.congress.gov/v3/committee/senate/ssbk00?format=json"}],
+                "communicationType": {"code": "EC", "name": "Executive Communication"},
+                "congress": 117,
+                "congressionalRecordDate": "2021-11-03",
+                "number": 2561,
+                "sessionNumber": 1,
+                "updateDate": "2021-11-04T07:15:16Z"
+            }
+        }"#;
+
+        let res: SenateCommunicationResponse = serde_json::from_str(json).unwrap();
+        let communication = res.senate_communication;
+
+        assert_eq!(communication.number, 2561);
+        assert_eq!(communication.chamber, Some(CommitteeChamber::Senate));
+        assert_eq!(communication.session_number, Some(1));
+        assert_eq!(
+            communication.congressional_record_date,
+            NaiveDate::from_ymd_opt(2021, 11, 3)
+        );
+        assert_eq!(communication.committees[0].system_code, "ssbk00");
+    }
+
+    #[test]
+    fn senate_communication_list() {
+        let json = r#"{"pagination": {"count": 1}, "senateCommunications": [{"chamber": "Senate", "communicationType": {"code": "EC", "name": "Executive Communication"}, "congress": 117, "number": 2561, "updateDate": "2021-11-04T07:15:16Z", "url": "https://api.congress.gov/v3/senate-communication/117/ec/2561?format=json"}]}"#;
+
+        let res: SenateCommunicationsResponse = serde_json::from_str(json).unwrap();
+        assert_eq!(res.senate_communications[0].communication_type.code, "EC");
+        assert!(res.senate_communications[0].abstract_text.is_none());
+    }
+}
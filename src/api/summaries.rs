@@ -6,6 +6,8 @@ mod bill_type;
 mod congress;
 mod summaries;
 
+pub mod models;
+
 pub use self::bill_type::{BillType, BillTypeBuilder, BillTypeBuilderError};
 pub use self::congress::{Congress, CongressBuilder, CongressBuilderError};
 pub use self::summaries::{Summaries, SummariesBuilder, SummariesBuilderError};
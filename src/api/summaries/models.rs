@@ -0,0 +1,96 @@
+//! Typed response models for the Summaries endpoints.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    bill::BillId,
+    common::{BillType, CommitteeChamber, Pagination},
+    de,
+    paged::impl_page,
+};
+
+/// The bill a summary was written for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SummaryBill {
+    pub congress: u16,
+    #[serde(rename = "type")]
+    pub bill_type: BillType,
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    pub title: Option<String>,
+    pub origin_chamber: Option<CommitteeChamber>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date_including_text: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl SummaryBill {
+    /// The identifier of the bill.
+    pub fn id(&self) -> BillId {
+        BillId::new(self.congress, self.bill_type, self.number)
+    }
+}
+
+/// A summary of a bill, as returned by the /summaries endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Summary {
+    pub bill: SummaryBill,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub action_date: Option<NaiveDate>,
+    pub action_desc: Option<String>,
+    pub current_chamber: Option<CommitteeChamber>,
+    /// The summary as HTML.
+    pub text: Option<String>,
+    pub version_code: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub last_summary_update_date: Option<DateTime<Utc>>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+}
+
+/// The response of the /summaries, /summaries/:congress and
+/// /summaries/:congress/:billType endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct SummariesResponse {
+    #[serde(default)]
+    pub summaries: Vec<Summary>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl_page!(SummariesResponse => summaries: Summary);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summaries() {
+        let json = r#"{
+            "summaries": [{
+                "actionDate": "2022-03-08",
+                "actionDesc": "Passed Senate",
+                "bill": {"congress": 117, "number": "3076", "originChamber": "House", "originChamberCode": "H", "title": "Postal Service Reform Act of 2022", "type": "HR", "updateDateIncludingText": "2022-09-29T03:27:05Z"},
+                "currentChamber": "Senate",
+                "currentChamberCode": "S",
+                "lastSummaryUpdateDate": "2022-03-31T15:20:50Z",
+                "text": "<p><strong>Postal Service Reform Act of 2022</strong></p>",
+                "updateDate": "2022-03-14T18:17:02Z",
+                "versionCode": "55"
+            }]
+        }"#;
+
+        let res: SummariesResponse = serde_json::from_str(json).unwrap();
+        let summary = &res.summaries[0];
+        assert_eq!(summary.bill.id().to_string(), "H.R. 3076 (117th Congress)");
+        assert_eq!(summary.current_chamber, Some(CommitteeChamber::Senate));
+        assert_eq!(summary.version_code.as_deref(), Some("55"));
+    }
+}
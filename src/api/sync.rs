@@ -0,0 +1,609 @@
+//! Incremental synchronisation of resources through their update date filters.
+//!
+//! The list endpoints of most resources accept `fromDateTime` and
+//! `toDateTime` filters on the date an item was last updated. A [`Syncer`]
+//! uses them to fetch only what changed since its last run: it keeps the
+//! latest update date seen for each resource, its high-water mark, in a
+//! [`CheckpointStore`], and resumes from there on the next run.
+//!
+//! Updates are not always visible in the API the moment they are stamped, so
+//! each run starts a little before the high-water mark. Items seen again in
+//! that overlap are recognised from the checkpoint and not reported twice.
+//!
+//! ```no_run
+//! # async fn example(client: &congressdotgov_rs::Cdg) -> Result<(), Box<dyn std::error::Error>> {
+//! use chrono::{TimeZone, Utc};
+//! use congressdotgov_rs::api::bill;
+//! use congressdotgov_rs::api::sync::{FileCheckpointStore, Syncer};
+//!
+//! let since = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+//! let syncer = Syncer::new(FileCheckpointStore::new("checkpoints"), since);
+//!
+//! let changes = syncer.pull::<bill::Bill, _>(client).await?;
+//! for event in &changes.events {
+//!     println!("{} updated at {:?}", event.key, event.update_date);
+//! }
+//! syncer.commit(&changes)?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use chrono::{DateTime, TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::api::{
+    amendments,
+    bill::{self, models::Bill},
+    client::Client,
+    committee::{self, models::Committee},
+    common::Sort,
+    endpoint::Endpoint,
+    error::ApiError,
+    member::{self, models::Member},
+    nomination::{self, models::Nomination},
+    paged::{all_pages, Page},
+    summaries::{self, models::Summary},
+    treaty::{self, models::Treaty},
+};
+
+/// How far before the high-water mark each run starts by default.
+pub const DEFAULT_OVERLAP: TimeDelta = TimeDelta::hours(1);
+
+/// An item which can be tracked across synchronisation runs.
+pub trait SyncItem {
+    /// A key identifying the item among those of its resource.
+    fn sync_key(&self) -> String;
+
+    /// The date the item was last updated, if the API reports it.
+    fn sync_update_date(&self) -> Option<DateTime<Utc>>;
+}
+
+/// A list endpoint which can be filtered by update date.
+pub trait SyncResource: Endpoint {
+    /// The name the resource's checkpoint is stored under.
+    const NAME: &'static str;
+
+    /// The item listed by the endpoint.
+    type Item: SyncItem;
+
+    /// A page of the endpoint's response.
+    type Response: Page<Item = Self::Item>;
+
+    /// The endpoint listing the items updated between `from` and `to`, oldest
+    /// first where the endpoint can sort.
+    fn changes(from: DateTime<Utc>, to: DateTime<Utc>, offset: u32, limit: u8) -> Self;
+}
+
+macro_rules! impl_sync_resource {
+    ($($endpoint:ty => $name:literal, $response:ty $(, sort = $sort:expr)?;)*) => {
+        $(
+            impl SyncResource for $endpoint {
+                const NAME: &'static str = $name;
+
+                type Item = <$response as Page>::Item;
+
+                type Response = $response;
+
+                fn changes(from: DateTime<Utc>, to: DateTime<Utc>, offset: u32, limit: u8) -> Self {
+                    Self::builder()
+                        .from_date_time(from)
+                        .to_date_time(to)
+                        .offset(offset)
+                        .limit(limit)
+                        $(.sort($sort))?
+                        .build()
+                        .unwrap()
+                }
+            }
+        )*
+    };
+}
+
+impl_sync_resource! {
+    amendments::Amendment => "amendment", amendments::models::AmendmentsResponse;
+    bill::Bill => "bill", bill::models::BillsResponse, sort = Sort::Asc;
+    committee::Committee => "committee", committee::models::CommitteesResponse;
+    member::Member => "member", member::models::MembersResponse;
+    nomination::Nomination => "nomination", nomination::models::NominationsResponse;
+    summaries::Summaries => "summaries", summaries::models::SummariesResponse, sort = Sort::Asc;
+    treaty::Treaty => "treaty", treaty::models::TreatiesResponse;
+}
+
+impl SyncItem for amendments::models::Amendment {
+    fn sync_key(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            self.congress,
+            self.amendment_type.as_str(),
+            self.number
+        )
+    }
+
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+impl SyncItem for Bill {
+    fn sync_key(&self) -> String {
+        format!(
+            "{}/{}/{}",
+            self.congress,
+            self.bill_type.as_str(),
+            self.number
+        )
+    }
+
+    // The list filters on `updateDate`, so the cursor must follow it rather
+    // than `updateDateIncludingText`.
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+impl SyncItem for Committee {
+    fn sync_key(&self) -> String {
+        self.system_code.clone()
+    }
+
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+impl SyncItem for Member {
+    fn sync_key(&self) -> String {
+        self.bioguide_id.clone()
+    }
+
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+impl SyncItem for Nomination {
+    fn sync_key(&self) -> String {
+        format!("{}/{}", self.congress, self.citation)
+    }
+
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+impl SyncItem for Summary {
+    fn sync_key(&self) -> String {
+        format!(
+            "{}/{}/{}/{}",
+            self.bill.congress,
+            self.bill.bill_type.as_str(),
+            self.bill.number,
+            self.version_code.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+impl SyncItem for Treaty {
+    fn sync_key(&self) -> String {
+        format!(
+            "{}/{}{}",
+            self.congress_received.unwrap_or_default(),
+            self.number,
+            self.suffix.as_deref().unwrap_or_default()
+        )
+    }
+
+    fn sync_update_date(&self) -> Option<DateTime<Utc>> {
+        self.update_date
+    }
+}
+
+/// The progress of synchronising a resource.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Checkpoint {
+    /// The latest update date seen.
+    pub high_water: DateTime<Utc>,
+    /// The items updated in the overlap before the high-water mark, with
+    /// their update dates, so they are not reported again.
+    #[serde(default)]
+    pub recent: BTreeMap<String, DateTime<Utc>>,
+}
+
+/// Errors which can occur when loading or saving a checkpoint.
+#[derive(Debug, Error)]
+pub enum CheckpointError {
+    #[error("failed to access checkpoint: {}", source)]
+    Io {
+        #[from]
+        source: io::Error,
+    },
+    #[error("invalid checkpoint: {}", source)]
+    Json {
+        #[from]
+        source: serde_json::Error,
+    },
+    #[error("checkpoint store error: {}", source)]
+    Store {
+        source: Box<dyn Error + Send + Sync>,
+    },
+}
+
+/// Where checkpoints are persisted between runs.
+pub trait CheckpointStore {
+    /// Loads the checkpoint of a resource, if it has one.
+    fn load(&self, resource: &str) -> Result<Option<Checkpoint>, CheckpointError>;
+
+    /// Saves the checkpoint of a resource, replacing any previous one.
+    fn save(&self, resource: &str, checkpoint: &Checkpoint) -> Result<(), CheckpointError>;
+}
+
+/// A checkpoint store kept in memory, for the lifetime of the process.
+#[derive(Debug, Default)]
+pub struct MemoryCheckpointStore {
+    checkpoints: Mutex<HashMap<String, Checkpoint>>,
+}
+
+impl MemoryCheckpointStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl CheckpointStore for MemoryCheckpointStore {
+    fn load(&self, resource: &str) -> Result<Option<Checkpoint>, CheckpointError> {
+        Ok(self.checkpoints.lock().unwrap().get(resource).cloned())
+    }
+
+    fn save(&self, resource: &str, checkpoint: &Checkpoint) -> Result<(), CheckpointError> {
+        self.checkpoints
+            .lock()
+            .unwrap()
+            .insert(resource.to_string(), checkpoint.clone());
+        Ok(())
+    }
+}
+
+/// A checkpoint store keeping one JSON file per resource in a directory.
+///
+/// The directory is created when the first checkpoint is saved. Files are
+/// replaced atomically, so an interrupted save leaves the previous
+/// checkpoint intact.
+#[derive(Debug, Clone)]
+pub struct FileCheckpointStore {
+    dir: PathBuf,
+}
+
+impl FileCheckpointStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The directory the checkpoints are kept in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, resource: &str) -> PathBuf {
+        self.dir.join(format!("{resource}.json"))
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn load(&self, resource: &str) -> Result<Option<Checkpoint>, CheckpointError> {
+        match fs::read(self.path(resource)) {
+            Ok(bytes) => Ok(Some(serde_json::from_slice(&bytes)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    fn save(&self, resource: &str, checkpoint: &Checkpoint) -> Result<(), CheckpointError> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(resource);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_vec_pretty(checkpoint)?)?;
+        fs::rename(&tmp, &path)?;
+        Ok(())
+    }
+}
+
+/// Errors which can occur when synchronising a resource.
+#[derive(Debug, Error)]
+pub enum SyncError<E>
+where
+    E: Error + Send + Sync + 'static,
+{
+    #[error("failed to fetch changes: {}", source)]
+    Api {
+        #[from]
+        source: ApiError<E>,
+    },
+    #[error("{}", source)]
+    Checkpoint {
+        #[from]
+        source: CheckpointError,
+    },
+}
+
+/// An item which was created or updated since the last run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UpdateEvent<T> {
+    pub key: String,
+    pub update_date: Option<DateTime<Utc>>,
+    pub item: T,
+}
+
+/// The changes to a resource found by a run, along with the checkpoint to
+/// commit once they are handled.
+#[derive(Debug, Clone)]
+pub struct Changes<T> {
+    /// The updated items, oldest first, each reported once.
+    pub events: Vec<UpdateEvent<T>>,
+    resource: &'static str,
+    checkpoint: Checkpoint,
+}
+
+impl<T> Changes<T> {
+    /// The name of the resource the changes are to.
+    pub fn resource(&self) -> &'static str {
+        self.resource
+    }
+
+    /// The checkpoint which committing the changes saves.
+    pub fn checkpoint(&self) -> &Checkpoint {
+        &self.checkpoint
+    }
+}
+
+/// Fetches the changes to resources since their last checkpoint.
+#[derive(Debug)]
+pub struct Syncer<S> {
+    store: S,
+    since: DateTime<Utc>,
+    overlap: TimeDelta,
+}
+
+impl<S> Syncer<S>
+where
+    S: CheckpointStore,
+{
+    /// Creates a syncer which starts resources without a checkpoint at
+    /// `since`.
+    pub fn new(store: S, since: DateTime<Utc>) -> Self {
+        Self {
+            store,
+            since,
+            overlap: DEFAULT_OVERLAP,
+        }
+    }
+
+    /// Sets how far before the high-water mark each run starts.
+    pub fn overlap(mut self, overlap: TimeDelta) -> Self {
+        self.overlap = overlap;
+        self
+    }
+
+    /// The store the checkpoints are kept in.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Fetches every change to a resource since its checkpoint.
+    ///
+    /// The checkpoint is not advanced until the changes are committed, so a
+    /// run which fails or is abandoned is simply repeated.
+    pub async fn pull<R, C>(&self, client: &C) -> Result<Changes<R::Item>, SyncError<C::Error>>
+    where
+        R: SyncResource,
+        C: Client,
+    {
+        self.pull_until::<R, C>(client, Utc::now()).await
+    }
+
+    /// Fetches the changes to a resource since its checkpoint up to `to`.
+    pub async fn pull_until<R, C>(
+        &self,
+        client: &C,
+        to: DateTime<Utc>,
+    ) -> Result<Changes<R::Item>, SyncError<C::Error>>
+    where
+        R: SyncResource,
+        C: Client,
+    {
+        let (from, mut high_water, mut seen) = match self.store.load(R::NAME)? {
+            Some(checkpoint) => (
+                checkpoint.high_water - self.overlap,
+                checkpoint.high_water,
+                checkpoint.recent,
+            ),
+            None => (self.since, self.since, BTreeMap::new()),
+        };
+
+        let (items, err) = all_pages::<R::Response, _, _, _>(client, |offset, limit| {
+            R::changes(from, to, offset, limit)
+        })
+        .await;
+        if let Some(err) = err {
+            return Err(err.into());
+        }
+
+        let mut events = Vec::new();
+        for item in items {
+            let key = item.sync_key();
+            let update_date = item.sync_update_date();
+            // Items without an update date are only reported once per key.
+            let stamp = update_date.unwrap_or(from);
+            if seen.get(&key).is_some_and(|seen| stamp <= *seen) {
+                continue;
+            }
+
+            seen.insert(key.clone(), stamp);
+            high_water = high_water.max(stamp);
+            events.push(UpdateEvent {
+                key,
+                update_date,
+                item,
+            });
+        }
+        events.sort_by_key(|event| event.update_date);
+
+        let cutoff = high_water - self.overlap;
+        seen.retain(|_, stamp| *stamp >= cutoff);
+
+        Ok(Changes {
+            events,
+            resource: R::NAME,
+            checkpoint: Checkpoint {
+                high_water,
+                recent: seen,
+            },
+        })
+    }
+
+    /// Saves the checkpoint of handled changes, so the next run resumes
+    /// after them.
+    pub fn commit<T>(&self, changes: &Changes<T>) -> Result<(), CheckpointError> {
+        self.store.save(changes.resource, &changes.checkpoint)
+    }
+
+    /// Fetches every change to a resource since its checkpoint and commits
+    /// them straight away.
+    pub async fn sync<R, C>(
+        &self,
+        client: &C,
+    ) -> Result<Vec<UpdateEvent<R::Item>>, SyncError<C::Error>>
+    where
+        R: SyncResource,
+        C: Client,
+    {
+        let changes = self.pull::<R, C>(client).await?;
+        self.commit(&changes)?;
+        Ok(changes.events)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::StatusCode;
+
+    use crate::test::MockClient;
+
+    use super::*;
+
+    fn at(s: &str) -> DateTime<Utc> {
+        s.parse().unwrap()
+    }
+
+    fn bill(number: u32, update_date: &str) -> String {
+        format!(
+            r#"{{"congress": 118, "type": "HR", "number": "{number}", "updateDate": "{update_date}"}}"#
+        )
+    }
+
+    fn key(from: &str, to: &str, offset: u32) -> String {
+        format!(
+            "bill?offset={offset}&limit=250&fromDateTime={}&toDateTime={}&sort=updateDate+asc",
+            from.replace(':', "%3A"),
+            to.replace(':', "%3A")
+        )
+    }
+
+    #[tokio::test]
+    async fn resumes_from_checkpoint_without_repeating_events() {
+        let client = MockClient::new()
+            .respond(
+                &key("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z", 0),
+                &format!(
+                    r#"{{"bills": [{}, {}], "pagination": {{"count": 3, "next": "https://api.congress.gov/v3/bill?offset=2"}}}}"#,
+                    bill(1, "2024-01-01T10:00:00Z"),
+                    bill(2, "2024-01-01T23:30:00Z"),
+                ),
+            )
+            // The second page shifted while paging, repeating the last bill.
+            .respond(
+                &key("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z", 2),
+                &format!(
+                    r#"{{"bills": [{}], "pagination": {{"count": 3}}}}"#,
+                    bill(2, "2024-01-01T23:30:00Z"),
+                ),
+            )
+            .respond(
+                &key("2024-01-01T22:30:00Z", "2024-01-03T00:00:00Z", 0),
+                &format!(
+                    r#"{{"bills": [{}, {}, {}], "pagination": {{"count": 3}}}}"#,
+                    bill(2, "2024-01-01T23:30:00Z"),
+                    bill(3, "2024-01-02T05:00:00Z"),
+                    bill(1, "2024-01-02T06:00:00Z"),
+                ),
+            );
+        let syncer = Syncer::new(MemoryCheckpointStore::new(), at("2024-01-01T00:00:00Z"));
+
+        let changes = syncer
+            .pull_until::<bill::Bill, _>(&client, at("2024-01-02T00:00:00Z"))
+            .await
+            .unwrap();
+        let keys: Vec<_> = changes.events.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["118/hr/1", "118/hr/2"]);
+        assert_eq!(changes.checkpoint().high_water, at("2024-01-01T23:30:00Z"));
+        assert_eq!(
+            changes.checkpoint().recent.keys().collect::<Vec<_>>(),
+            ["118/hr/2"]
+        );
+        syncer.commit(&changes).unwrap();
+
+        let changes = syncer
+            .pull_until::<bill::Bill, _>(&client, at("2024-01-03T00:00:00Z"))
+            .await
+            .unwrap();
+        let keys: Vec<_> = changes.events.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["118/hr/3", "118/hr/1"]);
+        assert_eq!(changes.checkpoint().high_water, at("2024-01-02T06:00:00Z"));
+    }
+
+    #[tokio::test]
+    async fn failed_run_keeps_checkpoint() {
+        let client = MockClient::new().respond_with(
+            &key("2024-01-01T00:00:00Z", "2024-01-02T00:00:00Z", 0),
+            StatusCode::SERVICE_UNAVAILABLE,
+            r#"{"error": "unavailable"}"#,
+        );
+        let syncer = Syncer::new(MemoryCheckpointStore::new(), at("2024-01-01T00:00:00Z"));
+
+        let err = syncer
+            .pull_until::<bill::Bill, _>(&client, at("2024-01-02T00:00:00Z"))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, SyncError::Api { .. }));
+        assert_eq!(syncer.store().load("bill").unwrap(), None);
+    }
+
+    #[test]
+    fn file_store_round_trip() {
+        let dir = std::env::temp_dir().join(format!("cdg-checkpoints-{}", std::process::id()));
+        let store = FileCheckpointStore::new(&dir);
+        assert_eq!(store.load("bill").unwrap(), None);
+
+        let checkpoint = Checkpoint {
+            high_water: at("2024-01-01T23:30:00Z"),
+            recent: [("118/hr/2".to_string(), at("2024-01-01T23:30:00Z"))].into(),
+        };
+        store.save("bill", &checkpoint).unwrap();
+        assert_eq!(store.load("bill").unwrap(), Some(checkpoint));
+        assert_eq!(store.load("member").unwrap(), None);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
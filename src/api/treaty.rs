@@ -5,11 +5,14 @@
 mod committees;
 mod congress;
 mod treaty;
+mod treaty_id;
 mod treaty_number;
 mod treaty_number_actions;
 mod treaty_suffix;
 mod treaty_suffix_actions;
 
+pub mod models;
+
 pub use self::committees::Committees;
 pub use self::committees::CommitteesBuilder;
 pub use self::committees::CommitteesBuilderError;
@@ -22,6 +25,10 @@ pub use self::treaty::Treaty;
 pub use self::treaty::TreatyBuilder;
 pub use self::treaty::TreatyBuilderError;
 
+pub use self::treaty_id::TreatyActions;
+pub use self::treaty_id::TreatyDetail;
+pub use self::treaty_id::TreatyId;
+
 pub use self::treaty_number::TreatyNumber;
 pub use self::treaty_number::TreatyNumberBuilder;
 pub use self::treaty_number::TreatyNumberBuilderError;
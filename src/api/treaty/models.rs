@@ -0,0 +1,400 @@
+//! Typed response models for the Treaty endpoints.
+
+use std::fmt;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::api::{
+    committee::models::{CommitteeRef, CommitteeReferral},
+    common::{Pagination, ResourceCount},
+    de,
+    paged::impl_page,
+    treaty::TreatyId,
+};
+
+/// A country or party to a treaty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct CountryParty {
+    pub name: String,
+}
+
+/// A term under which a treaty is indexed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct IndexTerm {
+    pub name: String,
+}
+
+/// A title of a treaty, such as its short or formal title.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatyTitle {
+    pub title: String,
+    pub title_type: Option<String>,
+}
+
+/// A document related to a treaty, such as an executive report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct RelatedDocument {
+    pub citation: String,
+    pub url: Option<String>,
+}
+
+/// The lettered parts of a treaty that has been partitioned.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatyParts {
+    #[serde(default)]
+    pub count: u32,
+    #[serde(default)]
+    pub urls: Vec<String>,
+}
+
+impl TreatyParts {
+    /// The suffixes of the parts, e.g. `A` and `B`, taken from the part URLs.
+    pub fn suffixes(&self) -> impl Iterator<Item = &str> + '_ {
+        self.urls.iter().filter_map(|url| {
+            let path = url.split(['?', '#']).next()?;
+            path.trim_end_matches('/').rsplit('/').next()
+        })
+    }
+}
+
+/// The text of the resolution of advice and consent to ratification.
+///
+/// The API returns the text as an HTML fragment.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ResolutionText(String);
+
+impl ResolutionText {
+    /// The raw HTML of the resolution text.
+    pub fn as_html(&self) -> &str {
+        &self.0
+    }
+
+    /// The resolution text with markup removed.
+    ///
+    /// Paragraphs and line breaks are separated by newlines and character
+    /// entities are decoded.
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::with_capacity(self.0.len());
+        let mut rest = self.0.as_str();
+
+        while let Some(start) = rest.find('<') {
+            text.push_str(&decode_entities(&rest[..start]));
+            let Some(end) = rest[start..].find('>') else {
+                rest = &rest[start..];
+                break;
+            };
+            let tag = rest[start + 1..start + end]
+                .trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+            if matches!(tag.as_str(), "p" | "br" | "div" | "li" | "tr") {
+                text.push('\n');
+            }
+            rest = &rest[start + end + 1..];
+        }
+        text.push_str(&decode_entities(rest));
+
+        text.lines()
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for ResolutionText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_plain_text())
+    }
+}
+
+fn decode_entities(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut rest = s;
+
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "amp" => '&',
+                "lt" => '<',
+                "gt" => '>',
+                "quot" => '"',
+                "apos" => '\'',
+                "nbsp" => ' ',
+                entity => {
+                    let code = match entity.strip_prefix("#x").or(entity.strip_prefix("#X")) {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                out.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                out.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+
+    out
+}
+
+/// A treaty, as returned by both the list and detail endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct Treaty {
+    #[serde(deserialize_with = "de::number")]
+    pub number: u32,
+    /// The letter of a partitioned treaty, if any.
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub suffix: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub congress_received: Option<u32>,
+    #[serde(default, deserialize_with = "de::opt_number")]
+    pub congress_considered: Option<u32>,
+    pub topic: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub transmitted_date: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub in_force_date: Option<NaiveDate>,
+    /// The lettered designation of treaties received before the 97th
+    /// Congress, e.g. "Ex. A".
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub old_number: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_string")]
+    pub old_number_display_name: Option<String>,
+    #[serde(default)]
+    pub countries_parties: Vec<CountryParty>,
+    #[serde(default)]
+    pub index_terms: Vec<IndexTerm>,
+    #[serde(default)]
+    pub titles: Vec<TreatyTitle>,
+    #[serde(default)]
+    pub related_docs: Vec<RelatedDocument>,
+    pub parts: Option<TreatyParts>,
+    pub resolution_text: Option<ResolutionText>,
+    pub actions: Option<ResourceCount>,
+    #[serde(default, deserialize_with = "de::opt_date_time")]
+    pub update_date: Option<DateTime<Utc>>,
+    pub url: Option<String>,
+}
+
+impl Treaty {
+    /// Whether the treaty has entered into force.
+    pub fn is_in_force(&self) -> bool {
+        self.in_force_date.is_some()
+    }
+
+    /// Whether the treaty has been partitioned into lettered parts.
+    pub fn has_parts(&self) -> bool {
+        self.parts.as_ref().is_some_and(|parts| parts.count > 0)
+    }
+
+    /// The identifier of the treaty, used to query its other endpoints.
+    pub fn id(&self) -> Option<TreatyId> {
+        let congress = u16::try_from(self.congress_received?).ok()?;
+        let id = TreatyId::new(congress, self.number);
+        Some(
+            match self.suffix.as_deref().and_then(|s| s.chars().next()) {
+                Some(suffix) => id.with_suffix(suffix),
+                None => id,
+            },
+        )
+    }
+}
+
+/// An action taken on a treaty.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatyAction {
+    pub action_code: Option<String>,
+    #[serde(default, deserialize_with = "de::opt_date")]
+    pub action_date: Option<NaiveDate>,
+    #[serde(rename = "type")]
+    pub action_type: Option<String>,
+    pub text: Option<String>,
+    pub committee: Option<CommitteeRef>,
+}
+
+/// The response of the /treaty and /treaty/:congress endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatiesResponse {
+    #[serde(default)]
+    pub treaties: Vec<Treaty>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /treaty/:congress/:treatyNumber and
+/// /treaty/:congress/:treatyNumber/:treatySuffix endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatyResponse {
+    pub treaty: Treaty,
+}
+
+/// The response of the /treaty/:congress/:treatyNumber/actions and
+/// /treaty/:congress/:treatyNumber/:treatySuffix/actions endpoints.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatyActionsResponse {
+    #[serde(default)]
+    pub actions: Vec<TreatyAction>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+/// The response of the /treaty/:congress/:treatyNumber/committees endpoint.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+pub struct TreatyCommitteesResponse {
+    #[serde(default)]
+    pub treaty_committees: Vec<CommitteeReferral>,
+    #[serde(default)]
+    pub pagination: Pagination,
+}
+
+impl_page!(TreatiesResponse => treaties: Treaty);
+
+#[cfg(test)]
+mod tests {
+    use crate::api::committee::CommitteeChamber;
+
+    use super::*;
+
+    #[test]
+    fn treaty_detail() {
+        let json = r#"{
+            "treaty": {
+                "actions": {"count": 18, "url": "https://api.congress.gov/v3/treaty/114/13/actions?format=json"},
+                "congressConsidered": 116,
+                "congressReceived": 114,
+                "countriesParties": [{"name": "Micronesia, Federated States of"}, {"name": "Kiribati"}],
+                "inForceDate": null,
+                "indexTerms": [{"name": "Maritime"}, {"name": "Micronesia"}, {"name": "Boundaries"}],
+                "number": 13,
+                "oldNumber": null,
+                "oldNumberDisplayName": null,
+                "parts": {"count": 2, "urls": ["https://api.congress.gov/v3/treaty/114/13/A?format=json", "https://api.congress.gov/v3/treaty/114/13/B?format=json"]},
+                "relatedDocs": [{"citation": "Ex. Rept. 116-5", "url": "https://api.congress.gov/v3/committee-report/116/ERPT/5"}],
+                "resolutionText": "<p><b>As approved by the Senate: </b></p><p>Resolved (two-thirds of the Senators present concurring therein),</p><p>Section 1. Senate Advice and Consent &amp; Declarations<br/>The Senate advises&nbsp;and consents&#8230;</p>",
+                "suffix": "",
+                "titles": [{"title": "Treaty between the Government of the United States of America and the Government of the Federated States of Micronesia on the Delimitation of a Maritime Boundary", "titleType": "Treaty - Formal Title"}],
+                "topic": "Maritime Boundaries and Claims",
+                "transmittedDate": "2016-12-09T00:00:00Z",
+                "updateDate": "2022-08-04T02:46:11Z"
+            },
+            "request": {"congress": "114", "contentType": "application/json", "format": "json", "treatyNumber": "13"}
+        }"#;
+
+        let res: TreatyResponse = serde_json::from_str(json).unwrap();
+        let treaty = res.treaty;
+
+        assert_eq!(treaty.number, 13);
+        assert_eq!(treaty.suffix, None);
+        assert_eq!(treaty.old_number, None);
+        assert!(!treaty.is_in_force());
+        assert!(treaty.has_parts());
+        assert_eq!(treaty.id(), Some(TreatyId::new(114, 13)));
+        assert_eq!(
+            treaty
+                .parts
+                .as_ref()
+                .unwrap()
+                .suffixes()
+                .collect::<Vec<_>>(),
+            ["A", "B"]
+        );
+        assert_eq!(treaty.countries_parties.len(), 2);
+        assert_eq!(treaty.index_terms[0].name, "Maritime");
+        assert_eq!(
+            treaty.transmitted_date,
+            NaiveDate::from_ymd_opt(2016, 12, 9)
+        );
+        assert_eq!(treaty.related_docs[0].citation, "Ex. Rept. 116-5");
+
+        let resolution = treaty.resolution_text.unwrap();
+        assert!(resolution.as_html().starts_with("<p><b>"));
+        assert_eq!(
+            resolution.to_plain_text(),
+            "As approved by the Senate:\n\
+             Resolved (two-thirds of the Senators present concurring therein),\n\
+             Section 1. Senate Advice and Consent & Declarations\n\
+             The Senate advises and consents\u{2026}"
+        );
+    }
+
+    #[test]
+    fn treaty_list() {
+        let json = r#"{
+            "pagination": {"count": 2},
+            "treaties": [
+                {"congressConsidered": 116, "congressReceived": 116, "number": 1, "parts": {}, "suffix": "", "topic": "Extradition", "transmittedDate": "2020-01-07T00:00:00Z", "updateDate": "2022-07-12T15:48:45Z", "url": "https://api.congress.gov/v3/treaty/116/1?format=json"},
+                {"congressReceived": 114, "inForceDate": "2021-03-01", "number": 13, "suffix": "A", "updateDate": "2022-07-12T15:48:45Z", "url": "https://api.congress.gov/v3/treaty/114/13/A?format=json"}
+            ]
+        }"#;
+
+        let res: TreatiesResponse = serde_json::from_str(json).unwrap();
+        assert!(!res.treaties[0].has_parts());
+        assert_eq!(res.treaties[1].suffix.as_deref(), Some("A"));
+        assert!(res.treaties[1].is_in_force());
+    }
+
+    #[test]
+    fn treaty_sub_resources() {
+        let actions: TreatyActionsResponse = serde_json::from_str(
+            r#"{"actions": [{"actionCode": "S05291", "actionDate": "2022-08-04", "committee": {"name": "Foreign Relations Committee", "systemCode": "ssfr00", "url": "https://api.congress.gov/v3/committee/senate/ssfr00?format=json"}, "text": "Resolution of advice and consent to ratification agreed to as amended in Senate by Yea-Nay Vote. 95 - 1.", "type": "Floor"}], "pagination": {"count": 18}}"#,
+        )
+        .unwrap();
+        let action = &actions.actions[0];
+        assert_eq!(action.committee.as_ref().unwrap().system_code, "ssfr00");
+        assert_eq!(action.action_type.as_deref(), Some("Floor"));
+
+        let committees: TreatyCommitteesResponse = serde_json::from_str(
+            r#"{"pagination": {"count": 1}, "treatyCommittees": [{"activities": [{"date": "2022-06-22T21:22:18Z", "name": "Referred to"}], "chamber": "Senate", "name": "Foreign Relations Committee", "subcommittees": [], "systemCode": "ssfr00", "type": "Standing", "url": "https://api.congress.gov/v3/committee/senate/ssfr00?format=json"}]}"#,
+        )
+        .unwrap();
+        let committee = &committees.treaty_committees[0];
+        assert_eq!(committee.chamber, Some(CommitteeChamber::Senate));
+        assert_eq!(committee.activities[0].name, "Referred to");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        assert_eq!(
+            decode_entities("a &amp; b &#x2014; c &bogus; &"),
+            "a & b \u{2014} c &bogus; &"
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use http::Method;
+use std::borrow::Cow;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::api::{
+    calendar::Congress,
+    citation::{self, Citation, ParseCitationError},
+    endpoint::{Endpoint, UrlBase},
+    params::QueryParams,
+    treaty::{
+        CommitteesBuilder, TreatyNumber, TreatyNumberActions, TreatyNumberActionsBuilder,
+        TreatyNumberBuilder, TreatySuffix, TreatySuffixActions, TreatySuffixActionsBuilder,
+        TreatySuffixBuilder,
+    },
+};
+
+/// Identifies a treaty document by the congress it was received in, its
+/// number and, for a treaty split into parts, the letter of the part.
+///
+/// A `TreatyId` can be parsed from a citation such as "Treaty Doc. 117-3",
+/// "Treaty Doc. 114-13A" or "TD 117-3B", and is displayed in the first form.
+/// It converts into [`TreatyDetail`] and [`TreatyActions`], which query the
+/// suffixed endpoints only when the treaty has a suffix.
+///
+/// ```rust
+/// use congressdotgov_rs::api::treaty::{TreatyDetail, TreatyId};
+/// use congressdotgov_rs::api::Endpoint;
+///
+/// let id: TreatyId = "TD 114-13a".parse().unwrap();
+/// assert_eq!(id, TreatyId::new(114, 13).with_suffix('A'));
+/// assert_eq!(id.to_string(), "Treaty Doc. 114-13A");
+/// assert_eq!(TreatyDetail::from(id).endpoint(), "treaty/114/13/A");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TreatyId {
+    pub congress: u16,
+    pub number: u32,
+    pub suffix: Option<char>,
+}
+
+impl TreatyId {
+    pub fn new(congress: u16, number: u32) -> Self {
+        Self {
+            congress,
+            number,
+            suffix: None,
+        }
+    }
+
+    /// The same treaty with the given suffix, which is uppercased.
+    pub fn with_suffix(self, suffix: char) -> Self {
+        Self {
+            suffix: Some(suffix.to_ascii_uppercase()),
+            ..self
+        }
+    }
+
+    /// The treaty this part belongs to, without its suffix.
+    pub fn without_suffix(self) -> Self {
+        Self {
+            suffix: None,
+            ..self
+        }
+    }
+}
+
+impl FromStr for TreatyId {
+    type Err = ParseCitationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const KIND: &str = "treaty";
+
+        let invalid = || ParseCitationError::unrecognized(KIND, s);
+        let citation = Citation::split(s).ok_or_else(invalid)?;
+        if !matches!(
+            citation.prefix.as_str(),
+            "td" | "treatydoc" | "treatydocument" | "treatydocno"
+        ) {
+            return Err(invalid());
+        }
+
+        let (congress, number) = match citation.numbers() {
+            (congress, Some(number)) => (congress, number),
+            (_, None) => return Err(invalid()),
+        };
+        let congress = citation::congress(congress).ok_or_else(invalid)?;
+        let congress = Congress::new(congress)
+            .map(u16::from)
+            .map_err(|_| ParseCitationError::invalid_congress(KIND, s, congress))?;
+
+        // A part is cited with its letter right after the number, as in "13A".
+        let (number, suffix) = match number.char_indices().last() {
+            Some((at, c)) if c.is_ascii_alphabetic() => (&number[..at], Some(c)),
+            _ => (number, None),
+        };
+        let id = Self::new(congress, citation::number(number).ok_or_else(invalid)?);
+
+        Ok(match suffix {
+            Some(suffix) => id.with_suffix(suffix),
+            None => id,
+        })
+    }
+}
+
+impl fmt::Display for TreatyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Treaty Doc. {}-{}", self.congress, self.number)?;
+        if let Some(suffix) = self.suffix {
+            write!(f, "{}", suffix)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<TreatyId> for CommitteesBuilder {
+    /// Committees are tracked for the whole treaty, so the suffix is dropped.
+    fn from(id: TreatyId) -> Self {
+        let mut builder = Self::default();
+        builder.congress(id.congress).treaty_number(id.number);
+        builder
+    }
+}
+
+/// The /treaty/:congress/:treatyNumber endpoint, or the
+/// /treaty/:congress/:treatyNumber/:treatySuffix endpoint for a part of a
+/// treaty.
+#[derive(Debug, Clone, Copy)]
+pub enum TreatyDetail {
+    Number(TreatyNumber),
+    Suffix(TreatySuffix),
+}
+
+impl From<TreatyId> for TreatyDetail {
+    fn from(id: TreatyId) -> Self {
+        match id.suffix {
+            Some(suffix) => Self::Suffix(
+                TreatySuffixBuilder::default()
+                    .congress(id.congress)
+                    .treaty_number(id.number)
+                    .treaty_suffix(suffix)
+                    .build()
+                    .expect("all required fields are set"),
+            ),
+            None => Self::Number(
+                TreatyNumberBuilder::default()
+                    .congress(id.congress)
+                    .treaty_number(id.number)
+                    .build()
+                    .expect("all required fields are set"),
+            ),
+        }
+    }
+}
+
+impl Endpoint for TreatyDetail {
+    fn method(&self) -> Method {
+        match self {
+            Self::Number(endpoint) => endpoint.method(),
+            Self::Suffix(endpoint) => endpoint.method(),
+        }
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        match self {
+            Self::Number(endpoint) => endpoint.endpoint(),
+            Self::Suffix(endpoint) => endpoint.endpoint(),
+        }
+    }
+
+    fn url_base(&self) -> UrlBase {
+        match self {
+            Self::Number(endpoint) => endpoint.url_base(),
+            Self::Suffix(endpoint) => endpoint.url_base(),
+        }
+    }
+
+    fn parameters(&self) -> QueryParams {
+        match self {
+            Self::Number(endpoint) => endpoint.parameters(),
+            Self::Suffix(endpoint) => endpoint.parameters(),
+        }
+    }
+}
+
+/// The /treaty/:congress/:treatyNumber/actions endpoint, or the
+/// /treaty/:congress/:treatyNumber/:treatySuffix/actions endpoint for a part
+/// of a treaty.
+#[derive(Debug, Clone, Copy)]
+pub enum TreatyActions {
+    Number(TreatyNumberActions),
+    Suffix(TreatySuffixActions),
+}
+
+impl TreatyActions {
+    /// The actions of a treaty, fetched `limit` at a time starting at
+    /// `offset`.
+    pub fn paged(id: TreatyId, offset: u32, limit: u8) -> Self {
+        Self::build(id, Some(offset), Some(limit))
+    }
+
+    fn build(id: TreatyId, offset: Option<u32>, limit: Option<u8>) -> Self {
+        match id.suffix {
+            Some(suffix) => {
+                let mut builder = TreatySuffixActionsBuilder::default();
+                builder
+                    .congress(id.congress)
+                    .treaty_number(id.number)
+                    .treaty_suffix(suffix);
+                if let Some(offset) = offset {
+                    builder.offset(offset);
+                }
+                if let Some(limit) = limit {
+                    builder.limit(limit);
+                }
+                Self::Suffix(builder.build().expect("all required fields are set"))
+            }
+            None => {
+                let mut builder = TreatyNumberActionsBuilder::default();
+                builder.congress(id.congress).treaty_number(id.number);
+                if let Some(offset) = offset {
+                    builder.offset(offset);
+                }
+                if let Some(limit) = limit {
+                    builder.limit(limit);
+                }
+                Self::Number(builder.build().expect("all required fields are set"))
+            }
+        }
+    }
+}
+
+impl From<TreatyId> for TreatyActions {
+    fn from(id: TreatyId) -> Self {
+        Self::build(id, None, None)
+    }
+}
+
+impl Endpoint for TreatyActions {
+    fn method(&self) -> Method {
+        match self {
+            Self::Number(endpoint) => endpoint.method(),
+            Self::Suffix(endpoint) => endpoint.method(),
+        }
+    }
+
+    fn endpoint(&self) -> Cow<'static, str> {
+        match self {
+            Self::Number(endpoint) => endpoint.endpoint(),
+            Self::Suffix(endpoint) => endpoint.endpoint(),
+        }
+    }
+
+    fn url_base(&self) -> UrlBase {
+        match self {
+            Self::Number(endpoint) => endpoint.url_base(),
+            Self::Suffix(endpoint) => endpoint.url_base(),
+        }
+    }
+
+    fn parameters(&self) -> QueryParams {
+        match self {
+            Self::Number(endpoint) => endpoint.parameters(),
+            Self::Suffix(endpoint) => endpoint.parameters(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_citations() {
+        for (citation, id) in [
+            ("Treaty Doc. 117-3", TreatyId::new(117, 3)),
+            (
+                "Treaty Doc. 114-13A",
+                TreatyId::new(114, 13).with_suffix('A'),
+            ),
+            ("TD 117-3B", TreatyId::new(117, 3).with_suffix('B')),
+            ("td117-3b", TreatyId::new(117, 3).with_suffix('B')),
+            ("Treaty Document 110-1", TreatyId::new(110, 1)),
+        ] {
+            assert_eq!(citation.parse::<TreatyId>(), Ok(id), "{citation}");
+        }
+    }
+
+    #[test]
+    fn round_trips() {
+        for id in [
+            TreatyId::new(117, 3),
+            TreatyId::new(114, 13).with_suffix('A'),
+        ] {
+            assert_eq!(id.to_string().parse::<TreatyId>(), Ok(id));
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_citations() {
+        for citation in [
+            "",
+            "Treaty Doc.",
+            "Treaty Doc. 117",
+            "Treaty Doc. 117-",
+            "Treaty Doc. 117-A",
+            "Treaty Doc. 117-3AB",
+            "H. Doc. 117-3",
+        ] {
+            assert!(citation.parse::<TreatyId>().is_err(), "{citation}");
+        }
+    }
+
+    #[test]
+    fn dispatches_on_suffix() {
+        let whole: TreatyId = "Treaty Doc. 114-13".parse().unwrap();
+        let part: TreatyId = "Treaty Doc. 114-13A".parse().unwrap();
+
+        assert!(matches!(TreatyDetail::from(whole), TreatyDetail::Number(_)));
+        assert_eq!(TreatyDetail::from(whole).endpoint(), "treaty/114/13");
+        assert!(matches!(TreatyDetail::from(part), TreatyDetail::Suffix(_)));
+        assert_eq!(TreatyDetail::from(part).endpoint(), "treaty/114/13/A");
+
+        assert_eq!(
+            TreatyActions::from(whole).endpoint(),
+            "treaty/114/13/actions"
+        );
+        assert_eq!(
+            TreatyActions::from(part).endpoint(),
+            "treaty/114/13/A/actions"
+        );
+        assert_eq!(part.without_suffix(), whole);
+
+        let mut url = url::Url::parse("https://api.congress.gov/v3/").unwrap();
+        TreatyActions::paged(part, 250, 250)
+            .parameters()
+            .add_to_url(&mut url);
+        assert_eq!(url.query(), Some("format=json&offset=250&limit=250"));
+
+        assert_eq!(
+            CommitteesBuilder::from(part).build().unwrap().endpoint(),
+            "treaty/114/13/committees"
+        );
+    }
+}
@@ -5,26 +5,26 @@ use std::borrow::Cow;
 use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /treaty/:congress/:treatyNumber/:treatySuffix endpoint.
-#[derive(Debug, Clone, Builder)]
+#[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
-pub struct TreatySuffix<'a> {
+pub struct TreatySuffix {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     treaty_number: u32,
     #[builder(setter(into))]
-    treaty_suffix: Cow<'a, str>,
+    treaty_suffix: char,
     #[builder(default)]
     format: Format,
 }
 
-impl<'a> TreatySuffix<'a> {
-    pub fn builder() -> TreatySuffixBuilder<'a> {
+impl TreatySuffix {
+    pub fn builder() -> TreatySuffixBuilder {
         TreatySuffixBuilder::default()
     }
 }
 
-impl Endpoint for TreatySuffix<'_> {
+impl Endpoint for TreatySuffix {
     fn method(&self) -> Method {
         Method::GET
     }
@@ -57,7 +57,7 @@ mod tests {
         TreatySuffix::builder()
             .congress(114_u8)
             .treaty_number(13_u32)
-            .treaty_suffix("A")
+            .treaty_suffix('A')
             .build()
             .unwrap();
     }
@@ -72,7 +72,7 @@ mod tests {
         let endpoint = TreatySuffix::builder()
             .congress(114_u8)
             .treaty_number(13_u32)
-            .treaty_suffix("A")
+            .treaty_suffix('A')
             .build()
             .unwrap();
 
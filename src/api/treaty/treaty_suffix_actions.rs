@@ -5,15 +5,15 @@ use std::borrow::Cow;
 use crate::{api::common::Format, api::endpoint::Endpoint, api::params::QueryParams};
 
 /// Represents the /treaty/:congress/:treatyNumber/:treatySuffix/actions endpoint.
-#[derive(Debug, Clone, Builder)]
+#[derive(Debug, Clone, Copy, Builder)]
 #[builder(setter(strip_option))]
-pub struct TreatySuffixActions<'a> {
+pub struct TreatySuffixActions {
     #[builder(setter(into))]
-    congress: u8,
+    congress: u16,
     #[builder(setter(into))]
     treaty_number: u32,
     #[builder(setter(into))]
-    treaty_suffix: Cow<'a, str>,
+    treaty_suffix: char,
     #[builder(default)]
     format: Format,
     #[builder(default)]
@@ -22,13 +22,13 @@ pub struct TreatySuffixActions<'a> {
     limit: Option<u8>,
 }
 
-impl<'a> TreatySuffixActions<'a> {
-    pub fn builder() -> TreatySuffixActionsBuilder<'a> {
+impl TreatySuffixActions {
+    pub fn builder() -> TreatySuffixActionsBuilder {
         TreatySuffixActionsBuilder::default()
     }
 }
 
-impl Endpoint for TreatySuffixActions<'_> {
+impl Endpoint for TreatySuffixActions {
     fn method(&self) -> Method {
         Method::GET
     }
@@ -63,7 +63,7 @@ mod tests {
         TreatySuffixActions::builder()
             .congress(114_u8)
             .treaty_number(13_u32)
-            .treaty_suffix("A")
+            .treaty_suffix('A')
             .build()
             .unwrap();
     }
@@ -78,7 +78,7 @@ mod tests {
         let endpoint = TreatySuffixActions::builder()
             .congress(114_u8)
             .treaty_number(13_u32)
-            .treaty_suffix("A")
+            .treaty_suffix('A')
             .build()
             .unwrap();
 
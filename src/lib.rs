@@ -5,7 +5,7 @@
 //! - REST API bindings are divided between Endpoint, Client, and Query traits
 //! - Auth and state are managed by the Cdg struct
 //! - Optional Endpoint parameters are added to a query via a Builder API
-//! - Responses are returned as a serde_json::Value
+//! - Responses are returned as a serde_json::Value or as typed response models
 //!
 //! This crate only provides an async implementation.
 
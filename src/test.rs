@@ -1,3 +1,9 @@
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
 macro_rules! assert_missing_field {
     ($err:expr, $type:tt, $field:expr $(,)?) => {
         let in_err = $err;
@@ -14,3 +20,159 @@ macro_rules! assert_missing_field {
 }
 
 pub(crate) use assert_missing_field;
+
+/// A client answering requests with canned JSON bodies, for testing code which
+/// issues several queries without reaching the network.
+///
+/// Responses are keyed by endpoint path and query string, without the
+/// `format` and `api_key` parameters, e.g. `bill/117/hr/3076?offset=0`.
+/// Unknown requests are answered with a 404.
+#[derive(Debug, Default)]
+pub(crate) struct MockClient {
+    responses: HashMap<String, (http::StatusCode, String)>,
+    requests: Mutex<Vec<String>>,
+}
+
+impl MockClient {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers `key` with a successful response.
+    pub(crate) fn respond(self, key: &str, body: &str) -> Self {
+        self.respond_with(key, http::StatusCode::OK, body)
+    }
+
+    /// Answers `key` with the given status and body.
+    pub(crate) fn respond_with(mut self, key: &str, status: http::StatusCode, body: &str) -> Self {
+        self.responses
+            .insert(key.to_string(), (status, body.to_string()));
+        self
+    }
+
+    /// The keys of the requests made so far, in order.
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    fn key(uri: &http::Uri) -> String {
+        let path = uri.path().trim_start_matches("/v3/");
+        let query = uri
+            .query()
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| {
+                !pair.is_empty() && !pair.starts_with("format=") && !pair.starts_with("api_key=")
+            })
+            .collect::<Vec<_>>()
+            .join("&");
+        if query.is_empty() {
+            path.to_string()
+        } else {
+            format!("{path}?{query}")
+        }
+    }
+}
+
+impl crate::api::Client for MockClient {
+    type Error = crate::cdg::CdgError;
+
+    fn rest_endpoint(&self, endpoint: &str) -> Result<url::Url, crate::api::ApiError<Self::Error>> {
+        Ok(url::Url::parse("https://api.congress.gov/v3/")?.join(endpoint)?)
+    }
+
+    fn set_auth(&self, _url: &mut url::Url) {}
+
+    async fn rest(
+        &self,
+        request: http::request::Builder,
+        _body: Vec<u8>,
+    ) -> Result<http::Response<bytes::Bytes>, crate::api::ApiError<Self::Error>> {
+        let key = Self::key(request.uri_ref().expect("request without a uri"));
+        self.requests.lock().unwrap().push(key.clone());
+        let (status, body) = self.responses.get(&key).cloned().unwrap_or_else(|| {
+            (
+                http::StatusCode::NOT_FOUND,
+                r#"{"error": "not found"}"#.to_string(),
+            )
+        });
+        Ok(http::Response::builder()
+            .status(status)
+            .body(bytes::Bytes::from(body))
+            .unwrap())
+    }
+}
+
+/// A local HTTP server answering requests with canned responses, for testing
+/// code which goes through a real HTTP client.
+///
+/// Responses are keyed by path, ignoring the query string, and may be added
+/// after the server is started so that they can refer to its URL. Unknown
+/// paths are answered with a 404. The server runs until the test process
+/// exits.
+pub(crate) struct StubServer {
+    addr: SocketAddr,
+    routes: Routes,
+}
+
+/// The content type and body served for each path.
+type Routes = Arc<Mutex<HashMap<String, (String, Vec<u8>)>>>;
+
+impl StubServer {
+    pub(crate) fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let routes = Routes::default();
+
+        let served = Arc::clone(&routes);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { continue };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                if reader.read_line(&mut request_line).is_err() {
+                    continue;
+                }
+                // Skip the headers; none of the stubbed requests have a body.
+                let mut line = String::new();
+                while reader.read_line(&mut line).is_ok_and(|n| n > 2) {
+                    line.clear();
+                }
+
+                let target = request_line.split(' ').nth(1).unwrap_or_default();
+                let path = target.split('?').next().unwrap_or_default();
+                let route = served.lock().unwrap().get(path).cloned();
+                let (status, content_type, body) = match route {
+                    Some((content_type, body)) => ("200 OK", content_type, body),
+                    None => (
+                        "404 Not Found",
+                        "application/json".to_string(),
+                        br#"{"error": "not found"}"#.to_vec(),
+                    ),
+                };
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(&body);
+            }
+        });
+
+        Self { addr, routes }
+    }
+
+    /// Answers `path` with a successful response of the given content type.
+    pub(crate) fn route(self, path: &str, content_type: &str, body: impl Into<Vec<u8>>) -> Self {
+        self.routes
+            .lock()
+            .unwrap()
+            .insert(path.to_string(), (content_type.to_string(), body.into()));
+        self
+    }
+
+    /// The URL of a path on the server.
+    pub(crate) fn url(&self, path: &str) -> String {
+        format!("http://{}{path}", self.addr)
+    }
+}